@@ -12,13 +12,30 @@ use runtime::starknet::constants::{
 };
 use runtime::starknet::context::ERC20_CONTRACT_ADDRESS;
 use runtime::starknet::state::DictStateReader;
+use starknet::core::crypto::pedersen_hash;
 use starknet::core::utils::get_selector_from_name;
 use starknet_api::contract_class::EntryPointType;
 use starknet_api::{core::ContractAddress, transaction::fields::Calldata};
+use starknet_types_core::felt::Felt;
 
 // Mocked class hashes, those are not checked anywhere
 pub const TEST_ERC20_CONTRACT_CLASS_HASH: &str = "0x1010";
 
+// System contract storing hashes of previous blocks under their numbers
+pub const BLOCK_HASH_CONTRACT_ADDRESS: &str = "0x1";
+
+// Seconds between blocks produced by `advance_blocks`, unless changed with `set_block_time`
+pub const DEFAULT_BLOCK_TIME: u64 = 6;
+
+// Hash of a block in tests without a fork, equal to `pedersen('BLOCK_HASH', block_number)`
+#[must_use]
+pub fn default_block_hash(block_number: u64) -> Felt {
+    pedersen_hash(
+        &Felt::from_bytes_be_slice(b"BLOCK_HASH"),
+        &Felt::from(block_number),
+    )
+}
+
 fn contract_class_no_entrypoints() -> ContractClass {
     let raw_contract_class = indoc!(
         r#"{
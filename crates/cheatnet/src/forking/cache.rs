@@ -1,15 +1,15 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail, ensure};
 use camino::{Utf8Path, Utf8PathBuf};
 use fs2::FileExt;
 use regex::Regex;
 use runtime::starknet::context::SerializableBlockInfo;
 use serde::{Deserialize, Serialize};
-use starknet::core::types::ContractClass;
+use starknet::core::types::{ContractClass, TransactionTraceWithHash};
 use starknet_api::block::{BlockInfo, BlockNumber};
 use starknet_api::core::{ClassHash, ContractAddress, Nonce};
 use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::fs::OpenOptions;
 use std::io::{Read, Write};
@@ -29,6 +29,11 @@ struct ForkCacheContent {
     class_hash_at: HashMap<ContractAddress, ClassHash>,
     compiled_contract_class: HashMap<ClassHash, ContractClass>,
     block_info: Option<SerializableBlockInfo>,
+    chain_id: Option<String>,
+    /// Missing in caches saved before block hashes were cached
+    #[serde(default)]
+    block_hash_at: HashMap<u64, Felt>,
+    block_transaction_traces: Option<Vec<TransactionTraceWithHash>>,
 }
 
 impl Default for ForkCacheContent {
@@ -40,6 +45,9 @@ impl Default for ForkCacheContent {
             class_hash_at: HashMap::default(),
             compiled_contract_class: HashMap::default(),
             block_info: Option::default(),
+            chain_id: Option::default(),
+            block_hash_at: HashMap::default(),
+            block_transaction_traces: Option::default(),
         }
     }
 }
@@ -74,9 +82,17 @@ impl ForkCacheContent {
         self.class_hash_at.extend(other.class_hash_at.clone());
         self.compiled_contract_class
             .extend(other.compiled_contract_class.clone());
+        self.block_hash_at.extend(other.block_hash_at.clone());
         if other.block_info.is_some() {
             self.block_info.clone_from(&other.block_info);
         }
+        if other.chain_id.is_some() {
+            self.chain_id.clone_from(&other.chain_id);
+        }
+        if other.block_transaction_traces.is_some() {
+            self.block_transaction_traces
+                .clone_from(&other.block_transaction_traces);
+        }
     }
 }
 
@@ -91,11 +107,15 @@ impl ToString for ForkCacheContent {
 pub struct ForkCache {
     fork_cache_content: ForkCacheContent,
     cache_file: Utf8PathBuf,
+    /// Offline caches are never filled, so they are not saved
+    offline: bool,
 }
 
 impl Drop for ForkCache {
     fn drop(&mut self) {
-        self.save();
+        if !self.offline {
+            self.save();
+        }
     }
 }
 
@@ -128,34 +148,37 @@ impl ForkCache {
         Ok(ForkCache {
             fork_cache_content,
             cache_file,
+            offline: false,
         })
     }
 
-    fn save(&self) {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(false)
-            .open(&self.cache_file)
-            .unwrap();
-
-        file.lock_exclusive().expect("Could not lock on cache file");
+    /// Loads an existing cache without creating it, used when the node must not be queried
+    pub(crate) fn load_offline(
+        url: &Url,
+        block_number: BlockNumber,
+        cache_dir: &Utf8Path,
+    ) -> Result<Self> {
+        let cache_file = cache_file_path_from_fork_config(url, block_number, cache_dir)?;
+        if !cache_file.exists() {
+            bail!(
+                "Fork cache of {url} at block {} not found in {cache_dir}, it cannot be fetched in offline mode",
+                block_number.0
+            );
+        }
 
         let cache_file_content =
-            fs::read_to_string(&self.cache_file).expect("Should have been able to read the cache");
-
-        let output = if cache_file_content.is_empty() {
-            self.fork_cache_content.to_string()
-        } else {
-            let mut fs_fork_cache_content = ForkCacheContent::from_str(&cache_file_content);
-            fs_fork_cache_content.extend(&self.fork_cache_content);
-            fs_fork_cache_content.to_string()
-        };
+            fs::read_to_string(&cache_file).context("Could not read cache file")?;
 
-        file.write_all(output.as_bytes())
-            .expect("Could not write cache to file");
+        Ok(ForkCache {
+            fork_cache_content: ForkCacheContent::from_str(&cache_file_content),
+            cache_file,
+            offline: true,
+        })
+    }
 
-        fs2::FileExt::unlock(&file).unwrap();
+    fn save(&self) {
+        write_merged(&self.cache_file, &self.fork_cache_content)
+            .expect("Could not save fork cache");
     }
 
     pub(crate) fn get_storage_at(
@@ -240,6 +263,147 @@ impl ForkCache {
     pub(crate) fn cache_get_block_info(&mut self, block_info: BlockInfo) {
         self.fork_cache_content.block_info = Some(block_info.into());
     }
+
+    pub(crate) fn get_chain_id(&self) -> Option<String> {
+        self.fork_cache_content.chain_id.clone()
+    }
+
+    pub(crate) fn cache_get_chain_id(&mut self, chain_id: String) {
+        self.fork_cache_content.chain_id = Some(chain_id);
+    }
+
+    pub(crate) fn get_block_hash_at(&self, block_number: u64) -> Option<Felt> {
+        self.fork_cache_content
+            .block_hash_at
+            .get(&block_number)
+            .copied()
+    }
+
+    pub(crate) fn cache_get_block_hash_at(&mut self, block_number: u64, block_hash: Felt) {
+        self.fork_cache_content
+            .block_hash_at
+            .insert(block_number, block_hash);
+    }
+
+    pub(crate) fn get_block_transaction_traces(&self) -> Option<Vec<TransactionTraceWithHash>> {
+        self.fork_cache_content.block_transaction_traces.clone()
+    }
+
+    pub(crate) fn cache_get_block_transaction_traces(
+        &mut self,
+        traces: Vec<TransactionTraceWithHash>,
+    ) {
+        self.fork_cache_content.block_transaction_traces = Some(traces);
+    }
+}
+
+/// Merges `content` into the cache file, which may have been extended by other processes meanwhile
+fn write_merged(cache_file: &Utf8Path, content: &ForkCacheContent) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(cache_file)
+        .with_context(|| format!("Could not open fork cache {cache_file}"))?;
+
+    file.lock_exclusive()
+        .with_context(|| format!("Could not lock on fork cache {cache_file}"))?;
+
+    let cache_file_content = fs::read_to_string(cache_file)
+        .with_context(|| format!("Could not read fork cache {cache_file}"))?;
+
+    let output = if cache_file_content.is_empty() {
+        content.to_string()
+    } else {
+        let mut fs_fork_cache_content = ForkCacheContent::from_str(&cache_file_content);
+        fs_fork_cache_content.extend(content);
+        fs_fork_cache_content.to_string()
+    };
+
+    file.write_all(output.as_bytes())
+        .with_context(|| format!("Could not write fork cache {cache_file}"))?;
+
+    fs2::FileExt::unlock(&file)
+        .with_context(|| format!("Could not unlock fork cache {cache_file}"))?;
+
+    Ok(())
+}
+
+/// Fork caches of all forks used in a workspace, stored in a single file
+#[derive(Serialize, Deserialize)]
+struct ForkCacheBundle {
+    cache_version: String,
+    /// Fork caches by their file names
+    caches: BTreeMap<String, ForkCacheContent>,
+}
+
+/// Writes all fork caches of the current version from `cache_dir` to a bundle at `output`.
+/// Returns the number of exported caches.
+pub fn export_fork_caches(cache_dir: &Utf8Path, output: &Utf8Path) -> Result<usize> {
+    let suffix = format!("_v{}.json", cache_version());
+    let mut caches = BTreeMap::new();
+
+    if cache_dir.exists() {
+        for entry in cache_dir.read_dir_utf8()? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            if !file_name.ends_with(&suffix) {
+                continue;
+            }
+
+            let content = fs::read_to_string(entry.path())
+                .with_context(|| format!("Could not read fork cache {}", entry.path()))?;
+            if !content.is_empty() {
+                caches.insert(file_name.to_string(), ForkCacheContent::from_str(&content));
+            }
+        }
+    }
+
+    ensure!(
+        !caches.is_empty(),
+        "No fork caches found in {cache_dir}, run forked tests to create them first"
+    );
+
+    let count = caches.len();
+    let bundle = ForkCacheBundle {
+        cache_version: cache_version(),
+        caches,
+    };
+    // Going through `Value` sorts all keys, so bundles of the same caches are identical
+    let bundle = serde_json::to_string_pretty(&serde_json::to_value(&bundle)?)?;
+    fs::write(output, bundle)
+        .with_context(|| format!("Could not write fork cache bundle to {output}"))?;
+
+    Ok(count)
+}
+
+/// Merges fork caches from a bundle at `input` into `cache_dir`.
+/// Returns the number of imported caches.
+pub fn import_fork_caches(cache_dir: &Utf8Path, input: &Utf8Path) -> Result<usize> {
+    let bundle = fs::read_to_string(input)
+        .with_context(|| format!("Could not read fork cache bundle from {input}"))?;
+    let bundle: ForkCacheBundle = serde_json::from_str(&bundle)
+        .with_context(|| format!("Could not parse fork cache bundle from {input}"))?;
+
+    ensure!(
+        bundle.cache_version == cache_version(),
+        "Fork cache bundle was created with cache version {}, expected {}",
+        bundle.cache_version,
+        cache_version()
+    );
+
+    fs::create_dir_all(cache_dir).context("Fork cache directory could not be created")?;
+
+    for (file_name, content) in &bundle.caches {
+        ensure!(
+            Utf8Path::new(file_name).file_name() == Some(file_name.as_str()),
+            "Invalid fork cache name {file_name} in bundle"
+        );
+
+        write_merged(&cache_dir.join(file_name), content)?;
+    }
+
+    Ok(bundle.caches.len())
 }
 
 fn cache_file_path_from_fork_config(
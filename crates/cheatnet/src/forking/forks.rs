@@ -0,0 +1,243 @@
+use crate::forking::state::ForkStateReader;
+use crate::state::BlockInfoReader;
+use anyhow::{Context, Result, anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use starknet::core::types::{BlockId, MaybePendingBlockWithTxHashes};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_api::block::{BlockInfo, BlockNumber};
+use starknet_api::core::ChainId;
+use starknet_types_core::felt::Felt;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tokio::runtime::Runtime;
+use url::Url;
+
+/// Identifier of a fork, the fork configured with `#[fork]` attribute has id 0
+#[derive(CairoSerialize, CairoDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ForkId(pub u32);
+
+/// Block at which a fork created in a test is pinned
+#[derive(CairoDeserialize, Clone, Debug, PartialEq)]
+pub enum ForkBlockId {
+    Latest,
+    Hash(Felt),
+    Number(u64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fork {
+    pub url: Url,
+    pub block_number: BlockNumber,
+    /// Number of transactions of `block_number` replayed before running the test
+    pub transaction_index: Option<u64>,
+}
+
+impl Fork {
+    /// Creates a reader of the state at this fork
+    pub fn state_reader(&self, cache_dir: &Utf8Path, offline: bool) -> Result<ForkStateReader> {
+        match self.transaction_index {
+            Some(transaction_index) => ForkStateReader::new_at_transaction(
+                self.url.clone(),
+                self.block_number,
+                transaction_index,
+                cache_dir,
+                offline,
+            ),
+            None if offline => {
+                ForkStateReader::new_offline(self.url.clone(), self.block_number, cache_dir)
+            }
+            None => ForkStateReader::new(self.url.clone(), self.block_number, cache_dir),
+        }
+    }
+}
+
+/// Forks available in a test, shared between `CheatnetState` and `MultiForkState`
+pub type SharedForks = Rc<RefCell<Forks>>;
+
+#[derive(Debug, Default)]
+pub struct Forks {
+    cache_dir: Utf8PathBuf,
+    offline: bool,
+    forks: Vec<Fork>,
+    active_fork: Option<ForkId>,
+    /// Reader of a newly selected fork, the state switches to it on the next access
+    pending_fork_state_reader: Option<ForkStateReader>,
+}
+
+impl Forks {
+    #[must_use]
+    pub fn new(cache_dir: &Utf8Path, offline: bool, initial_fork: Option<Fork>) -> Self {
+        Self {
+            cache_dir: cache_dir.to_owned(),
+            offline,
+            active_fork: initial_fork.as_ref().map(|_| ForkId(0)),
+            forks: initial_fork.into_iter().collect(),
+            pending_fork_state_reader: None,
+        }
+    }
+
+    #[must_use]
+    pub fn active_fork(&self) -> Option<ForkId> {
+        self.active_fork
+    }
+
+    pub fn create_fork(&mut self, url: Url, block_id: ForkBlockId) -> Result<ForkId> {
+        let block_number = resolve_block_number(&url, block_id, self.offline)?;
+        let fork_id = ForkId(u32::try_from(self.forks.len())?);
+
+        self.forks.push(Fork {
+            url,
+            block_number,
+            transaction_index: None,
+        });
+
+        Ok(fork_id)
+    }
+
+    /// Makes the fork active and returns its block info and chain id
+    pub fn select_fork(&mut self, fork_id: ForkId) -> Result<(BlockInfo, ChainId)> {
+        let fork = self.get(fork_id)?.clone();
+        let result = self.switch_to(&fork)?;
+        self.active_fork = Some(fork_id);
+
+        Ok(result)
+    }
+
+    /// Moves the active fork to another block and returns its block info and chain id
+    pub fn roll_fork(&mut self, block_number: u64) -> Result<(BlockInfo, ChainId)> {
+        let fork_id = self
+            .active_fork
+            .context("Cannot roll fork, no fork is selected")?;
+        let fork = Fork {
+            url: self.get(fork_id)?.url.clone(),
+            block_number: BlockNumber(block_number),
+            transaction_index: None,
+        };
+        let result = self.switch_to(&fork)?;
+        self.forks[fork_id.0 as usize] = fork;
+
+        Ok(result)
+    }
+
+    pub(crate) fn take_pending_fork_state_reader(&mut self) -> Option<ForkStateReader> {
+        self.pending_fork_state_reader.take()
+    }
+
+    fn get(&self, fork_id: ForkId) -> Result<&Fork> {
+        self.forks
+            .get(fork_id.0 as usize)
+            .ok_or_else(|| anyhow!("Fork with id {} does not exist", fork_id.0))
+    }
+
+    fn switch_to(&mut self, fork: &Fork) -> Result<(BlockInfo, ChainId)> {
+        let mut fork_state_reader = fork.state_reader(&self.cache_dir, self.offline)?;
+        let block_info = fork_state_reader.get_block_info()?;
+        let chain_id = fork_state_reader.chain_id()?;
+
+        self.pending_fork_state_reader = Some(fork_state_reader);
+
+        Ok((block_info, chain_id))
+    }
+}
+
+fn resolve_block_number(url: &Url, block_id: ForkBlockId, offline: bool) -> Result<BlockNumber> {
+    if let ForkBlockId::Number(block_number) = block_id {
+        return Ok(BlockNumber(block_number));
+    }
+    if offline {
+        bail!("Only forks at block numbers can be created in offline mode");
+    }
+
+    let client = JsonRpcClient::new(HttpTransport::new(url.clone()));
+    let runtime = Runtime::new().context("Could not instantiate Runtime")?;
+
+    match block_id {
+        ForkBlockId::Latest => Ok(BlockNumber(
+            runtime
+                .block_on(client.block_number())
+                .context("Failed to fetch latest block number from fork")?,
+        )),
+        ForkBlockId::Hash(hash) => {
+            match runtime
+                .block_on(client.get_block_with_tx_hashes(BlockId::Hash(hash)))
+                .with_context(|| format!("Failed to fetch block with hash {hash:#x} from fork"))?
+            {
+                MaybePendingBlockWithTxHashes::Block(block) => Ok(BlockNumber(block.block_number)),
+                MaybePendingBlockWithTxHashes::PendingBlock(_) => {
+                    bail!("Block with hash {hash:#x} is pending")
+                }
+            }
+        }
+        ForkBlockId::Number(_) => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fork(block_number: u64) -> Fork {
+        Fork {
+            url: Url::parse("http://127.0.0.1:5050/rpc").unwrap(),
+            block_number: BlockNumber(block_number),
+            transaction_index: None,
+        }
+    }
+
+    #[test]
+    fn test_initial_fork_is_active() {
+        let forks = Forks::new(Utf8Path::new("cache"), false, Some(fork(1)));
+        assert_eq!(forks.active_fork(), Some(ForkId(0)));
+
+        let forks = Forks::new(Utf8Path::new("cache"), false, None);
+        assert_eq!(forks.active_fork(), None);
+    }
+
+    #[test]
+    fn test_create_fork_at_block_number() {
+        let mut forks = Forks::new(Utf8Path::new("cache"), false, Some(fork(1)));
+
+        let fork_id = forks
+            .create_fork(fork(2).url, ForkBlockId::Number(2))
+            .unwrap();
+
+        assert_eq!(fork_id, ForkId(1));
+        assert_eq!(forks.get(fork_id).unwrap(), &fork(2));
+        assert_eq!(forks.active_fork(), Some(ForkId(0)));
+    }
+
+    #[test]
+    fn test_select_missing_fork() {
+        let mut forks = Forks::new(Utf8Path::new("cache"), false, None);
+
+        let error = forks.select_fork(ForkId(3)).unwrap_err();
+
+        assert_eq!(error.to_string(), "Fork with id 3 does not exist");
+    }
+
+    #[test]
+    fn test_roll_without_fork() {
+        let mut forks = Forks::new(Utf8Path::new("cache"), false, None);
+
+        let error = forks.roll_fork(10).unwrap_err();
+
+        assert_eq!(error.to_string(), "Cannot roll fork, no fork is selected");
+    }
+
+    #[test]
+    fn test_create_fork_at_latest_block_offline() {
+        let mut forks = Forks::new(Utf8Path::new("cache"), true, None);
+
+        let error = forks
+            .create_fork(fork(1).url, ForkBlockId::Latest)
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Only forks at block numbers can be created in offline mode"
+        );
+    }
+}
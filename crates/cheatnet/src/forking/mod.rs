@@ -1,2 +1,5 @@
 pub mod cache;
+pub mod forks;
+pub mod multi_fork_state;
+pub mod replay;
 pub mod state;
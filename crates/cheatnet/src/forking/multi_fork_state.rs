@@ -0,0 +1,282 @@
+use crate::forking::forks::SharedForks;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::contract_state::SharedPendingNonces;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::state_diff::{
+    SharedStateChanges, StateChange,
+};
+use crate::state::ExtendedStateReader;
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::cached_state::{CachedState, ContractClassMapping, StateMaps};
+use blockifier::state::state_api::{State, StateReader, StateResult};
+use starknet_api::core::{ClassHash, CompiledClassHash, ContractAddress, Nonce};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// State of a test which can be switched between forks with cheatcodes.
+/// Classes declared and contracts deployed in the test are carried over to the selected fork,
+/// changes made to contracts of the previous fork are discarded.
+/// Changes made to the state are recorded in `state_changes` once recording is enabled.
+/// Nonces set with cheatcodes are written on the next access.
+pub struct MultiForkState {
+    state: RefCell<CachedState<ExtendedStateReader>>,
+    forks: SharedForks,
+    state_changes: SharedStateChanges,
+    pending_nonces: SharedPendingNonces,
+}
+
+impl MultiForkState {
+    #[must_use]
+    pub fn new(
+        state_reader: ExtendedStateReader,
+        forks: SharedForks,
+        state_changes: SharedStateChanges,
+        pending_nonces: SharedPendingNonces,
+    ) -> Self {
+        Self {
+            state: RefCell::new(CachedState::new(state_reader)),
+            forks,
+            state_changes,
+            pending_nonces,
+        }
+    }
+
+    /// Returns the underlying state of the active fork
+    pub fn cached_state(&mut self) -> StateResult<&mut CachedState<ExtendedStateReader>> {
+        self.apply_pending_changes()?;
+        Ok(self.state.get_mut())
+    }
+
+    /// Switches to the fork selected with cheatcodes and writes nonces set with them
+    fn apply_pending_changes(&self) -> StateResult<()> {
+        self.switch_fork_if_selected()?;
+        self.write_pending_nonces()
+    }
+
+    fn switch_fork_if_selected(&self) -> StateResult<()> {
+        let Some(fork_state_reader) = self.forks.borrow_mut().take_pending_fork_state_reader()
+        else {
+            return Ok(());
+        };
+
+        let mut state = self.state.borrow_mut();
+        let (local_changes, local_classes) = local_changes(&mut state)?;
+        let dict_state_reader = std::mem::take(&mut state.state.dict_state_reader);
+        if let Some(previous_fork_state_reader) = &state.state.fork_state_reader {
+            fork_state_reader.extend_cache_misses(previous_fork_state_reader.cache_misses());
+        }
+
+        *state = CachedState::new(ExtendedStateReader {
+            dict_state_reader,
+            fork_state_reader: Some(fork_state_reader),
+        });
+        state.update_cache(&local_changes, local_classes);
+
+        Ok(())
+    }
+
+    /// Writes nonces directly to the cache, as `State` only allows incrementing them
+    fn write_pending_nonces(&self) -> StateResult<()> {
+        let nonces = std::mem::take(&mut *self.pending_nonces.borrow_mut());
+        if nonces.is_empty() {
+            return Ok(());
+        }
+
+        let mut state = self.state.borrow_mut();
+        if self.is_recording_changes() {
+            for (&contract_address, &new_nonce) in &nonces {
+                let old_nonce = state.get_nonce_at(contract_address)?;
+                self.record_change(StateChange::Nonce {
+                    contract_address,
+                    old_nonce,
+                    new_nonce,
+                });
+            }
+        }
+
+        state.update_cache(
+            &StateMaps {
+                nonces,
+                ..StateMaps::default()
+            },
+            ContractClassMapping::new(),
+        );
+
+        Ok(())
+    }
+
+    fn is_recording_changes(&self) -> bool {
+        self.state_changes.borrow().is_recording
+    }
+
+    fn record_change(&self, change: StateChange) {
+        self.state_changes.borrow_mut().changes.push(change);
+    }
+}
+
+/// Returns changes of classes declared and contracts deployed in the test
+fn local_changes(
+    state: &mut CachedState<ExtendedStateReader>,
+) -> StateResult<(StateMaps, ContractClassMapping)> {
+    let changes = state.to_state_diff()?.state_maps;
+
+    let local_contracts: HashSet<ContractAddress> = changes
+        .class_hashes
+        .keys()
+        .chain(state.state.dict_state_reader.address_to_class_hash.keys())
+        .copied()
+        .collect();
+    let declared_contracts: HashMap<ClassHash, bool> = changes
+        .declared_contracts
+        .into_iter()
+        .filter(|(_, declared)| *declared)
+        .collect();
+
+    let mut classes = ContractClassMapping::new();
+    for class_hash in declared_contracts.keys() {
+        classes.insert(*class_hash, state.get_compiled_class(*class_hash)?);
+    }
+
+    let changes = StateMaps {
+        nonces: changes
+            .nonces
+            .into_iter()
+            .filter(|(contract_address, _)| local_contracts.contains(contract_address))
+            .collect(),
+        class_hashes: changes.class_hashes,
+        storage: changes
+            .storage
+            .into_iter()
+            .filter(|((contract_address, _), _)| local_contracts.contains(contract_address))
+            .collect(),
+        compiled_class_hashes: changes
+            .compiled_class_hashes
+            .into_iter()
+            .filter(|(class_hash, _)| declared_contracts.contains_key(class_hash))
+            .collect(),
+        declared_contracts,
+    };
+
+    Ok((changes, classes))
+}
+
+impl StateReader for MultiForkState {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        self.apply_pending_changes()?;
+        self.state.borrow().get_storage_at(contract_address, key)
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.apply_pending_changes()?;
+        self.state.borrow().get_nonce_at(contract_address)
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.apply_pending_changes()?;
+        self.state.borrow().get_class_hash_at(contract_address)
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.apply_pending_changes()?;
+        self.state.borrow().get_compiled_class(class_hash)
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        self.apply_pending_changes()?;
+        self.state.borrow().get_compiled_class_hash(class_hash)
+    }
+}
+
+impl State for MultiForkState {
+    fn set_storage_at(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        value: Felt,
+    ) -> StateResult<()> {
+        let is_recording = self.is_recording_changes();
+        let state = self.cached_state()?;
+        if !is_recording {
+            return state.set_storage_at(contract_address, key, value);
+        }
+
+        let old_value = state.get_storage_at(contract_address, key)?;
+        state.set_storage_at(contract_address, key, value)?;
+
+        self.record_change(StateChange::Storage {
+            contract_address,
+            key,
+            old_value,
+            new_value: value,
+        });
+        Ok(())
+    }
+
+    fn increment_nonce(&mut self, contract_address: ContractAddress) -> StateResult<()> {
+        let is_recording = self.is_recording_changes();
+        let state = self.cached_state()?;
+        if !is_recording {
+            return state.increment_nonce(contract_address);
+        }
+
+        let old_nonce = state.get_nonce_at(contract_address)?;
+        state.increment_nonce(contract_address)?;
+        let new_nonce = state.get_nonce_at(contract_address)?;
+
+        self.record_change(StateChange::Nonce {
+            contract_address,
+            old_nonce,
+            new_nonce,
+        });
+        Ok(())
+    }
+
+    fn set_class_hash_at(
+        &mut self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+    ) -> StateResult<()> {
+        let is_recording = self.is_recording_changes();
+        let state = self.cached_state()?;
+        if !is_recording {
+            return state.set_class_hash_at(contract_address, class_hash);
+        }
+
+        let old_class_hash = state.get_class_hash_at(contract_address)?;
+        state.set_class_hash_at(contract_address, class_hash)?;
+
+        self.record_change(StateChange::ClassHash {
+            contract_address,
+            old_class_hash,
+            new_class_hash: class_hash,
+        });
+        Ok(())
+    }
+
+    fn set_contract_class(
+        &mut self,
+        class_hash: ClassHash,
+        contract_class: RunnableCompiledClass,
+    ) -> StateResult<()> {
+        self.cached_state()?
+            .set_contract_class(class_hash, contract_class)?;
+
+        if self.is_recording_changes() {
+            self.record_change(StateChange::DeclaredClass { class_hash });
+        }
+        Ok(())
+    }
+
+    fn set_compiled_class_hash(
+        &mut self,
+        class_hash: ClassHash,
+        compiled_class_hash: CompiledClassHash,
+    ) -> StateResult<()> {
+        self.cached_state()?
+            .set_compiled_class_hash(class_hash, compiled_class_hash)
+    }
+}
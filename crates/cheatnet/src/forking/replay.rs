@@ -0,0 +1,172 @@
+use crate::forking::state::ForkStateReader;
+use crate::state::BlockInfoReader;
+use anyhow::{Context, Result, ensure};
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::state_api::StateReader;
+use camino::Utf8Path;
+use conversions::FromConv;
+use starknet::core::types::{StateDiff, TransactionTrace};
+use starknet_api::block::{BlockInfo, BlockNumber};
+use starknet_api::core::{ClassHash, CompiledClassHash, ContractAddress, Nonce, PatriciaKey};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use url::Url;
+
+/// State changes of the first transactions of a block, read on top of the state
+/// of the block preceding it
+#[derive(Debug)]
+pub(crate) struct ReplayedState {
+    pub block_info: BlockInfo,
+    pub storage: HashMap<(ContractAddress, StorageKey), Felt>,
+    pub nonces: HashMap<ContractAddress, Nonce>,
+    pub class_hashes: HashMap<ContractAddress, ClassHash>,
+    pub compiled_classes: HashMap<ClassHash, RunnableCompiledClass>,
+    pub compiled_class_hashes: HashMap<ClassHash, CompiledClassHash>,
+}
+
+impl ForkStateReader {
+    /// Creates a reader of the state of `block_number` after its first `transaction_index`
+    /// transactions, i.e. of the preceding block with state diffs of these transactions applied.
+    /// The reader returns block info of `block_number`, as tests run within it.
+    pub fn new_at_transaction(
+        url: Url,
+        block_number: BlockNumber,
+        transaction_index: u64,
+        cache_dir: &Utf8Path,
+        offline: bool,
+    ) -> Result<Self> {
+        ensure!(
+            block_number.0 > 0,
+            "Transactions from the genesis block cannot be replayed"
+        );
+        let state_block_number = BlockNumber(block_number.0 - 1);
+
+        let mut reader = if offline {
+            ForkStateReader::new_offline(url.clone(), state_block_number, cache_dir)?
+        } else {
+            ForkStateReader::new(url.clone(), state_block_number, cache_dir)?
+        };
+        let replayed_state =
+            replay_transactions(url, block_number, transaction_index, cache_dir, offline)?;
+        reader.set_replayed_state(replayed_state);
+
+        Ok(reader)
+    }
+}
+
+fn replay_transactions(
+    url: Url,
+    block_number: BlockNumber,
+    transaction_index: u64,
+    cache_dir: &Utf8Path,
+    offline: bool,
+) -> Result<ReplayedState> {
+    // Classes declared in the replayed transactions are only available from `block_number`
+    let mut block_reader = if offline {
+        ForkStateReader::new_offline(url, block_number, cache_dir)?
+    } else {
+        ForkStateReader::new(url, block_number, cache_dir)?
+    };
+    let traces = block_reader.block_transaction_traces()?;
+
+    let transaction_index = usize::try_from(transaction_index)?;
+    ensure!(
+        transaction_index <= traces.len(),
+        "Block {} contains {} transactions, cannot replay {transaction_index} of them",
+        block_number.0,
+        traces.len(),
+    );
+
+    let mut replayed_state = ReplayedState {
+        block_info: block_reader.get_block_info()?,
+        storage: HashMap::new(),
+        nonces: HashMap::new(),
+        class_hashes: HashMap::new(),
+        compiled_classes: HashMap::new(),
+        compiled_class_hashes: HashMap::new(),
+    };
+
+    for trace in traces.iter().take(transaction_index) {
+        let state_diff = state_diff(&trace.trace_root).with_context(|| {
+            format!(
+                "Node did not return state diff of transaction {:#x}",
+                trace.transaction_hash
+            )
+        })?;
+
+        apply_state_diff(&mut replayed_state, &block_reader, state_diff)?;
+    }
+
+    Ok(replayed_state)
+}
+
+fn state_diff(trace: &TransactionTrace) -> Option<&StateDiff> {
+    match trace {
+        TransactionTrace::Invoke(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::DeployAccount(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::L1Handler(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::Declare(trace) => trace.state_diff.as_ref(),
+    }
+}
+
+fn apply_state_diff(
+    replayed_state: &mut ReplayedState,
+    block_reader: &ForkStateReader,
+    state_diff: &StateDiff,
+) -> Result<()> {
+    for class_hash in &state_diff.deprecated_declared_classes {
+        let class_hash = ClassHash::from_(*class_hash);
+        replayed_state
+            .compiled_classes
+            .insert(class_hash, block_reader.get_compiled_class(class_hash)?);
+    }
+
+    for declared_class in &state_diff.declared_classes {
+        let class_hash = ClassHash::from_(declared_class.class_hash);
+        replayed_state
+            .compiled_classes
+            .insert(class_hash, block_reader.get_compiled_class(class_hash)?);
+        replayed_state.compiled_class_hashes.insert(
+            class_hash,
+            CompiledClassHash(declared_class.compiled_class_hash),
+        );
+    }
+
+    for deployed_contract in &state_diff.deployed_contracts {
+        replayed_state.class_hashes.insert(
+            ContractAddress::from_(deployed_contract.address),
+            ClassHash::from_(deployed_contract.class_hash),
+        );
+    }
+
+    for replaced_class in &state_diff.replaced_classes {
+        replayed_state.class_hashes.insert(
+            ContractAddress::from_(replaced_class.contract_address),
+            ClassHash::from_(replaced_class.class_hash),
+        );
+    }
+
+    for storage_diff in &state_diff.storage_diffs {
+        let contract_address = ContractAddress::from_(storage_diff.address);
+
+        for entry in &storage_diff.storage_entries {
+            replayed_state.storage.insert(
+                (
+                    contract_address,
+                    StorageKey(PatriciaKey::try_from(entry.key)?),
+                ),
+                entry.value,
+            );
+        }
+    }
+
+    for nonce_update in &state_diff.nonces {
+        replayed_state.nonces.insert(
+            ContractAddress::from_(nonce_update.contract_address),
+            Nonce(nonce_update.nonce),
+        );
+    }
+
+    Ok(())
+}
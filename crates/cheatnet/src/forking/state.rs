@@ -1,4 +1,6 @@
+use crate::constants::default_block_hash;
 use crate::forking::cache::ForkCache;
+use crate::forking::replay::ReplayedState;
 use crate::state::BlockInfoReader;
 use anyhow::{Context, Result};
 use blockifier::execution::contract_class::{
@@ -15,6 +17,7 @@ use num_bigint::BigUint;
 use runtime::starknet::context::SerializableGasPrices;
 use starknet::core::types::{
     BlockId, ContractClass as ContractClassStarknet, MaybePendingBlockWithTxHashes, StarknetError,
+    TransactionTraceWithHash,
 };
 use starknet::core::utils::parse_cairo_short_string;
 use starknet::providers::jsonrpc::HttpTransport;
@@ -37,6 +40,12 @@ pub struct ForkStateReader {
     block_number: BlockNumber,
     runtime: Runtime,
     cache: RefCell<ForkCache>,
+    /// Fail on cache misses instead of fetching data from the node
+    offline: bool,
+    /// Changes of transactions replayed on top of the forked block
+    replayed_state: Option<ReplayedState>,
+    /// Data missing from the fork cache in offline mode, in the order of access
+    cache_misses: RefCell<Vec<String>>,
 }
 
 impl ForkStateReader {
@@ -49,18 +58,141 @@ impl ForkStateReader {
             client: JsonRpcClient::new(HttpTransport::new(url)),
             block_number,
             runtime: Runtime::new().expect("Could not instantiate Runtime"),
+            offline: false,
+            replayed_state: None,
+            cache_misses: RefCell::default(),
+        })
+    }
+
+    /// Creates a reader serving data only from an existing fork cache
+    pub fn new_offline(url: Url, block_number: BlockNumber, cache_dir: &Utf8Path) -> Result<Self> {
+        Ok(ForkStateReader {
+            cache: RefCell::new(ForkCache::load_offline(&url, block_number, cache_dir)?),
+            client: JsonRpcClient::new(HttpTransport::new(url)),
+            block_number,
+            runtime: Runtime::new().expect("Could not instantiate Runtime"),
+            offline: true,
+            replayed_state: None,
+            cache_misses: RefCell::default(),
         })
     }
 
     pub fn chain_id(&self) -> Result<ChainId> {
+        if let Some(cache_hit) = self.cache.borrow().get_chain_id() {
+            return Ok(ChainId::from(cache_hit));
+        }
+        if self.offline {
+            return Err(self.cache_miss("chain id").into());
+        }
+
         let id = self.runtime.block_on(self.client.chain_id())?;
         let id = parse_cairo_short_string(&id)?;
+        self.cache.borrow_mut().cache_get_chain_id(id.clone());
+
         Ok(ChainId::from(id))
     }
 
+    pub fn block_transaction_traces(&self) -> Result<Vec<TransactionTraceWithHash>> {
+        if let Some(cache_hit) = self.cache.borrow().get_block_transaction_traces() {
+            return Ok(cache_hit);
+        }
+        if self.offline {
+            return Err(self.cache_miss("transaction traces").into());
+        }
+
+        let traces = self
+            .runtime
+            .block_on(self.client.trace_block_transactions(self.block_id()))
+            .with_context(|| {
+                format!(
+                    "Unable to get transaction traces of block {} from fork",
+                    self.block_number.0
+                )
+            })?;
+        self.cache
+            .borrow_mut()
+            .cache_get_block_transaction_traces(traces.clone());
+
+        Ok(traces)
+    }
+
+    /// Returns hash of a block preceding the forked one, or the default hash of a block produced
+    /// by the test after it (e.g. with `advance_blocks`)
+    pub fn get_block_hash(&self, block_number: u64) -> StateResult<Felt> {
+        if block_number > self.block_number.0 {
+            return Ok(default_block_hash(block_number));
+        }
+        if let Some(cache_hit) = self.cache.borrow().get_block_hash_at(block_number) {
+            return Ok(cache_hit);
+        }
+        if self.offline {
+            return Err(self.cache_miss(&format!("hash of block {block_number}")));
+        }
+
+        match self.runtime.block_on(
+            self.client
+                .get_block_with_tx_hashes(BlockId::Number(block_number)),
+        ) {
+            Ok(MaybePendingBlockWithTxHashes::Block(block)) => {
+                self.cache
+                    .borrow_mut()
+                    .cache_get_block_hash_at(block_number, block.block_hash);
+                Ok(block.block_hash)
+            }
+            Ok(MaybePendingBlockWithTxHashes::PendingBlock(_)) => {
+                unreachable!("Blocks preceding the forked one cannot be pending")
+            }
+            Err(ProviderError::Other(boxed)) => other_provider_error(boxed),
+            Err(err) => Err(StateReadError(format!(
+                "Unable to get hash of block {block_number} from fork ({err})"
+            ))),
+        }
+    }
+
+    /// Returns data which was missing from the fork cache in offline mode
+    #[must_use]
+    pub fn cache_misses(&self) -> Vec<String> {
+        self.cache_misses.borrow().clone()
+    }
+
+    /// Keeps cache misses of the reader of another fork, so they are reported together
+    pub(crate) fn extend_cache_misses(&self, cache_misses: Vec<String>) {
+        for missing in cache_misses {
+            self.push_cache_miss(missing);
+        }
+    }
+
+    pub(crate) fn set_replayed_state(&mut self, replayed_state: ReplayedState) {
+        self.replayed_state = Some(replayed_state);
+    }
+
     fn block_id(&self) -> BlockId {
         BlockId::Number(self.block_number.0)
     }
+
+    /// Records data missing from the fork cache and returns the error for misses which
+    /// the execution cannot continue without
+    fn cache_miss(&self, missing: &str) -> StateError {
+        self.record_cache_miss(missing);
+
+        StateReadError(format!(
+            "Fork cache of block {} does not contain {missing}, it cannot be fetched in offline mode",
+            self.block_number.0
+        ))
+    }
+
+    /// Records data missing from the fork cache, which is read as zero instead,
+    /// so the test keeps running and all misses are reported together
+    fn record_cache_miss(&self, missing: &str) {
+        self.push_cache_miss(format!("{missing} at block {}", self.block_number.0));
+    }
+
+    fn push_cache_miss(&self, missing: String) {
+        let mut cache_misses = self.cache_misses.borrow_mut();
+        if !cache_misses.contains(&missing) {
+            cache_misses.push(missing);
+        }
+    }
 }
 
 #[expect(clippy::needless_pass_by_value)]
@@ -78,9 +210,15 @@ fn other_provider_error<T>(boxed: impl ToString) -> Result<T, StateError> {
 
 impl BlockInfoReader for ForkStateReader {
     fn get_block_info(&mut self) -> StateResult<BlockInfo> {
+        if let Some(replayed_state) = &self.replayed_state {
+            return Ok(replayed_state.block_info.clone());
+        }
         if let Some(cache_hit) = self.cache.borrow().get_block_info() {
             return Ok(cache_hit);
         }
+        if self.offline {
+            return Err(self.cache_miss("block info"));
+        }
 
         match self
             .runtime
@@ -118,9 +256,24 @@ impl StateReader for ForkStateReader {
         contract_address: ContractAddress,
         key: StorageKey,
     ) -> StateResult<Felt> {
+        if let Some(replayed) = self
+            .replayed_state
+            .as_ref()
+            .and_then(|replayed_state| replayed_state.storage.get(&(contract_address, key)))
+        {
+            return Ok(*replayed);
+        }
         if let Some(cache_hit) = self.cache.borrow().get_storage_at(&contract_address, &key) {
             return Ok(cache_hit);
         }
+        if self.offline {
+            self.record_cache_miss(&format!(
+                "storage of contract {:#x} at key {:#x}",
+                Felt::from_(contract_address),
+                Felt::from_(*key.0.key())
+            ));
+            return Ok(Felt::default());
+        }
 
         match self.runtime.block_on(self.client.get_storage_at(
             Felt::from_(contract_address),
@@ -150,9 +303,23 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        if let Some(replayed) = self
+            .replayed_state
+            .as_ref()
+            .and_then(|replayed_state| replayed_state.nonces.get(&contract_address))
+        {
+            return Ok(*replayed);
+        }
         if let Some(cache_hit) = self.cache.borrow().get_nonce_at(&contract_address) {
             return Ok(cache_hit);
         }
+        if self.offline {
+            self.record_cache_miss(&format!(
+                "nonce of contract {:#x}",
+                Felt::from_(contract_address)
+            ));
+            return Ok(Nonce::default());
+        }
 
         match self.runtime.block_on(
             self.client
@@ -179,9 +346,23 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        if let Some(replayed) = self
+            .replayed_state
+            .as_ref()
+            .and_then(|replayed_state| replayed_state.class_hashes.get(&contract_address))
+        {
+            return Ok(*replayed);
+        }
         if let Some(cache_hit) = self.cache.borrow().get_class_hash_at(&contract_address) {
             return Ok(cache_hit);
         }
+        if self.offline {
+            self.record_cache_miss(&format!(
+                "class hash of contract {:#x}",
+                Felt::from_(contract_address)
+            ));
+            return Ok(ClassHash::default());
+        }
 
         match self.runtime.block_on(
             self.client
@@ -208,11 +389,20 @@ impl StateReader for ForkStateReader {
     }
 
     fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        if let Some(replayed) = self
+            .replayed_state
+            .as_ref()
+            .and_then(|replayed_state| replayed_state.compiled_classes.get(&class_hash))
+        {
+            return Ok(replayed.clone());
+        }
         let mut cache = self.cache.borrow_mut();
 
         let contract_class = {
             if let Some(cache_hit) = cache.get_compiled_contract_class(&class_hash) {
                 Ok(cache_hit)
+            } else if self.offline {
+                Err(self.cache_miss(&format!("class {:#x}", class_hash.0)))
             } else {
                 match self.runtime.block_on(
                     self.client
@@ -280,7 +470,14 @@ impl StateReader for ForkStateReader {
         }
     }
 
-    fn get_compiled_class_hash(&self, _class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        if let Some(replayed) = self
+            .replayed_state
+            .as_ref()
+            .and_then(|replayed_state| replayed_state.compiled_class_hashes.get(&class_hash))
+        {
+            return Ok(*replayed);
+        }
         Err(StateReadError(
             "Unable to get compiled class hash from the fork".to_string(),
         ))
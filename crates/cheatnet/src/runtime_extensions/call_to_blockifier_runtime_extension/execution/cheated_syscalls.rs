@@ -2,7 +2,8 @@ use crate::runtime_extensions::call_to_blockifier_runtime_extension::CheatnetSta
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_constructor_entry_point;
 use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
 use blockifier::execution::syscalls::{
-    DeployRequest, DeployResponse, LibraryCallRequest, SyscallResponse, syscall_base::SyscallResult,
+    DeployRequest, DeployResponse, GetBlockHashRequest, GetBlockHashResponse, LibraryCallRequest,
+    SyscallResponse, get_block_hash, syscall_base::SyscallResult,
 };
 use blockifier::execution::{call_info::CallInfo, entry_point::ConstructorContext};
 use blockifier::execution::{
@@ -26,6 +27,7 @@ use blockifier::{
 };
 use cairo_vm::types::relocatable::Relocatable;
 use cairo_vm::vm::vm_core::VirtualMachine;
+use starknet_api::block::BlockHash;
 use starknet_api::core::calculate_contract_address;
 use starknet_api::{
     contract_class::EntryPointType,
@@ -55,6 +57,25 @@ pub fn get_execution_info_syscall(
     })
 }
 
+pub fn get_block_hash_syscall(
+    request: GetBlockHashRequest,
+    vm: &mut VirtualMachine,
+    syscall_handler: &mut SyscallHintProcessor<'_>,
+    cheatnet_state: &mut CheatnetState,
+    remaining_gas: &mut u64,
+) -> SyscallResult<GetBlockHashResponse> {
+    let cheated_data = cheatnet_state.get_cheated_data(syscall_handler.storage_address());
+
+    // Cheated hashes are returned for any block, including the ones too recent to be queried
+    if let Some(block_hash) = cheated_data.block_hashes.get(&request.block_number.0) {
+        return Ok(GetBlockHashResponse {
+            block_hash: BlockHash(*block_hash),
+        });
+    }
+
+    get_block_hash(request, vm, syscall_handler, remaining_gas)
+}
+
 // blockifier/src/execution/syscalls/mod.rs:222 (deploy_syscall)
 pub fn deploy_syscall(
     request: DeployRequest,
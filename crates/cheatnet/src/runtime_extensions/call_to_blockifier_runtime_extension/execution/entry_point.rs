@@ -1,9 +1,9 @@
 use super::cairo1_execution::execute_entry_point_call_cairo1;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::execution::deprecated::cairo0_execution::execute_entry_point_call_cairo0;
-use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{AddressOrClassHash, CallResult};
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{AddressOrClassHash, CallFailure, CallResult};
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::CheatnetState;
 use crate::runtime_extensions::common::{get_relocated_vm_trace, sum_syscall_counters};
-use crate::state::{CallTrace, CallTraceNode, CheatStatus, EncounteredError};
+use crate::state::{CallTrace, CallTraceNode, CheatStatus, EncounteredError, TargetCall};
 use blockifier::execution::call_info::{CallExecution, Retdata};
 use blockifier::execution::contract_class::{RunnableCompiledClass, TrackedResource};
 use blockifier::execution::deprecated_syscalls::hint_processor::SyscallCounter;
@@ -54,8 +54,9 @@ pub fn execute_call_entry_point(
             .clone()
     } else {
         let contract_address = entry_point.storage_address;
-        let cheated_data_ = cheatnet_state.create_cheated_data(contract_address);
-        cheatnet_state.update_cheats(&contract_address);
+        let call = TargetCall::from(&*entry_point);
+        let cheated_data_ = cheatnet_state.create_cheated_data(contract_address, &call);
+        cheatnet_state.update_cheats(&contract_address, &call);
         cheated_data_
     };
 
@@ -66,8 +67,9 @@ pub fn execute_call_entry_point(
         .enter_nested_call(entry_point.clone(), cheated_data);
 
     if let Some(cheat_status) = get_mocked_function_cheat_status(entry_point, cheatnet_state) {
-        if let CheatStatus::Cheated(ret_data, _) = (*cheat_status).clone() {
-            cheat_status.decrement_cheat_span();
+        let call = TargetCall::from(&*entry_point);
+        if let Some(ret_data) = cheat_status.as_value_for_call(&call) {
+            cheat_status.progress_cheat_span(&call);
             let ret_data_f252: Vec<Felt> =
                 ret_data.iter().map(|datum| Felt::from_(*datum)).collect();
             cheatnet_state.trace_data.exit_nested_call(
@@ -79,9 +81,30 @@ pub fn execute_call_entry_point(
                 &[],
                 None,
             );
-            return Ok(mocked_call_info(entry_point.clone(), ret_data.clone()));
+            return Ok(mocked_call_info(entry_point.clone(), ret_data));
         }
     }
+
+    if let Some(result) = cheatnet_state.call_mocked_contract(entry_point) {
+        let (ret_data, call_result) = match result {
+            Ok(ret_data) => (ret_data.clone(), CallResult::Success { ret_data }),
+            Err(panic_data) => (
+                panic_data.clone(),
+                CallResult::Failure(CallFailure::Panic { panic_data }),
+            ),
+        };
+        let failed = matches!(call_result, CallResult::Failure(_));
+        cheatnet_state.trace_data.exit_nested_call(
+            ExecutionResources::default(),
+            HashMap::default(),
+            call_result,
+            &[],
+            None,
+        );
+        let mut call_info = mocked_call_info(entry_point.clone(), ret_data);
+        call_info.execution.failed = failed;
+        return Ok(call_info);
+    }
     // endregion
 
     // Validate contract is deployed.
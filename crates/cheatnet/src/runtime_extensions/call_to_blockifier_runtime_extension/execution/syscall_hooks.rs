@@ -4,11 +4,20 @@ use crate::{
     state::CheatnetState,
 };
 use blockifier::execution::call_info::OrderedL2ToL1Message;
+use blockifier::execution::deprecated_syscalls::{
+    self, DeprecatedSyscallSelector, hint_processor::DeprecatedSyscallExecutionError,
+};
+use blockifier::execution::syscalls::{
+    self, SyscallRequest, hint_processor::SyscallExecutionError,
+};
 use blockifier::execution::{
     call_info::OrderedEvent, deprecated_syscalls::hint_processor::DeprecatedSyscallHintProcessor,
     syscalls::hint_processor::SyscallHintProcessor,
 };
+use cairo_vm::vm::errors::hint_errors::HintError;
+use cairo_vm::vm::vm_core::VirtualMachine;
 use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
 
 pub trait SyscallHintProcessorExt {
     fn contract_address(&self) -> ContractAddress;
@@ -68,4 +77,107 @@ pub fn send_message_to_l1_syscall_hook(
             last_message,
             contract_address,
         ));
+    cheatnet_state.register_message_to_l1(
+        contract_address,
+        last_message.message.to_address,
+        last_message.message.payload.0.clone(),
+    );
+}
+
+/// Records the storage access before the syscall is executed, as its request cannot be read afterwards
+pub fn storage_access_hook(
+    selector: DeprecatedSyscallSelector,
+    vm: &VirtualMachine,
+    syscall_handler: &SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+) -> Result<(), HintError> {
+    if !cheatnet_state.is_recording_storage_accesses {
+        return Ok(());
+    }
+
+    let contract_address = syscall_handler.storage_address();
+    // Request is preceded by the selector and the gas counter
+    let mut request_ptr = (syscall_handler.syscall_ptr + 2)?;
+
+    match selector {
+        DeprecatedSyscallSelector::StorageRead => {
+            let request = syscalls::StorageReadRequest::read(vm, &mut request_ptr)?;
+            // Syscall fails for other address domains
+            if request.address_domain != Felt::ZERO {
+                return Ok(());
+            }
+            let value = syscall_handler
+                .base
+                .state
+                .get_storage_at(contract_address, request.address)
+                .map_err(SyscallExecutionError::from)?;
+
+            cheatnet_state.add_storage_read(contract_address, request.address, value);
+        }
+        DeprecatedSyscallSelector::StorageWrite => {
+            let request = syscalls::StorageWriteRequest::read(vm, &mut request_ptr)?;
+            if request.address_domain != Felt::ZERO {
+                return Ok(());
+            }
+            let old_value = syscall_handler
+                .base
+                .state
+                .get_storage_at(contract_address, request.address)
+                .map_err(SyscallExecutionError::from)?;
+
+            cheatnet_state.add_storage_write(
+                contract_address,
+                request.address,
+                old_value,
+                request.value,
+            );
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+pub fn deprecated_storage_access_hook(
+    selector: DeprecatedSyscallSelector,
+    vm: &VirtualMachine,
+    syscall_handler: &DeprecatedSyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+) -> Result<(), HintError> {
+    if !cheatnet_state.is_recording_storage_accesses {
+        return Ok(());
+    }
+
+    let contract_address = syscall_handler.storage_address;
+    // Request is preceded by the selector
+    let mut request_ptr = (syscall_handler.syscall_ptr + 1)?;
+
+    match selector {
+        DeprecatedSyscallSelector::StorageRead => {
+            let request = <deprecated_syscalls::StorageReadRequest as deprecated_syscalls::SyscallRequest>::read(vm, &mut request_ptr)?;
+            let value = syscall_handler
+                .state
+                .get_storage_at(contract_address, request.address)
+                .map_err(DeprecatedSyscallExecutionError::from)?;
+
+            cheatnet_state.add_storage_read(contract_address, request.address, value);
+        }
+        DeprecatedSyscallSelector::StorageWrite => {
+            let request = <deprecated_syscalls::StorageWriteRequest as deprecated_syscalls::SyscallRequest>::read(vm, &mut request_ptr)?;
+            let old_value = syscall_handler
+                .state
+                .get_storage_at(contract_address, request.address)
+                .map_err(DeprecatedSyscallExecutionError::from)?;
+
+            cheatnet_state.add_storage_write(
+                contract_address,
+                request.address,
+                old_value,
+                request.value,
+            );
+        }
+        _ => {}
+    }
+
+    Ok(())
 }
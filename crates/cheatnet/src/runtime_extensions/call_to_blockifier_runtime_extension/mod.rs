@@ -1,5 +1,3 @@
-use std::marker::PhantomData;
-
 use blockifier::execution::entry_point::{CallEntryPoint, CallType};
 use blockifier::execution::execution_utils::felt_from_ptr;
 use blockifier::execution::syscalls::{
@@ -28,6 +26,8 @@ use crate::runtime_extensions::call_to_blockifier_runtime_extension::{
     execution::cheated_syscalls::SingleSegmentResponse,
     rpc::{CallFailure, CallResult},
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect::default_ret_data;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 
 use super::cheatable_starknet_runtime_extension::CheatableStarknetRuntime;
 use conversions::string::TryFromHexStr;
@@ -38,7 +38,7 @@ pub mod panic_data;
 pub mod rpc;
 
 pub struct CallToBlockifierExtension<'a> {
-    pub lifetime: &'a PhantomData<()>,
+    pub contracts_data: &'a ContractsData,
 }
 
 pub type CallToBlockifierRuntime<'a> = ExtendedRuntime<CallToBlockifierExtension<'a>>;
@@ -59,7 +59,7 @@ impl<'a> ExtensionLogic for CallToBlockifierExtension<'a> {
             // since call errors cannot be handled on real starknet
             // https://docs.starknet.io/architecture-and-concepts/smart-contracts/system-calls-cairo1/#call_contract
             DeprecatedSyscallSelector::CallContract => {
-                execute_syscall::<CallContractRequest>(vm, extended_runtime)?;
+                execute_syscall::<CallContractRequest>(vm, extended_runtime, self.contracts_data)?;
 
                 extended_runtime
                     .extended_runtime
@@ -69,7 +69,7 @@ impl<'a> ExtensionLogic for CallToBlockifierExtension<'a> {
                 Ok(SyscallHandlingResult::Handled)
             }
             DeprecatedSyscallSelector::LibraryCall => {
-                execute_syscall::<LibraryCallRequest>(vm, extended_runtime)?;
+                execute_syscall::<LibraryCallRequest>(vm, extended_runtime, self.contracts_data)?;
 
                 extended_runtime
                     .extended_runtime
@@ -155,6 +155,7 @@ impl ExecuteCall for LibraryCallRequest {
 fn execute_syscall<Request: ExecuteCall + SyscallRequest>(
     vm: &mut VirtualMachine,
     cheatable_starknet_runtime: &mut CheatableStarknetRuntime,
+    contracts_data: &ContractsData,
 ) -> Result<(), HintError> {
     let _selector = felt_from_ptr(vm, cheatable_starknet_runtime.get_mut_syscall_ptr())?;
 
@@ -169,7 +170,22 @@ fn execute_syscall<Request: ExecuteCall + SyscallRequest>(
     let cheatnet_state = &mut *cheatable_starknet_runtime.extension.cheatnet_state;
     let syscall_handler = &mut cheatable_starknet_runtime.extended_runtime.hint_handler;
 
-    let call_result = request.execute_call(syscall_handler, cheatnet_state);
+    let call_expectations = cheatnet_state.take_call_expectations();
+    let events_offset = cheatnet_state.detected_events.len();
+
+    let mut call_result = request.execute_call(syscall_handler, cheatnet_state);
+
+    if let Some(call_expectations) = call_expectations {
+        let called_entry_point = cheatnet_state.trace_data.last_nested_call_entry_point();
+        call_result = call_expectations
+            .verify(
+                call_result,
+                &cheatnet_state.detected_events[events_offset..],
+                || default_ret_data(contracts_data, called_entry_point.as_ref()),
+            )
+            .map_err(|msg| HintError::CustomHint(Box::from(msg)))?;
+    }
+
     write_call_response(syscall_handler, vm, gas_counter, call_result)?;
     Ok(())
 }
@@ -176,6 +176,15 @@ impl<'a> DeprecatedExtensionLogic for DeprecatedCheatableStarknetRuntimeExtensio
                 self.execute_syscall(vm, deploy, syscall_handler)?;
                 Ok(SyscallHandlingResult::Handled)
             }
+            DeprecatedSyscallSelector::StorageRead | DeprecatedSyscallSelector::StorageWrite => {
+                syscall_hooks::deprecated_storage_access_hook(
+                    selector,
+                    vm,
+                    syscall_handler,
+                    self.cheatnet_state,
+                )?;
+                Ok(SyscallHandlingResult::Forwarded)
+            }
             _ => Ok(SyscallHandlingResult::Forwarded),
         }
     }
@@ -90,12 +90,14 @@ impl<'de> Deserialize<'de> for BlockId {
 pub struct InlineForkConfig {
     pub url: Url,
     pub block: BlockId,
+    pub transaction_index: Option<u64>,
 }
 
 #[derive(Debug, Clone, CairoDeserialize, PartialEq)]
 pub struct OverriddenForkConfig {
     pub name: ByteArray,
     pub block: BlockId,
+    pub transaction_index: Option<u64>,
 }
 
 #[derive(Debug, Clone, CairoDeserialize, PartialEq)]
@@ -0,0 +1,92 @@
+use crate::CheatnetState;
+use anyhow::{Context, Result};
+use blockifier::context::TransactionContext;
+use blockifier::execution::entry_point::EntryPointExecutionContext;
+use runtime::starknet::context::build_block_context;
+use starknet_api::block::{BlockInfo, BlockNumber, BlockTimestamp};
+use starknet_api::core::ContractAddress;
+use std::sync::Arc;
+
+impl CheatnetState {
+    /// Sets the timestamp of the current block
+    pub fn warp(&mut self, block_timestamp: u64, context: &mut EntryPointExecutionContext) {
+        let block_info = BlockInfo {
+            block_timestamp: BlockTimestamp(block_timestamp),
+            ..self.block_info.clone()
+        };
+        self.set_block_info(block_info, context);
+    }
+
+    /// Sets the number of the current block, hashes of preceding blocks follow the default
+    /// block hash history
+    pub fn roll(&mut self, block_number: u64, context: &mut EntryPointExecutionContext) {
+        let block_info = BlockInfo {
+            block_number: BlockNumber(block_number),
+            ..self.block_info.clone()
+        };
+        self.set_block_info(block_info, context);
+    }
+
+    /// Moves the chain forward by `count` blocks, each lasting `block_time` seconds
+    pub fn advance_blocks(
+        &mut self,
+        count: u64,
+        context: &mut EntryPointExecutionContext,
+    ) -> Result<()> {
+        let block_number = self
+            .block_info
+            .block_number
+            .0
+            .checked_add(count)
+            .context("Block number overflowed when advancing blocks")?;
+        let block_timestamp = count
+            .checked_mul(self.block_time)
+            .and_then(|elapsed| self.block_info.block_timestamp.0.checked_add(elapsed))
+            .context("Block timestamp overflowed when advancing blocks")?;
+
+        let block_info = BlockInfo {
+            block_number: BlockNumber(block_number),
+            block_timestamp: BlockTimestamp(block_timestamp),
+            ..self.block_info.clone()
+        };
+        self.set_block_info(block_info, context);
+
+        Ok(())
+    }
+
+    /// Sets the number of seconds between blocks produced by `advance_blocks`
+    pub fn set_block_time(&mut self, block_time: u64) {
+        self.block_time = block_time;
+    }
+
+    /// Sets the sequencer address of the current block
+    pub fn set_sequencer(
+        &mut self,
+        sequencer_address: ContractAddress,
+        context: &mut EntryPointExecutionContext,
+    ) {
+        let block_info = BlockInfo {
+            sequencer_address,
+            ..self.block_info.clone()
+        };
+        self.set_block_info(block_info, context);
+    }
+
+    /// Updates block info observed by all subsequent calls, keeping the chain id and
+    /// transaction info of the `context`.
+    /// Cheats of the block info made for contracts still take precedence.
+    fn set_block_info(&mut self, block_info: BlockInfo, context: &mut EntryPointExecutionContext) {
+        let chain_id = context
+            .tx_context
+            .block_context
+            .chain_info()
+            .chain_id
+            .clone();
+
+        context.tx_context = Arc::new(TransactionContext {
+            block_context: build_block_context(&block_info, Some(chain_id)),
+            tx_info: context.tx_context.tx_info.clone(),
+        });
+        self.block_info = block_info;
+    }
+}
@@ -0,0 +1,100 @@
+use super::cheat_execution_info::{CheatArguments, Operation};
+use crate::CheatnetState;
+use crate::state::{CheatSpan, CheatStatus, TargetCall};
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+impl CheatnetState {
+    pub fn cheat_block_hash(&mut self, block_number: u64, operation: Operation<Felt>) {
+        match operation {
+            Operation::Start(CheatArguments {
+                value,
+                span,
+                target,
+            }) => {
+                span.validate();
+
+                self.cheated_block_hashes
+                    .entry(target)
+                    .or_default()
+                    .insert(block_number, CheatStatus::Cheated(value, span));
+            }
+            Operation::Stop(target) => {
+                self.cheated_block_hashes
+                    .entry(target)
+                    .or_default()
+                    .insert(block_number, CheatStatus::Uncheated);
+            }
+            Operation::StartGlobal(value) => {
+                self.global_cheated_block_hashes.insert(block_number, value);
+
+                for block_hashes in self.cheated_block_hashes.values_mut() {
+                    block_hashes.remove(&block_number);
+                }
+            }
+            Operation::StopGlobal => {
+                self.global_cheated_block_hashes.remove(&block_number);
+
+                for block_hashes in self.cheated_block_hashes.values_mut() {
+                    block_hashes.remove(&block_number);
+                }
+            }
+            Operation::Retain => {}
+        }
+    }
+
+    pub fn start_cheat_block_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        block_number: u64,
+        block_hash: Felt,
+    ) {
+        self.cheat_block_hash(
+            block_number,
+            Operation::Start(CheatArguments {
+                value: block_hash,
+                span: CheatSpan::Indefinite,
+                target: contract_address,
+            }),
+        );
+    }
+
+    pub fn stop_cheat_block_hash(&mut self, contract_address: ContractAddress, block_number: u64) {
+        self.cheat_block_hash(block_number, Operation::Stop(contract_address));
+    }
+
+    /// Returns block hashes cheated for the contract, cheats of the contract take precedence
+    /// over the global ones
+    #[must_use]
+    pub fn get_cheated_block_hashes(
+        &self,
+        contract_address: ContractAddress,
+        call: &TargetCall,
+    ) -> HashMap<u64, Felt> {
+        let mut block_hashes = self.global_cheated_block_hashes.clone();
+
+        if let Some(contract_block_hashes) = self.cheated_block_hashes.get(&contract_address) {
+            for (block_number, cheat_status) in contract_block_hashes {
+                match cheat_status.as_value_for_call(call) {
+                    Some(block_hash) => block_hashes.insert(*block_number, block_hash),
+                    None => block_hashes.remove(block_number),
+                };
+            }
+        }
+
+        block_hashes
+    }
+
+    pub fn progress_cheated_block_hashes(
+        &mut self,
+        contract_address: ContractAddress,
+        call: &TargetCall,
+    ) {
+        if let Some(contract_block_hashes) = self.cheated_block_hashes.get_mut(&contract_address) {
+            for cheat_status in contract_block_hashes.values_mut() {
+                cheat_status.progress_cheat_span(call);
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     CheatnetState,
-    state::{CheatSpan, CheatStatus},
+    state::{CheatSpan, CheatStatus, TargetCall},
 };
 use conversions::serde::{deserialize::CairoDeserialize, serialize::CairoSerialize};
 use starknet_api::core::ContractAddress;
@@ -136,6 +136,8 @@ impl CheatnetState {
                         span,
                         target,
                     }) => {
+                        span.validate();
+
                         let cheated_info = self.get_cheated_execution_info_for_contract(target);
 
                         cheated_info.$($path).+ = CheatStatus::Cheated(value, span);
@@ -167,15 +169,15 @@ impl CheatnetState {
         for_all_fields!(cheat!);
     }
 
-    pub fn progress_cheated_execution_info(&mut self, address: ContractAddress) {
+    pub fn progress_cheated_execution_info(&mut self, address: ContractAddress, call: &TargetCall) {
         let mocks = self.get_cheated_execution_info_for_contract(address);
 
-        macro_rules! decrement {
+        macro_rules! progress {
             ($($path:ident).+) => {
-                mocks.$($path).+.decrement_cheat_span();
+                mocks.$($path).+.progress_cheat_span(call);
             };
         }
 
-        for_all_fields!(decrement!);
+        for_all_fields!(progress!);
     }
 }
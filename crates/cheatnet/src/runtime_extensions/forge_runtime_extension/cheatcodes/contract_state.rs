@@ -0,0 +1,49 @@
+use crate::CheatnetState;
+use anyhow::{Result, anyhow};
+use blockifier::state::errors::StateError;
+use blockifier::state::state_api::State;
+use conversions::string::IntoHexStr;
+use starknet_api::core::{ClassHash, ContractAddress, Nonce};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Nonces set with `set_nonce`, written to the state by `MultiForkState` on its next access,
+/// as `State` only allows incrementing them
+pub type SharedPendingNonces = Rc<RefCell<HashMap<ContractAddress, Nonce>>>;
+
+pub fn get_nonce(state: &mut dyn State, target: ContractAddress) -> Result<Nonce> {
+    Ok(state.get_nonce_at(target)?)
+}
+
+impl CheatnetState {
+    /// Sets the nonce of the contract at `target`, it is written to the state on its next access
+    pub fn set_nonce(&mut self, target: ContractAddress, nonce: Nonce) {
+        self.pending_nonces.borrow_mut().insert(target, nonce);
+    }
+
+    /// Sets the class of the contract at `target` in the state, deploying it if the address is
+    /// empty. Cancels class replacement made with `replace_bytecode` for the contract.
+    pub fn set_class_hash(
+        &mut self,
+        state: &mut dyn State,
+        target: ContractAddress,
+        class_hash: ClassHash,
+    ) -> Result<()> {
+        match state.get_compiled_class(class_hash) {
+            Err(StateError::UndeclaredClassHash(_)) => {
+                return Err(anyhow!(
+                    "Class with hash {} is not declared",
+                    class_hash.into_hex_string()
+                ));
+            }
+            Err(err) => return Err(err.into()),
+            Ok(_) => {}
+        }
+
+        state.set_class_hash_at(target, class_hash)?;
+        self.replaced_bytecode_contracts.remove(&target);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use anyhow::Result;
+use blockifier::state::state_api::State;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::string::IntoHexStr;
+use data_transformer::{AbiDecoder, DecodedEvent};
+use shared::utils::format_felts;
+use starknet::core::types::contract::SierraClass;
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct EventFieldMatcher {
+    pub name: ByteArray,
+    /// Value of the field serialized with `Serde`
+    pub value: Vec<Felt>,
+}
+
+/// Matches events by their name and a subset of their fields, decoded using the ABI of the
+/// contract which emitted them
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct EventMatcher {
+    pub from: Option<ContractAddress>,
+    pub name: ByteArray,
+    pub fields: Vec<EventFieldMatcher>,
+}
+
+/// Event with the name looked for by the matcher
+struct Candidate<'a> {
+    index: usize,
+    event: &'a Event,
+    decoded: DecodedEvent,
+    mismatched_fields: Vec<String>,
+}
+
+/// Decoders of events emitted by contracts from the project, built from their ABIs
+struct EventDecoders<'a> {
+    contracts_data: &'a ContractsData,
+    decoders: HashMap<ContractAddress, Option<AbiDecoder>>,
+}
+
+impl<'a> EventDecoders<'a> {
+    fn new(contracts_data: &'a ContractsData) -> Self {
+        Self {
+            contracts_data,
+            decoders: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, state: &mut dyn State, address: ContractAddress) -> Option<&AbiDecoder> {
+        let contracts_data = self.contracts_data;
+
+        self.decoders
+            .entry(address)
+            .or_insert_with(|| {
+                let class_hash = state.get_class_hash_at(address).ok()?;
+                let contract_name = contracts_data.get_contract_name(&class_hash)?;
+                let artifacts = contracts_data.get_artifacts(contract_name)?;
+                let sierra_class: SierraClass = serde_json::from_str(&artifacts.sierra).ok()?;
+
+                Some(AbiDecoder::from_abi(sierra_class.abi))
+            })
+            .as_ref()
+    }
+}
+
+/// Returns indices of `events` matched by the `matcher`
+pub fn get_matching_events(
+    state: &mut dyn State,
+    contracts_data: &ContractsData,
+    events: &[Event],
+    matcher: &EventMatcher,
+) -> Result<Vec<usize>> {
+    let mut decoders = EventDecoders::new(contracts_data);
+
+    Ok(find_candidates(state, &mut decoders, events, matcher)?
+        .into_iter()
+        .filter(|candidate| candidate.mismatched_fields.is_empty())
+        .map(|candidate| candidate.index)
+        .collect())
+}
+
+/// Describes the event expected by the `matcher` and the closest of `events`,
+/// i.e. the one with the same name and the fewest mismatched fields
+pub fn describe_event_mismatch(
+    state: &mut dyn State,
+    contracts_data: &ContractsData,
+    events: &[Event],
+    matcher: &EventMatcher,
+) -> Result<String> {
+    let mut decoders = EventDecoders::new(contracts_data);
+
+    let candidates = find_candidates(state, &mut decoders, events, matcher)?;
+    let expected = format!(
+        "expected: {}",
+        format_matcher(state, &mut decoders, events, matcher)
+    );
+
+    let Some(closest) = candidates
+        .iter()
+        .min_by_key(|candidate| candidate.mismatched_fields.len())
+    else {
+        let emitted = events
+            .iter()
+            .map(|event| {
+                let decoded = decoders
+                    .get(state, event.from)
+                    .and_then(|decoder| decoder.decode_event(&event.keys, &event.data).ok());
+                format!("    {}", format_event(event, decoded.as_ref()))
+            })
+            .collect::<Vec<_>>();
+
+        return Ok(if emitted.is_empty() {
+            format!("{expected}\nno events were emitted")
+        } else {
+            format!(
+                "{expected}\nno event with this name was emitted, emitted events:\n{}",
+                emitted.join("\n")
+            )
+        });
+    };
+
+    let mut description = format!(
+        "{expected}\nclosest:  {}",
+        format_event(closest.event, Some(&closest.decoded))
+    );
+    if !closest.mismatched_fields.is_empty() {
+        description.push_str(&format!(
+            "\nmismatched fields: {}",
+            closest.mismatched_fields.join(", ")
+        ));
+    }
+
+    Ok(description)
+}
+
+/// Finds events with the name looked for by the `matcher`.
+/// Expected values which cannot be decoded for an event (e.g. emitted by another contract
+/// with a same-named event of a different shape) make the field mismatched, the decoding error
+/// is returned only if none of the events defines the field.
+fn find_candidates<'e>(
+    state: &mut dyn State,
+    decoders: &mut EventDecoders,
+    events: &'e [Event],
+    matcher: &EventMatcher,
+) -> Result<Vec<Candidate<'e>>> {
+    let name = matcher.name.to_string();
+    let mut candidates = vec![];
+    let mut is_field_defined = vec![false; matcher.fields.len()];
+    let mut field_errors: Vec<Option<anyhow::Error>> =
+        matcher.fields.iter().map(|_| None).collect();
+
+    for (index, event) in events.iter().enumerate() {
+        if matcher.from.is_some_and(|from| from != event.from) {
+            continue;
+        }
+        let Some(decoder) = decoders.get(state, event.from) else {
+            continue;
+        };
+        let Ok(decoded) = decoder.decode_event(&event.keys, &event.data) else {
+            continue;
+        };
+        if !decoded.is_named(&name) {
+            continue;
+        }
+
+        let mut mismatched_fields = vec![];
+        for (field_index, field) in matcher.fields.iter().enumerate() {
+            let field_name = field.name.to_string();
+            let actual = decoded
+                .fields
+                .iter()
+                .find(|(name, _)| *name == field_name)
+                .map(|(_, value)| value);
+            if actual.is_some() {
+                is_field_defined[field_index] = true;
+            }
+
+            match decoder.decode_event_field(&decoded.name, &field_name, &field.value) {
+                Ok(expected) if actual == Some(&expected) => {}
+                Ok(_) => mismatched_fields.push(field_name),
+                Err(error) => {
+                    field_errors[field_index].get_or_insert(error);
+                    mismatched_fields.push(field_name);
+                }
+            }
+        }
+
+        candidates.push(Candidate {
+            index,
+            event,
+            decoded,
+            mismatched_fields,
+        });
+    }
+
+    for (is_defined, error) in is_field_defined.into_iter().zip(field_errors) {
+        if let (false, Some(error)) = (is_defined, error) {
+            return Err(error);
+        }
+    }
+
+    Ok(candidates)
+}
+
+/// Formats the matcher with field values decoded using the ABI of a contract which has the event,
+/// falling back to raw felts if there is none
+fn format_matcher(
+    state: &mut dyn State,
+    decoders: &mut EventDecoders,
+    events: &[Event],
+    matcher: &EventMatcher,
+) -> String {
+    let name = matcher.name.to_string();
+
+    let mut addresses: Vec<ContractAddress> = matcher.from.into_iter().collect();
+    addresses.extend(events.iter().map(|event| event.from));
+
+    let fields = matcher
+        .fields
+        .iter()
+        .map(|field| {
+            let field_name = field.name.to_string();
+            let value = addresses
+                .iter()
+                .find_map(|address| {
+                    decoders
+                        .get(state, *address)?
+                        .decode_event_field(&name, &field_name, &field.value)
+                        .ok()
+                })
+                .unwrap_or_else(|| format_felts(&field.value));
+
+            format!("{field_name}: {value}")
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut formatted = format!("{name} {{ {fields} }}");
+    if let Some(from) = matcher.from {
+        formatted.push_str(&format!(" emitted by {}", from.into_hex_string()));
+    }
+    formatted
+}
+
+fn format_event(event: &Event, decoded: Option<&DecodedEvent>) -> String {
+    let formatted = match decoded {
+        Some(decoded) => {
+            let fields = decoded
+                .fields
+                .iter()
+                .map(|(name, value)| format!("{name}: {value}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{} {{ {fields} }}", decoded.name)
+        }
+        None => format!(
+            "keys: {}, data: {}",
+            format_felts(&event.keys),
+            format_felts(&event.data)
+        ),
+    };
+
+    format!("{formatted} emitted by {}", event.from.into_hex_string())
+}
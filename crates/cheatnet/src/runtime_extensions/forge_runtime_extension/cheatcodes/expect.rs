@@ -0,0 +1,229 @@
+use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    CallFailure, CallResult,
+};
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_contract::load_abi;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use crate::state::CheatnetState;
+use anyhow::Result;
+use blockifier::execution::entry_point::CallEntryPoint;
+use data_transformer::AbiDecoder;
+use shared::utils::{build_readable_text, format_felts};
+use starknet_types_core::felt::Felt;
+
+/// Expectations armed for the next call made from the test contract
+#[derive(Debug, Default)]
+pub struct CallExpectations {
+    revert: Option<Vec<Felt>>,
+    emitted_events: Vec<Event>,
+}
+
+impl CheatnetState {
+    pub fn expect_revert(&mut self, panic_data: Vec<Felt>) {
+        self.call_expectations.revert = Some(panic_data);
+    }
+
+    pub fn expect_emit(&mut self, events: Vec<Event>) {
+        self.call_expectations.emitted_events.extend(events);
+    }
+
+    /// Returns expectations armed for the next call made from the test contract, disarming them
+    pub fn take_call_expectations(&mut self) -> Option<CallExpectations> {
+        let expectations = std::mem::take(&mut self.call_expectations);
+
+        if expectations.revert.is_none() && expectations.emitted_events.is_empty() {
+            None
+        } else {
+            Some(expectations)
+        }
+    }
+}
+
+impl CallExpectations {
+    /// Checks the result of the call and events emitted during it against the expectations.
+    /// Returns a message describing the mismatch if the expectations are not met.
+    /// The expected revert is turned into a successful call returning `default_ret_data`.
+    pub fn verify(
+        self,
+        call_result: CallResult,
+        emitted_events: &[Event],
+        default_ret_data: impl FnOnce() -> Result<Vec<Felt>>,
+    ) -> Result<CallResult, String> {
+        let call_result = match (self.revert, call_result) {
+            (None, call_result) => call_result,
+            (Some(expected), CallResult::Success { .. }) => {
+                return Err(format!(
+                    "Call was expected to revert but it succeeded\n    expected panic data: {}",
+                    format_panic_data(&expected)
+                ));
+            }
+            (Some(expected), CallResult::Failure(CallFailure::Panic { panic_data })) => {
+                if panic_data != expected {
+                    return Err(format!(
+                        "Call reverted with unexpected panic data\n    expected: {}\n    actual:   {}",
+                        format_panic_data(&expected),
+                        format_panic_data(&panic_data)
+                    ));
+                }
+                let ret_data = default_ret_data().map_err(|error| {
+                    format!("Failed to build return data of the reverted call: {error:#}")
+                })?;
+                CallResult::Success { ret_data }
+            }
+            (Some(expected), CallResult::Failure(CallFailure::Error { msg })) => {
+                return Err(format!(
+                    "Call was expected to revert but it failed with an error\n    expected panic data: {}\n    error: {}",
+                    format_panic_data(&expected),
+                    msg
+                ));
+            }
+        };
+
+        let mut remaining_events = emitted_events.iter();
+        for expected in &self.emitted_events {
+            if !remaining_events.any(|event| event == expected) {
+                let emitted = if emitted_events.is_empty() {
+                    "\n        none".to_string()
+                } else {
+                    emitted_events
+                        .iter()
+                        .map(|event| format!("\n        {}", format_event(event)))
+                        .collect::<String>()
+                };
+
+                return Err(format!(
+                    "Expected event was not emitted by the call\n    expected: {}\n    emitted:{emitted}",
+                    format_event(expected)
+                ));
+            }
+        }
+
+        Ok(call_result)
+    }
+}
+
+/// Builds default return data of the called function from the ABI of the called class,
+/// so dispatchers can deserialize it. Calls to classes from outside of the project return no data.
+pub fn default_ret_data(
+    contracts_data: &ContractsData,
+    entry_point: Option<&CallEntryPoint>,
+) -> Result<Vec<Felt>> {
+    let Some((contract_name, function_name)) = entry_point.and_then(|entry_point| {
+        let contract_name = contracts_data.get_contract_name(entry_point.class_hash.as_ref()?)?;
+        let function_name = contracts_data.get_function_name(&entry_point.entry_point_selector)?;
+        Some((contract_name, function_name))
+    }) else {
+        return Ok(vec![]);
+    };
+
+    let decoder = AbiDecoder::from_abi(load_abi(contracts_data, contract_name)?);
+    if !decoder.function_names().contains(&function_name.as_str()) {
+        return Ok(vec![]);
+    }
+
+    decoder.default_outputs(function_name)
+}
+
+fn format_panic_data(panic_data: &[Felt]) -> String {
+    build_readable_text(panic_data)
+        .map_or_else(|| "no data".to_string(), |text| text.trim().to_string())
+}
+
+fn format_event(event: &Event) -> String {
+    format!(
+        "from: {:#x}, keys: {}, data: {}",
+        event.from.0.key(),
+        format_felts(&event.keys),
+        format_felts(&event.data)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet_api::core::ContractAddress;
+
+    fn event(data: u8) -> Event {
+        Event {
+            from: ContractAddress::from(1_u8),
+            keys: vec![Felt::from(1)],
+            data: vec![Felt::from(data)],
+        }
+    }
+
+    fn no_ret_data() -> Result<Vec<Felt>> {
+        Ok(vec![])
+    }
+
+    #[test]
+    fn expected_revert_becomes_success() {
+        let expectations = CallExpectations {
+            revert: Some(vec![Felt::from(1)]),
+            emitted_events: vec![],
+        };
+        let call_result = CallResult::Failure(CallFailure::Panic {
+            panic_data: vec![Felt::from(1)],
+        });
+
+        let result = expectations
+            .verify(call_result, &[], || Ok(vec![Felt::from(0), Felt::from(2)]))
+            .unwrap();
+
+        assert!(
+            matches!(result, CallResult::Success { ret_data } if ret_data == [Felt::from(0), Felt::from(2)])
+        );
+    }
+
+    #[test]
+    fn revert_with_other_panic_data() {
+        let expectations = CallExpectations {
+            revert: Some(vec![Felt::from(1)]),
+            emitted_events: vec![],
+        };
+        let call_result = CallResult::Failure(CallFailure::Panic {
+            panic_data: vec![Felt::from_bytes_be_slice(b"abc")],
+        });
+
+        let message = expectations
+            .verify(call_result, &[], no_ret_data)
+            .unwrap_err();
+
+        assert_eq!(
+            message,
+            "Call reverted with unexpected panic data\n    expected: 0x1\n    actual:   0x616263 ('abc')"
+        );
+    }
+
+    #[test]
+    fn expected_events_emitted_in_order() {
+        let expectations = CallExpectations {
+            revert: None,
+            emitted_events: vec![event(1), event(3)],
+        };
+        let call_result = CallResult::Success { ret_data: vec![] };
+
+        assert!(
+            expectations
+                .verify(call_result, &[event(1), event(2), event(3)], no_ret_data)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn expected_event_not_emitted() {
+        let expectations = CallExpectations {
+            revert: None,
+            emitted_events: vec![event(3), event(1)],
+        };
+        let call_result = CallResult::Success { ret_data: vec![] };
+
+        let message = expectations
+            .verify(call_result, &[event(1), event(3)], no_ret_data)
+            .unwrap_err();
+
+        assert_eq!(
+            message,
+            "Expected event was not emitted by the call\n    expected: from: 0x1, keys: [0x1], data: [0x1]\n    emitted:\n        from: 0x1, keys: [0x1], data: [0x1]\n        from: 0x1, keys: [0x1], data: [0x3]"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::CheatnetState;
+use crate::forking::forks::{ForkBlockId, ForkId};
+use anyhow::Result;
+use blockifier::context::TransactionContext;
+use blockifier::execution::entry_point::EntryPointExecutionContext;
+use runtime::starknet::context::build_block_context;
+use starknet_api::block::BlockInfo;
+use starknet_api::core::ChainId;
+use std::sync::Arc;
+use url::Url;
+
+impl CheatnetState {
+    pub fn create_fork(&mut self, url: Url, block_id: ForkBlockId) -> Result<ForkId> {
+        self.forks.borrow_mut().create_fork(url, block_id)
+    }
+
+    /// Switches the test to the fork, updating block info and chain id of the `context`
+    pub fn select_fork(
+        &mut self,
+        fork_id: ForkId,
+        context: &mut EntryPointExecutionContext,
+    ) -> Result<()> {
+        let (block_info, chain_id) = self.forks.borrow_mut().select_fork(fork_id)?;
+        self.set_fork_block_context(block_info, chain_id, context);
+
+        Ok(())
+    }
+
+    /// Moves the active fork to another block, updating block info of the `context`
+    pub fn roll_fork(
+        &mut self,
+        block_number: u64,
+        context: &mut EntryPointExecutionContext,
+    ) -> Result<()> {
+        let (block_info, chain_id) = self.forks.borrow_mut().roll_fork(block_number)?;
+        self.set_fork_block_context(block_info, chain_id, context);
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn active_fork(&self) -> Option<ForkId> {
+        self.forks.borrow().active_fork()
+    }
+
+    /// Updates block info and chain id of the `context` to the ones of the fork,
+    /// keeping its transaction info
+    fn set_fork_block_context(
+        &mut self,
+        block_info: BlockInfo,
+        chain_id: ChainId,
+        context: &mut EntryPointExecutionContext,
+    ) {
+        context.tx_context = Arc::new(TransactionContext {
+            block_context: build_block_context(&block_info, Some(chain_id)),
+            tx_info: context.tx_context.tx_info.clone(),
+        });
+        self.block_info = block_info;
+    }
+}
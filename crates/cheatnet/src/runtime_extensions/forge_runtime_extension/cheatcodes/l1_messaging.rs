@@ -0,0 +1,176 @@
+use crate::{
+    runtime_extensions::{
+        call_to_blockifier_runtime_extension::rpc::CallResult,
+        forge_runtime_extension::cheatcodes::l1_handler_execute::l1_handler_execute,
+    },
+    state::CheatnetState,
+};
+use anyhow::{Result, anyhow};
+use blockifier::execution::syscalls::hint_processor::SyscallHintProcessor;
+use conversions::IntoConv;
+use conversions::serde::serialize::CairoSerialize;
+use conversions::string::IntoHexStr;
+use sha3::{Digest, Keccak256};
+use starknet_api::core::{ContractAddress, EntryPointSelector, EthAddress};
+use starknet_types_core::felt::Felt;
+
+/// Keccak hash of a message passed between L1 and L2, serialized as Cairo `u256`
+#[derive(CairoSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MessageHash {
+    low: u128,
+    high: u128,
+}
+
+impl MessageHash {
+    /// Hashes the words packed as `uint256` values, as the Starknet core contract on L1 does
+    fn from_words(words: impl IntoIterator<Item = Felt>) -> Self {
+        let mut hasher = Keccak256::new();
+        for word in words {
+            hasher.update(word.to_bytes_be());
+        }
+        let hash: [u8; 32] = hasher.finalize().into();
+        let (high, low) = hash.split_at(16);
+
+        Self {
+            low: u128::from_be_bytes(low.try_into().unwrap()),
+            high: u128::from_be_bytes(high.try_into().unwrap()),
+        }
+    }
+
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        format!("0x{:032x}{:032x}", self.high, self.low)
+    }
+}
+
+#[must_use]
+pub fn l2_to_l1_message_hash(
+    from_address: ContractAddress,
+    to_address: EthAddress,
+    payload: &[Felt],
+) -> MessageHash {
+    let header = [
+        from_address.into_(),
+        to_address.into_(),
+        Felt::from(payload.len()),
+    ];
+
+    MessageHash::from_words(header.into_iter().chain(payload.iter().copied()))
+}
+
+#[must_use]
+pub fn l1_to_l2_message_hash(
+    from_address: EthAddress,
+    to_address: ContractAddress,
+    nonce: Felt,
+    selector: EntryPointSelector,
+    payload: &[Felt],
+) -> MessageHash {
+    let header = [
+        from_address.into_(),
+        to_address.into_(),
+        nonce,
+        selector.into_(),
+        Felt::from(payload.len()),
+    ];
+
+    MessageHash::from_words(header.into_iter().chain(payload.iter().copied()))
+}
+
+/// Message sent to L1 by a contract, waiting to be consumed on L1
+#[derive(CairoSerialize, Clone, Debug, PartialEq)]
+pub struct PendingMessageToL1 {
+    pub from_address: ContractAddress,
+    pub to_address: EthAddress,
+    pub payload: Vec<Felt>,
+    pub message_hash: MessageHash,
+}
+
+#[derive(CairoSerialize, Clone, Debug)]
+pub struct MessageToL2Receipt {
+    pub nonce: Felt,
+    pub message_hash: MessageHash,
+    pub result: Result<(), Vec<Felt>>,
+}
+
+impl CheatnetState {
+    pub fn register_message_to_l1(
+        &mut self,
+        from_address: ContractAddress,
+        to_address: EthAddress,
+        payload: Vec<Felt>,
+    ) {
+        let message_hash = l2_to_l1_message_hash(from_address, to_address, &payload);
+
+        self.pending_messages_to_l1.push(PendingMessageToL1 {
+            from_address,
+            to_address,
+            payload,
+            message_hash,
+        });
+    }
+
+    /// Consumes the oldest pending message to L1 with the hash matching the given message,
+    /// the same way `consumeMessageFromL2` of the Starknet core contract does
+    pub fn consume_message_from_l2(
+        &mut self,
+        from_address: ContractAddress,
+        to_address: EthAddress,
+        payload: &[Felt],
+    ) -> Result<MessageHash> {
+        let message_hash = l2_to_l1_message_hash(from_address, to_address, payload);
+
+        let position = self
+            .pending_messages_to_l1
+            .iter()
+            .position(|message| message.message_hash == message_hash)
+            .ok_or_else(|| {
+                anyhow!(
+                    "Message with hash {} sent from {} to {} is not pending to be consumed",
+                    message_hash.to_hex_string(),
+                    from_address.into_hex_string(),
+                    Felt::from(to_address).into_hex_string(),
+                )
+            })?;
+        self.pending_messages_to_l1.remove(position);
+
+        Ok(message_hash)
+    }
+
+    #[must_use]
+    pub fn get_pending_messages_to_l1(&self) -> Vec<PendingMessageToL1> {
+        self.pending_messages_to_l1.clone()
+    }
+
+    fn next_l1_to_l2_message_nonce(&mut self) -> Felt {
+        let nonce = self.l1_to_l2_message_nonce;
+        self.l1_to_l2_message_nonce += 1;
+        Felt::from(nonce)
+    }
+}
+
+/// Sends a message from L1 the way the Starknet core contract does, assigning it the next nonce,
+/// and delivers it to the L1 handler of the `to_address` contract.
+/// Returns the nonce and the hash of the message, along with the result of the L1 handler.
+pub fn send_message_to_l2(
+    syscall_handler: &mut SyscallHintProcessor,
+    cheatnet_state: &mut CheatnetState,
+    from_address: EthAddress,
+    to_address: ContractAddress,
+    selector: EntryPointSelector,
+    payload: &[Felt],
+) -> (Felt, MessageHash, CallResult) {
+    let nonce = cheatnet_state.next_l1_to_l2_message_nonce();
+    let message_hash = l1_to_l2_message_hash(from_address, to_address, nonce, selector, payload);
+
+    let call_result = l1_handler_execute(
+        syscall_handler,
+        cheatnet_state,
+        to_address,
+        selector,
+        from_address.into_(),
+        payload,
+    );
+
+    (nonce, message_hash, call_result)
+}
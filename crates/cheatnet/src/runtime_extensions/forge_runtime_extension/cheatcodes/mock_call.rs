@@ -12,6 +12,8 @@ impl CheatnetState {
         ret_data: &[Felt],
         span: CheatSpan,
     ) {
+        span.validate();
+
         let contract_mocked_functions = self.mocked_functions.entry(contract_address).or_default();
 
         contract_mocked_functions.insert(
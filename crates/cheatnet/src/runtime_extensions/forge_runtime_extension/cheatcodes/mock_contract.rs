@@ -0,0 +1,181 @@
+use crate::CheatnetState;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use anyhow::{Context, Result, anyhow};
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use camino::Utf8Path;
+use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use conversions::string::IntoHexStr;
+use data_transformer::AbiDecoder;
+use serde::Deserialize;
+use starknet::core::types::contract::{AbiEntry, SierraClass};
+use starknet::core::utils::get_selector_from_name;
+use starknet_api::core::{ContractAddress, EntryPointSelector};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::fs;
+
+#[derive(Clone, Debug)]
+pub struct MockedFunction {
+    pub name: String,
+    pub ret_data: Vec<Felt>,
+    pub calls_count: u32,
+}
+
+/// Contract whose every function returns the default value of its return type,
+/// unless overridden with `mock_contract_function`
+#[derive(Clone, Debug, Default)]
+pub struct ContractMock {
+    pub functions: HashMap<EntryPointSelector, MockedFunction>,
+}
+
+/// ABI file accepted by `mock_contract`, either a plain ABI or a whole Sierra class
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AbiFile {
+    Abi(Vec<AbiEntry>),
+    SierraClass(SierraClass),
+}
+
+/// Loads ABI of the contract from the project with the given name,
+/// or from a JSON file if `contract` is a path to one
+pub fn load_abi(contracts_data: &ContractsData, contract: &str) -> Result<Vec<AbiEntry>> {
+    if let Some(artifacts) = contracts_data.get_artifacts(contract) {
+        let sierra_class: SierraClass = serde_json::from_str(&artifacts.sierra)?;
+        return Ok(sierra_class.abi);
+    }
+
+    let path = Utf8Path::new(contract);
+    if path.extension() != Some("json") {
+        return Err(anyhow!(
+            "Contract with name {contract} not found in the project, ABI file paths must end with .json"
+        ));
+    }
+
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read ABI file {path}"))?;
+    let abi = match serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse ABI file {path}"))?
+    {
+        AbiFile::Abi(abi) => abi,
+        AbiFile::SierraClass(sierra_class) => sierra_class.abi,
+    };
+
+    Ok(abi)
+}
+
+impl CheatnetState {
+    pub fn mock_contract(
+        &mut self,
+        contract_address: ContractAddress,
+        abi: Vec<AbiEntry>,
+    ) -> Result<()> {
+        let decoder = AbiDecoder::from_abi(abi);
+
+        let functions = decoder
+            .function_names()
+            .into_iter()
+            .map(|name| {
+                let selector: EntryPointSelector = get_selector_from_name(name)?.into_();
+                let ret_data = decoder.default_outputs(name)?;
+
+                Ok((
+                    selector,
+                    MockedFunction {
+                        name: name.to_string(),
+                        ret_data,
+                        calls_count: 0,
+                    },
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        self.mocked_contracts
+            .insert(contract_address, ContractMock { functions });
+
+        Ok(())
+    }
+
+    pub fn mock_contract_function(
+        &mut self,
+        contract_address: ContractAddress,
+        function_name: &str,
+        ret_data: Vec<Felt>,
+    ) -> Result<()> {
+        self.get_mocked_function(contract_address, function_name)?
+            .ret_data = ret_data;
+
+        Ok(())
+    }
+
+    pub fn get_mocked_function_calls_count(
+        &mut self,
+        contract_address: ContractAddress,
+        function_name: &str,
+    ) -> Result<u32> {
+        Ok(self
+            .get_mocked_function(contract_address, function_name)?
+            .calls_count)
+    }
+
+    pub fn stop_mock_contract(&mut self, contract_address: ContractAddress) {
+        self.mocked_contracts.remove(&contract_address);
+    }
+
+    /// Returns return data of the call to a mocked contract, or panic data if the called function
+    /// is not in the ABI of the contract. Returns `None` if the contract is not mocked.
+    pub fn call_mocked_contract(
+        &mut self,
+        call: &CallEntryPoint,
+    ) -> Option<Result<Vec<Felt>, Vec<Felt>>> {
+        if call.call_type == CallType::Delegate {
+            return None;
+        }
+
+        let contract_mock = self.mocked_contracts.get_mut(&call.storage_address)?;
+
+        Some(
+            match contract_mock.functions.get_mut(&call.entry_point_selector) {
+                Some(function) => {
+                    function.calls_count += 1;
+                    Ok(function.ret_data.clone())
+                }
+                None => {
+                    let message = format!(
+                        "Function with selector {} not found in ABI of contract mocked at {}",
+                        call.entry_point_selector.0.to_hex_string(),
+                        call.storage_address.into_hex_string()
+                    );
+                    Err(ByteArray::from(message.as_str()).serialize_with_magic())
+                }
+            },
+        )
+    }
+
+    fn get_mocked_function(
+        &mut self,
+        contract_address: ContractAddress,
+        function_name: &str,
+    ) -> Result<&mut MockedFunction> {
+        let contract_mock = self
+            .mocked_contracts
+            .get_mut(&contract_address)
+            .with_context(|| {
+                format!(
+                    "Contract at {} is not mocked",
+                    contract_address.into_hex_string()
+                )
+            })?;
+
+        contract_mock
+            .functions
+            .values_mut()
+            .find(|function| function.name == function_name)
+            .with_context(|| {
+                format!(
+                    "Function {function_name} not found in ABI of contract mocked at {}",
+                    contract_address.into_hex_string()
+                )
+            })
+    }
+}
@@ -3,22 +3,33 @@ use cairo_vm::vm::errors::hint_errors::HintError;
 use runtime::EnhancedHintError;
 use starknet_types_core::felt::Felt;
 
+pub mod block_progression;
+pub mod cheat_block_hash;
 pub mod cheat_block_number;
 pub mod cheat_block_timestamp;
 pub mod cheat_caller_address;
 pub mod cheat_execution_info;
 pub mod cheat_sequencer_address;
+pub mod contract_state;
 pub mod declare;
 pub mod deploy;
+pub mod event_matching;
+pub mod expect;
+pub mod fork;
 pub mod generate_random_felt;
 pub mod get_class_hash;
 pub mod l1_handler_execute;
+pub mod l1_messaging;
 pub mod mock_call;
+pub mod mock_contract;
 pub mod precalculate_address;
 pub mod replace_bytecode;
 pub mod spy_events;
 pub mod spy_messages_to_l1;
+pub mod state_diff;
 pub mod storage;
+pub mod storage_accesses;
+pub mod transaction;
 
 /// A structure used for returning cheatcode errors in tests
 #[derive(Debug)]
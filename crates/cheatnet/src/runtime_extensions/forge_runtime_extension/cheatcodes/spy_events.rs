@@ -1,12 +1,15 @@
 use crate::CheatnetState;
 use blockifier::execution::call_info::OrderedEvent;
-use conversions::{FromConv, serde::serialize::CairoSerialize};
+use conversions::{
+    FromConv,
+    serde::{deserialize::CairoDeserialize, serialize::CairoSerialize},
+};
 use starknet_api::core::ContractAddress;
 use starknet_types_core::felt::Felt;
 
 /// Represents an emitted event. It is used in the `CheatnetState` to keep track of events
 /// emitted in the `cheatnet::src::rpc::call_contract`
-#[derive(CairoSerialize, Debug, PartialEq, Clone)]
+#[derive(CairoSerialize, CairoDeserialize, Debug, PartialEq, Clone)]
 pub struct Event {
     pub from: ContractAddress,
     pub keys: Vec<Felt>,
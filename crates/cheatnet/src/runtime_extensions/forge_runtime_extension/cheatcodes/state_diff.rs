@@ -0,0 +1,229 @@
+use crate::state::CheatnetState;
+use anyhow::{Result, ensure};
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::core::{ClassHash, ContractAddress, Nonce};
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+
+/// Changes made to the state during the test, in the order they were made
+#[derive(Debug, Default)]
+pub struct StateChanges {
+    /// Changes are recorded only after the first `mark_state`, as recording them requires
+    /// reading previous values, which may be fetched from the fork node
+    pub is_recording: bool,
+    pub changes: Vec<StateChange>,
+}
+
+pub type SharedStateChanges = Rc<RefCell<StateChanges>>;
+
+#[derive(Clone, Debug)]
+pub enum StateChange {
+    Storage {
+        contract_address: ContractAddress,
+        key: StorageKey,
+        old_value: Felt,
+        new_value: Felt,
+    },
+    Nonce {
+        contract_address: ContractAddress,
+        old_nonce: Nonce,
+        new_nonce: Nonce,
+    },
+    ClassHash {
+        contract_address: ContractAddress,
+        old_class_hash: ClassHash,
+        new_class_hash: ClassHash,
+    },
+    DeclaredClass {
+        class_hash: ClassHash,
+    },
+}
+
+#[derive(CairoSerialize, Debug, PartialEq)]
+pub struct StorageDiff {
+    contract_address: ContractAddress,
+    key: Felt,
+    old_value: Felt,
+    new_value: Felt,
+}
+
+#[derive(CairoSerialize, Debug, PartialEq)]
+pub struct NonceDiff {
+    contract_address: ContractAddress,
+    old_nonce: Nonce,
+    new_nonce: Nonce,
+}
+
+#[derive(CairoSerialize, Debug, PartialEq)]
+pub struct ClassHashDiff {
+    contract_address: ContractAddress,
+    old_class_hash: ClassHash,
+    new_class_hash: ClassHash,
+}
+
+#[derive(CairoSerialize, Debug, Default, PartialEq)]
+pub struct StateDiff {
+    storage: Vec<StorageDiff>,
+    nonces: Vec<NonceDiff>,
+    class_hashes: Vec<ClassHashDiff>,
+    declared_classes: Vec<ClassHash>,
+}
+
+impl StateDiff {
+    /// Squashes the changes, keeping the value from before the first change and the value after the last one.
+    /// Entries for which these values are equal are skipped.
+    #[must_use]
+    pub fn from_changes(changes: &[StateChange]) -> Self {
+        let mut storage = BTreeMap::new();
+        let mut nonces = BTreeMap::new();
+        let mut class_hashes = BTreeMap::new();
+        let mut declared_classes = BTreeSet::new();
+
+        for change in changes {
+            match *change {
+                StateChange::Storage {
+                    contract_address,
+                    key,
+                    old_value,
+                    new_value,
+                } => {
+                    storage
+                        .entry((contract_address, key))
+                        .or_insert((old_value, new_value))
+                        .1 = new_value;
+                }
+                StateChange::Nonce {
+                    contract_address,
+                    old_nonce,
+                    new_nonce,
+                } => {
+                    nonces
+                        .entry(contract_address)
+                        .or_insert((old_nonce, new_nonce))
+                        .1 = new_nonce;
+                }
+                StateChange::ClassHash {
+                    contract_address,
+                    old_class_hash,
+                    new_class_hash,
+                } => {
+                    class_hashes
+                        .entry(contract_address)
+                        .or_insert((old_class_hash, new_class_hash))
+                        .1 = new_class_hash;
+                }
+                StateChange::DeclaredClass { class_hash } => {
+                    declared_classes.insert(class_hash);
+                }
+            }
+        }
+
+        Self {
+            storage: storage
+                .into_iter()
+                .filter(|(_, (old_value, new_value))| old_value != new_value)
+                .map(
+                    |((contract_address, key), (old_value, new_value))| StorageDiff {
+                        contract_address,
+                        key: *key.0.key(),
+                        old_value,
+                        new_value,
+                    },
+                )
+                .collect(),
+            nonces: nonces
+                .into_iter()
+                .filter(|(_, (old_nonce, new_nonce))| old_nonce != new_nonce)
+                .map(|(contract_address, (old_nonce, new_nonce))| NonceDiff {
+                    contract_address,
+                    old_nonce,
+                    new_nonce,
+                })
+                .collect(),
+            class_hashes: class_hashes
+                .into_iter()
+                .filter(|(_, (old_class_hash, new_class_hash))| old_class_hash != new_class_hash)
+                .map(
+                    |(contract_address, (old_class_hash, new_class_hash))| ClassHashDiff {
+                        contract_address,
+                        old_class_hash,
+                        new_class_hash,
+                    },
+                )
+                .collect(),
+            declared_classes: declared_classes.into_iter().collect(),
+        }
+    }
+}
+
+impl CheatnetState {
+    /// Starts recording state changes and returns the offset of the first one to be recorded
+    pub fn mark_state(&mut self) -> usize {
+        let mut state_changes = self.state_changes.borrow_mut();
+        state_changes.is_recording = true;
+        state_changes.changes.len()
+    }
+
+    /// Fails if no state was marked, as changes made before it are not recorded
+    pub fn get_state_diff(&self, changes_offset: usize) -> Result<StateDiff> {
+        let state_changes = self.state_changes.borrow();
+        ensure!(
+            state_changes.is_recording,
+            "State changes are not recorded, call `mark_state` before `get_state_diff`"
+        );
+        let changes = &state_changes.changes;
+
+        Ok(StateDiff::from_changes(
+            &changes[changes_offset.min(changes.len())..],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage_change(key: u64, old_value: u64, new_value: u64) -> StateChange {
+        StateChange::Storage {
+            contract_address: ContractAddress::from(1_u8),
+            key: StorageKey::from(key),
+            old_value: Felt::from(old_value),
+            new_value: Felt::from(new_value),
+        }
+    }
+
+    #[test]
+    fn squashes_storage_changes() {
+        let diff = StateDiff::from_changes(&[
+            storage_change(1, 0, 5),
+            storage_change(1, 5, 7),
+            storage_change(2, 3, 4),
+            storage_change(2, 4, 3),
+        ]);
+
+        assert_eq!(
+            diff.storage,
+            vec![StorageDiff {
+                contract_address: ContractAddress::from(1_u8),
+                key: Felt::from(1),
+                old_value: Felt::from(0),
+                new_value: Felt::from(7),
+            }]
+        );
+    }
+
+    #[test]
+    fn deduplicates_declared_classes() {
+        let class_hash = ClassHash(Felt::from(123));
+
+        let diff = StateDiff::from_changes(&[
+            StateChange::DeclaredClass { class_hash },
+            StateChange::DeclaredClass { class_hash },
+        ]);
+
+        assert_eq!(diff.declared_classes, vec![class_hash]);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::state::CheatnetState;
+use conversions::serde::serialize::CairoSerialize;
+use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+
+#[derive(CairoSerialize, Clone, Debug, PartialEq)]
+pub struct StorageRead {
+    pub key: Felt,
+    pub value: Felt,
+}
+
+#[derive(CairoSerialize, Clone, Debug, PartialEq)]
+pub struct StorageWrite {
+    pub key: Felt,
+    pub old_value: Felt,
+    pub new_value: Felt,
+}
+
+#[derive(CairoSerialize, Clone, Debug, PartialEq)]
+pub enum StorageAccessKind {
+    Read(StorageRead),
+    Write(StorageWrite),
+}
+
+#[derive(CairoSerialize, Clone, Debug, PartialEq)]
+pub struct StorageAccess {
+    pub contract_address: ContractAddress,
+    pub kind: StorageAccessKind,
+}
+
+impl CheatnetState {
+    /// Starts recording storage accesses and returns the offset of the first one to be recorded
+    pub fn record_storage_accesses(&mut self) -> usize {
+        self.is_recording_storage_accesses = true;
+        self.detected_storage_accesses.len()
+    }
+
+    #[must_use]
+    pub fn get_storage_accesses(&self, access_offset: usize) -> Vec<StorageAccess> {
+        let accesses = &self.detected_storage_accesses;
+        accesses[access_offset.min(accesses.len())..].to_vec()
+    }
+
+    pub fn add_storage_read(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        value: Felt,
+    ) {
+        self.detected_storage_accesses.push(StorageAccess {
+            contract_address,
+            kind: StorageAccessKind::Read(StorageRead {
+                key: *key.0.key(),
+                value,
+            }),
+        });
+    }
+
+    pub fn add_storage_write(
+        &mut self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+        old_value: Felt,
+        new_value: Felt,
+    ) {
+        self.detected_storage_accesses.push(StorageAccess {
+            contract_address,
+            kind: StorageAccessKind::Write(StorageWrite {
+                key: *key.0.key(),
+                old_value,
+                new_value,
+            }),
+        });
+    }
+}
@@ -0,0 +1,419 @@
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
+use crate::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use crate::state::CheatnetState;
+use anyhow::{Context, Result};
+use blockifier::context::BlockContext;
+use blockifier::execution::contract_class::RunnableCompiledClass;
+use blockifier::state::cached_state::{ContractClassMapping, StateMaps};
+use blockifier::state::state_api::{State, StateReader, StateResult, UpdatableState};
+use blockifier::transaction::account_transaction::AccountTransaction;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use blockifier::transaction::transactions::ExecutableTransaction;
+use cairo_lang_starknet_classes::casm_contract_class::CasmContractClass;
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use runtime::EnhancedHintError;
+use starknet::core::types::contract::SierraClass;
+use starknet::signers::SigningKey;
+use starknet_api::contract_class::{ClassInfo, ContractClass, SierraVersion};
+use starknet_api::core::{ClassHash, CompiledClassHash, ContractAddress, Nonce};
+use starknet_api::data_availability::DataAvailabilityMode;
+use starknet_api::executable_transaction::{
+    AccountTransaction as ExecutableAccountTransaction, DeclareTransaction,
+    DeployAccountTransaction, InvokeTransaction,
+};
+use starknet_api::execution_resources::GasAmount;
+use starknet_api::state::StorageKey;
+use starknet_api::transaction::fields::{
+    AllResourceBounds, Calldata, ContractAddressSalt, ResourceBounds, Tip, TransactionSignature,
+    ValidResourceBounds,
+};
+use starknet_api::transaction::{
+    DeclareTransactionV3, DeployAccountTransactionV3, InvokeTransactionV3, TransactionHash,
+    TransactionHasher, TransactionVersion,
+};
+use starknet_types_core::felt::Felt;
+use std::sync::Arc;
+
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct ResourceBound {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+#[derive(CairoDeserialize, Clone, Debug)]
+pub enum TransactionSigner {
+    /// Signature passed to the account as is
+    Signature(Vec<Felt>),
+    /// Stark curve private key used to sign the transaction hash, producing `[r, s]` signature
+    StarkKey(Felt),
+}
+
+#[derive(CairoDeserialize, Clone, Debug)]
+pub struct TransactionParams {
+    /// Current nonce of the sender is used if not provided
+    pub nonce: Option<Nonce>,
+    pub signer: TransactionSigner,
+    pub l1_gas: ResourceBound,
+    pub l2_gas: ResourceBound,
+    pub l1_data_gas: ResourceBound,
+    pub tip: u64,
+}
+
+#[derive(CairoSerialize, Debug, PartialEq)]
+pub struct GasConsumed {
+    pub l1_gas: u64,
+    pub l1_data_gas: u64,
+    pub l2_gas: u64,
+}
+
+#[derive(CairoSerialize, Debug)]
+pub struct TransactionReceipt {
+    pub transaction_hash: Felt,
+    pub actual_fee: u128,
+    pub gas_consumed: GasConsumed,
+    pub events: Vec<Event>,
+    pub revert_reason: Option<ByteArray>,
+}
+
+/// Outcome of a submitted transaction, serialized like Cairo `Result`.
+/// Rejected transactions (e.g. failing validation or with invalid nonce) leave the state untouched.
+#[derive(CairoSerialize, Debug)]
+pub enum TransactionResult<T> {
+    Accepted(T),
+    Rejected(ByteArray),
+}
+
+/// State of the test exposed to blockifier, which applies writes of a transaction only once it is accepted
+struct TestState<'a>(&'a mut dyn State);
+
+impl StateReader for TestState<'_> {
+    fn get_storage_at(
+        &self,
+        contract_address: ContractAddress,
+        key: StorageKey,
+    ) -> StateResult<Felt> {
+        self.0.get_storage_at(contract_address, key)
+    }
+
+    fn get_nonce_at(&self, contract_address: ContractAddress) -> StateResult<Nonce> {
+        self.0.get_nonce_at(contract_address)
+    }
+
+    fn get_class_hash_at(&self, contract_address: ContractAddress) -> StateResult<ClassHash> {
+        self.0.get_class_hash_at(contract_address)
+    }
+
+    fn get_compiled_class(&self, class_hash: ClassHash) -> StateResult<RunnableCompiledClass> {
+        self.0.get_compiled_class(class_hash)
+    }
+
+    fn get_compiled_class_hash(&self, class_hash: ClassHash) -> StateResult<CompiledClassHash> {
+        self.0.get_compiled_class_hash(class_hash)
+    }
+}
+
+impl UpdatableState for TestState<'_> {
+    fn apply_writes(&mut self, writes: &StateMaps, class_hash_to_class: &ContractClassMapping) {
+        for (class_hash, contract_class) in class_hash_to_class {
+            self.0
+                .set_contract_class(*class_hash, contract_class.clone())
+                .expect("Failed to set contract class");
+        }
+        for (class_hash, compiled_class_hash) in &writes.compiled_class_hashes {
+            self.0
+                .set_compiled_class_hash(*class_hash, *compiled_class_hash)
+                .expect("Failed to set compiled class hash");
+        }
+        for (contract_address, class_hash) in &writes.class_hashes {
+            self.0
+                .set_class_hash_at(*contract_address, *class_hash)
+                .expect("Failed to set class hash");
+        }
+        for ((contract_address, key), value) in &writes.storage {
+            self.0
+                .set_storage_at(*contract_address, *key, *value)
+                .expect("Failed to set storage");
+        }
+        // `State` only allows incrementing nonces
+        for (contract_address, nonce) in &writes.nonces {
+            while self
+                .0
+                .get_nonce_at(*contract_address)
+                .expect("Failed to get nonce")
+                < *nonce
+            {
+                self.0
+                    .increment_nonce(*contract_address)
+                    .expect("Failed to increment nonce");
+            }
+        }
+    }
+}
+
+impl TransactionParams {
+    fn resource_bounds(&self) -> ValidResourceBounds {
+        let resource_bounds = |bound: &ResourceBound| ResourceBounds {
+            max_amount: GasAmount(bound.max_amount),
+            max_price_per_unit: bound.max_price_per_unit.into(),
+        };
+
+        ValidResourceBounds::AllResources(AllResourceBounds {
+            l1_gas: resource_bounds(&self.l1_gas),
+            l2_gas: resource_bounds(&self.l2_gas),
+            l1_data_gas: resource_bounds(&self.l1_data_gas),
+        })
+    }
+
+    fn signature(&self, tx_hash: TransactionHash) -> Result<TransactionSignature, String> {
+        match &self.signer {
+            TransactionSigner::Signature(signature) => Ok(TransactionSignature(signature.clone())),
+            TransactionSigner::StarkKey(private_key) => {
+                let signature = SigningKey::from_secret_scalar(*private_key)
+                    .sign(&tx_hash.0)
+                    .map_err(|error| format!("Failed to sign transaction: {error}"))?;
+                Ok(TransactionSignature(vec![signature.r, signature.s]))
+            }
+        }
+    }
+}
+
+impl CheatnetState {
+    /// Submits an invoke transaction calling `__execute__` of `sender_address` with `calldata`
+    pub fn invoke_transaction(
+        &mut self,
+        state: &mut dyn State,
+        block_context: &BlockContext,
+        sender_address: ContractAddress,
+        calldata: Vec<Felt>,
+        params: &TransactionParams,
+    ) -> Result<TransactionResult<TransactionReceipt>, EnhancedHintError> {
+        let nonce = match params.nonce {
+            Some(nonce) => nonce,
+            None => state.get_nonce_at(sender_address)?,
+        };
+        let mut tx = InvokeTransactionV3 {
+            resource_bounds: params.resource_bounds(),
+            tip: Tip(params.tip),
+            signature: TransactionSignature::default(),
+            nonce,
+            sender_address,
+            calldata: Calldata(Arc::new(calldata)),
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L1,
+            paymaster_data: Default::default(),
+            account_deployment_data: Default::default(),
+        };
+        let tx_hash = tx
+            .calculate_transaction_hash(
+                &block_context.chain_info().chain_id,
+                &TransactionVersion::THREE,
+            )
+            .map_err(anyhow::Error::from)?;
+        tx.signature = match params.signature(tx_hash) {
+            Ok(signature) => signature,
+            Err(message) => {
+                return Ok(TransactionResult::Rejected(ByteArray::from(
+                    message.as_str(),
+                )));
+            }
+        };
+
+        let tx = ExecutableAccountTransaction::Invoke(InvokeTransaction {
+            tx: starknet_api::transaction::InvokeTransaction::V3(tx),
+            tx_hash,
+        });
+
+        Ok(self.execute_transaction(state, block_context, tx))
+    }
+
+    /// Submits a deploy account transaction, returning the address of the deployed account
+    pub fn deploy_account_transaction(
+        &mut self,
+        state: &mut dyn State,
+        block_context: &BlockContext,
+        class_hash: ClassHash,
+        contract_address_salt: Felt,
+        constructor_calldata: Vec<Felt>,
+        params: &TransactionParams,
+    ) -> Result<TransactionResult<(ContractAddress, TransactionReceipt)>, EnhancedHintError> {
+        let mut tx = DeployAccountTransactionV3 {
+            resource_bounds: params.resource_bounds(),
+            tip: Tip(params.tip),
+            signature: TransactionSignature::default(),
+            nonce: params.nonce.unwrap_or_default(),
+            class_hash,
+            contract_address_salt: ContractAddressSalt(contract_address_salt),
+            constructor_calldata: Calldata(Arc::new(constructor_calldata)),
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L1,
+            paymaster_data: Default::default(),
+        };
+        let tx_hash = tx
+            .calculate_transaction_hash(
+                &block_context.chain_info().chain_id,
+                &TransactionVersion::THREE,
+            )
+            .map_err(anyhow::Error::from)?;
+        tx.signature = match params.signature(tx_hash) {
+            Ok(signature) => signature,
+            Err(message) => {
+                return Ok(TransactionResult::Rejected(ByteArray::from(
+                    message.as_str(),
+                )));
+            }
+        };
+
+        let tx = DeployAccountTransaction::create(
+            starknet_api::transaction::DeployAccountTransaction::V3(tx),
+            &block_context.chain_info().chain_id,
+        )
+        .map_err(anyhow::Error::from)?;
+        let contract_address = tx.contract_address;
+
+        Ok(
+            match self.execute_transaction(
+                state,
+                block_context,
+                ExecutableAccountTransaction::DeployAccount(tx),
+            ) {
+                TransactionResult::Accepted(receipt) => {
+                    TransactionResult::Accepted((contract_address, receipt))
+                }
+                TransactionResult::Rejected(reason) => TransactionResult::Rejected(reason),
+            },
+        )
+    }
+
+    /// Submits a declare transaction of the contract with the given name sent from `sender_address`
+    pub fn declare_transaction(
+        &mut self,
+        state: &mut dyn State,
+        block_context: &BlockContext,
+        contracts_data: &ContractsData,
+        sender_address: ContractAddress,
+        contract_name: &str,
+        params: &TransactionParams,
+    ) -> Result<TransactionResult<TransactionReceipt>, EnhancedHintError> {
+        let class_hash = *contracts_data
+            .get_class_hash(contract_name)
+            .with_context(|| format!("Failed to get class hash for name = {contract_name}."))?;
+        let class_info = class_info(contracts_data, contract_name)?;
+
+        let nonce = match params.nonce {
+            Some(nonce) => nonce,
+            None => state.get_nonce_at(sender_address)?,
+        };
+        let mut tx = DeclareTransactionV3 {
+            resource_bounds: params.resource_bounds(),
+            tip: Tip(params.tip),
+            signature: TransactionSignature::default(),
+            nonce,
+            class_hash,
+            compiled_class_hash: class_info.contract_class.compiled_class_hash(),
+            sender_address,
+            nonce_data_availability_mode: DataAvailabilityMode::L1,
+            fee_data_availability_mode: DataAvailabilityMode::L1,
+            paymaster_data: Default::default(),
+            account_deployment_data: Default::default(),
+        };
+        let tx_hash = tx
+            .calculate_transaction_hash(
+                &block_context.chain_info().chain_id,
+                &TransactionVersion::THREE,
+            )
+            .map_err(anyhow::Error::from)?;
+        tx.signature = match params.signature(tx_hash) {
+            Ok(signature) => signature,
+            Err(message) => {
+                return Ok(TransactionResult::Rejected(ByteArray::from(
+                    message.as_str(),
+                )));
+            }
+        };
+
+        let tx = ExecutableAccountTransaction::Declare(DeclareTransaction {
+            tx: starknet_api::transaction::DeclareTransaction::V3(tx),
+            tx_hash,
+            class_info,
+        });
+
+        Ok(self.execute_transaction(state, block_context, tx))
+    }
+
+    /// Executes the transaction with the full account flow: nonce check, `__validate__*`,
+    /// execution and fee charging. Fee is charged only if any of the resource prices is non-zero.
+    fn execute_transaction(
+        &mut self,
+        state: &mut dyn State,
+        block_context: &BlockContext,
+        tx: ExecutableAccountTransaction,
+    ) -> TransactionResult<TransactionReceipt> {
+        let tx_hash = tx.tx_hash();
+        let tx = AccountTransaction::new_for_sequencing(tx);
+
+        match tx.execute(&mut TestState(state), block_context) {
+            Ok(execution_info) => {
+                let events = transaction_events(&execution_info);
+                self.detected_events.extend(events.iter().cloned());
+
+                TransactionResult::Accepted(TransactionReceipt {
+                    transaction_hash: tx_hash.0,
+                    actual_fee: execution_info.receipt.fee.0,
+                    gas_consumed: GasConsumed {
+                        l1_gas: execution_info.receipt.gas.l1_gas.0,
+                        l1_data_gas: execution_info.receipt.gas.l1_data_gas.0,
+                        l2_gas: execution_info.receipt.gas.l2_gas.0,
+                    },
+                    events,
+                    revert_reason: execution_info
+                        .revert_error
+                        .map(|error| ByteArray::from(error.to_string().as_str())),
+                })
+            }
+            Err(error) => TransactionResult::Rejected(ByteArray::from(error.to_string().as_str())),
+        }
+    }
+}
+
+fn class_info(contracts_data: &ContractsData, contract_name: &str) -> Result<ClassInfo> {
+    let artifacts = contracts_data
+        .get_artifacts(contract_name)
+        .with_context(|| format!("Failed to get contract artifact for name = {contract_name}."))?;
+    let sierra_class: SierraClass = serde_json::from_str(&artifacts.sierra)?;
+    let casm_class: CasmContractClass = serde_json::from_str(&artifacts.casm)?;
+    let sierra_version = SierraVersion::extract_from_program(&sierra_class.sierra_program)?;
+
+    Ok(ClassInfo {
+        contract_class: ContractClass::V1((casm_class, sierra_version.clone())),
+        sierra_program_length: sierra_class.sierra_program.len(),
+        abi_length: serde_json::to_string(&sierra_class.abi)?.len(),
+        sierra_version,
+    })
+}
+
+/// Events emitted during validation, execution and fee transfer, in the order of emission
+fn transaction_events(execution_info: &TransactionExecutionInfo) -> Vec<Event> {
+    execution_info
+        .non_optional_call_infos()
+        .flat_map(|call_info| {
+            let mut events: Vec<_> = call_info
+                .iter()
+                .flat_map(|call_info| {
+                    call_info.execution.events.iter().map(|ordered_event| {
+                        (
+                            ordered_event.order,
+                            Event::from_ordered_event(
+                                ordered_event,
+                                call_info.call.storage_address,
+                            ),
+                        )
+                    })
+                })
+                .collect();
+            events.sort_by_key(|(order, _)| *order);
+            events.into_iter().map(|(_, event)| event)
+        })
+        .collect()
+}
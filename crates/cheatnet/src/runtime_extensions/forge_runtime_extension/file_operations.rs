@@ -10,7 +10,7 @@ use starknet_types_core::felt::Felt;
 use starknet_types_core::felt::FromStrError;
 use std::fs::read_to_string;
 
-pub(super) fn read_txt(path: String) -> Result<Vec<Felt>, EnhancedHintError> {
+pub fn read_txt(path: String) -> Result<Vec<Felt>, EnhancedHintError> {
     Ok(read_to_string(&path)?
         .lines()
         .filter(|line| !line.is_empty())
@@ -23,7 +23,7 @@ pub(super) fn read_txt(path: String) -> Result<Vec<Felt>, EnhancedHintError> {
         .collect())
 }
 
-pub(super) fn read_json(path: String) -> Result<Vec<Felt>, EnhancedHintError> {
+pub fn read_json(path: String) -> Result<Vec<Felt>, EnhancedHintError> {
     let content = read_to_string(&path)?;
 
     let json: Map<String, Value> = serde_json::from_str(&content)
@@ -11,12 +11,17 @@ use crate::runtime_extensions::{
     common::get_relocated_vm_trace,
     forge_runtime_extension::cheatcodes::{
         CheatcodeError,
+        contract_state::get_nonce,
         declare::declare,
         deploy::{deploy, deploy_at},
+        event_matching::{EventMatcher, describe_event_mismatch, get_matching_events},
         generate_random_felt::generate_random_felt,
         get_class_hash::get_class_hash,
         l1_handler_execute::l1_handler_execute,
+        l1_messaging::{MessageToL2Receipt, send_message_to_l2},
+        mock_contract::load_abi,
         storage::{calculate_variable_address, load, store},
+        transaction::TransactionParams,
     },
 };
 use crate::state::{CallTrace, CallTraceNode};
@@ -57,10 +62,11 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use url::Url;
 
 pub mod cheatcodes;
 pub mod contracts_data;
-mod file_operations;
+pub mod file_operations;
 mod fuzzer;
 
 pub type ForgeRuntime<'a> = ExtendedRuntime<ForgeExtension<'a>>;
@@ -95,6 +101,18 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "cheat_block_hash" => {
+                let block_number = input_reader.read()?;
+                let operation = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .cheat_block_hash(block_number, operation);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "mock_call" => {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
@@ -120,6 +138,56 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     .stop_mock_call(contract_address, function_selector);
                 Ok(CheatcodeHandlingResult::from_serializable(()))
             }
+            "mock_contract" => {
+                let contract_address = input_reader.read()?;
+                let contract = input_reader.read::<ByteArray>()?.to_string();
+
+                let abi = load_abi(self.contracts_data, &contract)?;
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mock_contract(contract_address, abi)
+                    .with_context(|| format!("Failed to mock contract {contract}"))?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "mock_contract_function" => {
+                let contract_address = input_reader.read()?;
+                let function_name = input_reader.read::<ByteArray>()?.to_string();
+                let ret_data = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mock_contract_function(contract_address, &function_name, ret_data)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "get_mocked_function_calls_count" => {
+                let contract_address = input_reader.read()?;
+                let function_name = input_reader.read::<ByteArray>()?.to_string();
+
+                let calls_count = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_mocked_function_calls_count(contract_address, &function_name)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(calls_count))
+            }
+            "stop_mock_contract" => {
+                let contract_address = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .stop_mock_contract(contract_address);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "replace_bytecode" => {
                 let contract = input_reader.read()?;
                 let class = input_reader.read()?;
@@ -274,6 +342,132 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     ),
                 }
             }
+            "send_message_to_l2" => {
+                let from_address = input_reader.read()?;
+                let to_address = input_reader.read()?;
+                let selector = input_reader.read()?;
+                let payload: Vec<_> = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                let syscall_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let (nonce, message_hash, call_result) = send_message_to_l2(
+                    syscall_handler,
+                    cheatnet_runtime.extension.cheatnet_state,
+                    from_address,
+                    to_address,
+                    selector,
+                    &payload,
+                );
+                let result = match call_result {
+                    CallResult::Success { .. } => Ok(()),
+                    CallResult::Failure(CallFailure::Panic { panic_data }) => Err(panic_data),
+                    CallResult::Failure(CallFailure::Error { msg }) => {
+                        return Err(EnhancedHintError::from(HintError::CustomHint(Box::from(
+                            msg.to_string(),
+                        ))));
+                    }
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    MessageToL2Receipt {
+                        nonce,
+                        message_hash,
+                        result,
+                    },
+                ))
+            }
+            "consume_message_from_l2" => {
+                let from_address = input_reader.read()?;
+                let to_address = input_reader.read()?;
+                let payload: Vec<_> = input_reader.read()?;
+
+                let message_hash = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .consume_message_from_l2(from_address, to_address, &payload);
+
+                Ok(CheatcodeHandlingResult::from_serializable(message_hash))
+            }
+            "get_pending_messages_to_l1" => {
+                let messages = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_pending_messages_to_l1();
+
+                Ok(CheatcodeHandlingResult::from_serializable(messages))
+            }
+            "invoke_transaction" => {
+                let sender_address = input_reader.read()?;
+                let calldata = input_reader.read()?;
+                let params: TransactionParams = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let hint_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let block_context = hint_handler.base.context.tx_context.block_context.clone();
+
+                let result = cheatnet_runtime
+                    .extension
+                    .cheatnet_state
+                    .invoke_transaction(
+                        hint_handler.base.state,
+                        &block_context,
+                        sender_address,
+                        calldata,
+                        &params,
+                    )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
+            "deploy_account_transaction" => {
+                let class_hash = input_reader.read()?;
+                let contract_address_salt = input_reader.read()?;
+                let constructor_calldata = input_reader.read()?;
+                let params: TransactionParams = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let hint_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let block_context = hint_handler.base.context.tx_context.block_context.clone();
+
+                let result = cheatnet_runtime
+                    .extension
+                    .cheatnet_state
+                    .deploy_account_transaction(
+                        hint_handler.base.state,
+                        &block_context,
+                        class_hash,
+                        contract_address_salt,
+                        constructor_calldata,
+                        &params,
+                    )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
+            "declare_transaction" => {
+                let sender_address = input_reader.read()?;
+                let contract_name = input_reader.read::<ByteArray>()?.to_string();
+                let params: TransactionParams = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let hint_handler = &mut cheatnet_runtime.extended_runtime.hint_handler;
+                let block_context = hint_handler.base.context.tx_context.block_context.clone();
+
+                let result = cheatnet_runtime
+                    .extension
+                    .cheatnet_state
+                    .declare_transaction(
+                        hint_handler.base.state,
+                        &block_context,
+                        self.contracts_data,
+                        sender_address,
+                        &contract_name,
+                        &params,
+                    )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(result))
+            }
             "read_txt" => {
                 let file_path: String = input_reader.read::<ByteArray>()?.to_string();
                 let parsed_content = file_operations::read_txt(file_path)?;
@@ -307,6 +501,44 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(events))
             }
+            "get_matching_events" => {
+                let events_offset: usize = input_reader.read()?;
+                let matcher: EventMatcher = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let events = &cheatnet_runtime.extension.cheatnet_state.detected_events;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let matching_events = get_matching_events(
+                    *state,
+                    self.contracts_data,
+                    &events[events_offset..],
+                    &matcher,
+                )
+                .with_context(|| format!("Failed to match events named {}", matcher.name))?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(matching_events))
+            }
+            "describe_event_mismatch" => {
+                let events_offset: usize = input_reader.read()?;
+                let matcher: EventMatcher = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                let events = &cheatnet_runtime.extension.cheatnet_state.detected_events;
+                let state = &mut cheatnet_runtime.extended_runtime.hint_handler.base.state;
+
+                let description = describe_event_mismatch(
+                    *state,
+                    self.contracts_data,
+                    &events[events_offset..],
+                    &matcher,
+                )
+                .with_context(|| format!("Failed to match events named {}", matcher.name))?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(ByteArray::from(
+                    description.as_str(),
+                )))
+            }
             "spy_messages_to_l1" => {
                 let messages_offset = extended_runtime
                     .extended_runtime
@@ -328,6 +560,68 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(messages))
             }
+            "expect_revert" => {
+                let panic_data = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_revert(panic_data);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "expect_emit" => {
+                let events = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .expect_emit(events);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "record_storage_accesses" => {
+                let access_offset = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .record_storage_accesses();
+
+                Ok(CheatcodeHandlingResult::from_serializable(access_offset))
+            }
+            "get_storage_accesses" => {
+                let access_offset = input_reader.read()?;
+
+                let accesses = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_storage_accesses(access_offset);
+
+                Ok(CheatcodeHandlingResult::from_serializable(accesses))
+            }
+            "mark_state" => {
+                let changes_offset = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .mark_state();
+
+                Ok(CheatcodeHandlingResult::from_serializable(changes_offset))
+            }
+            "get_state_diff" => {
+                let changes_offset = input_reader.read()?;
+
+                let state_diff = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .get_state_diff(changes_offset)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(state_diff))
+            }
             "generate_stark_keys" => {
                 let key_pair = SigningKey::from_random();
 
@@ -481,6 +775,56 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
 
                 Ok(CheatcodeHandlingResult::from_serializable(loaded))
             }
+            "set_nonce" => {
+                let target = input_reader.read()?;
+                let nonce = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .set_nonce(target, nonce);
+
+                // Accessing the state writes the nonce right away, before the following changes
+                let state = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler
+                    .base
+                    .state;
+                get_nonce(*state, target).context("Failed to set nonce")?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "get_nonce" => {
+                let state = &mut extended_runtime
+                    .extended_runtime
+                    .extended_runtime
+                    .hint_handler
+                    .base
+                    .state;
+                let target = input_reader.read()?;
+                let nonce = get_nonce(*state, target).context("Failed to get nonce")?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(nonce))
+            }
+            "set_class_hash" => {
+                let target = input_reader.read()?;
+                let class_hash = input_reader.read()?;
+
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+                cheatnet_runtime
+                    .extension
+                    .cheatnet_state
+                    .set_class_hash(
+                        cheatnet_runtime.extended_runtime.hint_handler.base.state,
+                        target,
+                        class_hash,
+                    )
+                    .context("Failed to set class hash")?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
             "map_entry_address" => {
                 let map_selector = input_reader.read()?;
                 let keys: Vec<_> = input_reader.read()?;
@@ -490,6 +834,103 @@ impl<'a> ExtensionLogic for ForgeExtension<'a> {
                     map_entry_address,
                 ))
             }
+            "create_fork" => {
+                let url = input_reader.read::<ByteArray>()?.to_string();
+                let block_id = input_reader.read()?;
+                let url = Url::parse(&url).with_context(|| format!("Invalid fork url {url}"))?;
+
+                let fork_id = extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .create_fork(url, block_id)?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(fork_id))
+            }
+            "select_fork" => {
+                let fork_id = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.select_fork(
+                    fork_id,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "roll_fork" => {
+                let block_number = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.roll_fork(
+                    block_number,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "warp" => {
+                let block_timestamp = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.warp(
+                    block_timestamp,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                );
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "roll" => {
+                let block_number = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.roll(
+                    block_number,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                );
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "advance_blocks" => {
+                let count = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.advance_blocks(
+                    count,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "set_block_time" => {
+                let block_time = input_reader.read()?;
+
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .set_block_time(block_time);
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "set_sequencer" => {
+                let sequencer_address = input_reader.read()?;
+                let cheatnet_runtime = &mut extended_runtime.extended_runtime;
+
+                cheatnet_runtime.extension.cheatnet_state.set_sequencer(
+                    sequencer_address,
+                    cheatnet_runtime.extended_runtime.hint_handler.base.context,
+                );
+
+                Ok(CheatcodeHandlingResult::from_serializable(()))
+            }
+            "active_fork" => Ok(CheatcodeHandlingResult::from_serializable(
+                extended_runtime
+                    .extended_runtime
+                    .extension
+                    .cheatnet_state
+                    .active_fork(),
+            )),
             "generate_random_felt" => Ok(CheatcodeHandlingResult::from_serializable(
                 generate_random_felt(),
             )),
@@ -1,11 +1,20 @@
-use crate::constants::build_test_entry_point;
+use crate::constants::{
+    BLOCK_HASH_CONTRACT_ADDRESS, DEFAULT_BLOCK_TIME, build_test_entry_point, default_block_hash,
+};
+use crate::forking::forks::SharedForks;
 use crate::forking::state::ForkStateReader;
 use crate::runtime_extensions::call_to_blockifier_runtime_extension::rpc::CallResult;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
     ExecutionInfoMock, ResourceBounds,
 };
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::contract_state::SharedPendingNonces;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::expect::CallExpectations;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::l1_messaging::PendingMessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::mock_contract::ContractMock;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use crate::runtime_extensions::forge_runtime_extension::cheatcodes::spy_messages_to_l1::MessageToL1;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::state_diff::SharedStateChanges;
+use crate::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::StorageAccess;
 use blockifier::execution::call_info::OrderedL2ToL1Message;
 use blockifier::execution::contract_class::RunnableCompiledClass;
 use blockifier::execution::entry_point::CallEntryPoint;
@@ -39,6 +48,36 @@ use std::rc::Rc;
 pub enum CheatSpan {
     Indefinite,
     TargetCalls(usize),
+    /// Applies only to calls made by the given caller, for the given number of such calls
+    TargetCallsFrom(ContractAddress, usize),
+    /// Applies to all calls until and including the first call of the given selector
+    UntilSelector(EntryPointSelector),
+}
+
+impl CheatSpan {
+    /// Panics if the span targets 0 calls, as such a cheat could never be applied
+    pub fn validate(&self) {
+        assert!(
+            !matches!(self, Self::TargetCalls(0) | Self::TargetCallsFrom(_, 0)),
+            "cannot cheat 0 calls, number of calls in CheatSpan must be greater than 0"
+        );
+    }
+}
+
+/// Call to a cheated contract, used to resolve cheats with spans filtered by the caller or selector
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TargetCall {
+    pub caller_address: ContractAddress,
+    pub entry_point_selector: EntryPointSelector,
+}
+
+impl From<&CallEntryPoint> for TargetCall {
+    fn from(entry_point: &CallEntryPoint) -> Self {
+        Self {
+            caller_address: entry_point.caller_address,
+            entry_point_selector: entry_point.entry_point_selector,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -70,6 +109,12 @@ impl StateReader for ExtendedStateReader {
         self.dict_state_reader
             .get_storage_at(contract_address, key)
             .or_else(|_| {
+                if contract_address
+                    == ContractAddress::try_from_hex_str(BLOCK_HASH_CONTRACT_ADDRESS).unwrap()
+                {
+                    return self.get_block_hash(key);
+                }
+
                 self.fork_state_reader
                     .as_ref()
                     .map_or(Ok(Felt252::default()), {
@@ -123,6 +168,18 @@ impl StateReader for ExtendedStateReader {
 }
 
 impl ExtendedStateReader {
+    /// Block hashes are stored in the block hash contract under block numbers
+    fn get_block_hash(&self, key: StorageKey) -> StateResult<Felt> {
+        let Ok(block_number) = u64::try_from(*key.0.key()) else {
+            return Ok(Felt::default());
+        };
+
+        match &self.fork_state_reader {
+            Some(reader) => reader.get_block_hash(block_number),
+            None => Ok(default_block_hash(block_number)),
+        }
+    }
+
     pub fn get_chain_id(&self) -> anyhow::Result<Option<ChainId>> {
         self.fork_state_reader
             .as_ref()
@@ -139,12 +196,44 @@ pub enum CheatStatus<T> {
 }
 
 impl<T> CheatStatus<T> {
-    pub fn decrement_cheat_span(&mut self) {
-        if let CheatStatus::Cheated(_, CheatSpan::TargetCalls(n)) = self {
-            *n -= 1;
-            if *n == 0 {
+    /// Progresses the span of the cheat after it was resolved for the `call`
+    pub fn progress_cheat_span(&mut self, call: &TargetCall) {
+        match self {
+            CheatStatus::Cheated(_, CheatSpan::TargetCalls(n)) => {
+                *n -= 1;
+                if *n == 0 {
+                    *self = CheatStatus::Uncheated;
+                }
+            }
+            CheatStatus::Cheated(_, CheatSpan::TargetCallsFrom(caller_address, n))
+                if *caller_address == call.caller_address =>
+            {
+                *n -= 1;
+                if *n == 0 {
+                    *self = CheatStatus::Uncheated;
+                }
+            }
+            CheatStatus::Cheated(_, CheatSpan::UntilSelector(selector))
+                if *selector == call.entry_point_selector =>
+            {
                 *self = CheatStatus::Uncheated;
             }
+            _ => {}
+        }
+    }
+
+    /// Returns the cheated value if the cheat applies to the `call`
+    pub fn as_value_for_call(&self, call: &TargetCall) -> Option<T>
+    where
+        T: Clone,
+    {
+        match self {
+            Self::Cheated(_, CheatSpan::TargetCallsFrom(caller_address, _))
+                if *caller_address != call.caller_address =>
+            {
+                None
+            }
+            _ => self.as_value(),
         }
     }
 
@@ -306,6 +395,7 @@ pub struct CheatedData {
     pub caller_address: Option<ContractAddress>,
     pub sequencer_address: Option<ContractAddress>,
     pub tx_info: CheatedTxInfo,
+    pub block_hashes: HashMap<u64, Felt>,
 }
 
 #[derive(Debug)]
@@ -323,14 +413,26 @@ pub struct EncounteredError {
 pub struct CheatnetState {
     pub cheated_execution_info_contracts: HashMap<ContractAddress, ExecutionInfoMock>,
     pub global_cheated_execution_info: ExecutionInfoMock,
+    pub cheated_block_hashes: HashMap<ContractAddress, HashMap<u64, CheatStatus<Felt>>>,
+    pub global_cheated_block_hashes: HashMap<u64, Felt>,
 
     pub mocked_functions:
         HashMap<ContractAddress, HashMap<EntryPointSelector, CheatStatus<Vec<Felt>>>>,
+    pub mocked_contracts: HashMap<ContractAddress, ContractMock>,
     pub replaced_bytecode_contracts: HashMap<ContractAddress, ClassHash>,
     pub detected_events: Vec<Event>,
     pub detected_messages_to_l1: Vec<MessageToL1>,
+    pub pending_messages_to_l1: Vec<PendingMessageToL1>,
+    pub l1_to_l2_message_nonce: u64,
+    pub detected_storage_accesses: Vec<StorageAccess>,
+    pub call_expectations: CallExpectations,
+    pub is_recording_storage_accesses: bool,
+    pub state_changes: SharedStateChanges,
+    pub pending_nonces: SharedPendingNonces,
     pub deploy_salt_base: u32,
     pub block_info: BlockInfo,
+    pub block_time: u64,
+    pub forks: SharedForks,
     pub trace_data: TraceData,
     pub encountered_errors: Vec<EncounteredError>,
     pub fuzzer_args: Vec<String>,
@@ -349,12 +451,24 @@ impl Default for CheatnetState {
         Self {
             cheated_execution_info_contracts: HashMap::default(),
             global_cheated_execution_info: ExecutionInfoMock::default(),
+            cheated_block_hashes: HashMap::default(),
+            global_cheated_block_hashes: HashMap::default(),
             mocked_functions: HashMap::default(),
+            mocked_contracts: HashMap::default(),
             replaced_bytecode_contracts: HashMap::default(),
             detected_events: vec![],
             detected_messages_to_l1: vec![],
+            pending_messages_to_l1: vec![],
+            l1_to_l2_message_nonce: 0,
+            detected_storage_accesses: vec![],
+            call_expectations: CallExpectations::default(),
+            is_recording_storage_accesses: false,
+            state_changes: SharedStateChanges::default(),
+            pending_nonces: SharedPendingNonces::default(),
             deploy_salt_base: 0,
             block_info: SerializableBlockInfo::default().into(),
+            block_time: DEFAULT_BLOCK_TIME,
+            forks: SharedForks::default(),
             trace_data: TraceData {
                 current_call_stack: NotEmptyCallStack::from(test_call),
                 is_vm_trace_needed: false,
@@ -367,38 +481,65 @@ impl Default for CheatnetState {
 
 impl CheatnetState {
     #[must_use]
-    pub fn create_cheated_data(&mut self, contract_address: ContractAddress) -> CheatedData {
+    pub fn create_cheated_data(
+        &mut self,
+        contract_address: ContractAddress,
+        call: &TargetCall,
+    ) -> CheatedData {
+        let block_hashes = self.get_cheated_block_hashes(contract_address, call);
         let execution_info = self.get_cheated_execution_info_for_contract(contract_address);
 
         CheatedData {
-            block_number: execution_info.block_info.block_number.as_value(),
-            block_timestamp: execution_info.block_info.block_timestamp.as_value(),
-            caller_address: execution_info.caller_address.as_value(),
-            sequencer_address: execution_info.block_info.sequencer_address.as_value(),
+            block_number: execution_info
+                .block_info
+                .block_number
+                .as_value_for_call(call),
+            block_timestamp: execution_info
+                .block_info
+                .block_timestamp
+                .as_value_for_call(call),
+            caller_address: execution_info.caller_address.as_value_for_call(call),
+            sequencer_address: execution_info
+                .block_info
+                .sequencer_address
+                .as_value_for_call(call),
             tx_info: CheatedTxInfo {
-                version: execution_info.tx_info.version.as_value(),
+                version: execution_info.tx_info.version.as_value_for_call(call),
                 account_contract_address: execution_info
                     .tx_info
                     .account_contract_address
-                    .as_value(),
-                max_fee: execution_info.tx_info.max_fee.as_value(),
-                signature: execution_info.tx_info.signature.as_value(),
-                transaction_hash: execution_info.tx_info.transaction_hash.as_value(),
-                chain_id: execution_info.tx_info.chain_id.as_value(),
-                nonce: execution_info.tx_info.nonce.as_value(),
-                resource_bounds: execution_info.tx_info.resource_bounds.as_value(),
-                tip: execution_info.tx_info.tip.as_value(),
-                paymaster_data: execution_info.tx_info.paymaster_data.as_value(),
+                    .as_value_for_call(call),
+                max_fee: execution_info.tx_info.max_fee.as_value_for_call(call),
+                signature: execution_info.tx_info.signature.as_value_for_call(call),
+                transaction_hash: execution_info
+                    .tx_info
+                    .transaction_hash
+                    .as_value_for_call(call),
+                chain_id: execution_info.tx_info.chain_id.as_value_for_call(call),
+                nonce: execution_info.tx_info.nonce.as_value_for_call(call),
+                resource_bounds: execution_info
+                    .tx_info
+                    .resource_bounds
+                    .as_value_for_call(call),
+                tip: execution_info.tx_info.tip.as_value_for_call(call),
+                paymaster_data: execution_info
+                    .tx_info
+                    .paymaster_data
+                    .as_value_for_call(call),
                 nonce_data_availability_mode: execution_info
                     .tx_info
                     .nonce_data_availability_mode
-                    .as_value(),
+                    .as_value_for_call(call),
                 fee_data_availability_mode: execution_info
                     .tx_info
                     .fee_data_availability_mode
-                    .as_value(),
-                account_deployment_data: execution_info.tx_info.account_deployment_data.as_value(),
+                    .as_value_for_call(call),
+                account_deployment_data: execution_info
+                    .tx_info
+                    .account_deployment_data
+                    .as_value_for_call(call),
             },
+            block_hashes,
         }
     }
 
@@ -407,7 +548,8 @@ impl CheatnetState {
 
         // case of cheating the test address itself
         if current_call_stack.size() == 1 {
-            self.create_cheated_data(contract_address)
+            let call = TargetCall::from(&current_call_stack.top().borrow().entry_point);
+            self.create_cheated_data(contract_address, &call)
             // do not update the cheats, as the test address cannot be called from the outside
         } else {
             current_call_stack.top_cheated_data()
@@ -460,8 +602,9 @@ impl CheatnetState {
             .as_value()
     }
 
-    pub fn update_cheats(&mut self, address: &ContractAddress) {
-        self.progress_cheated_execution_info(*address);
+    pub fn update_cheats(&mut self, address: &ContractAddress, call: &TargetCall) {
+        self.progress_cheated_execution_info(*address, call);
+        self.progress_cheated_block_hashes(*address, call);
     }
 
     pub fn update_fuzzer_args(&mut self, arg: String) {
@@ -486,6 +629,20 @@ impl TraceData {
         self.current_call_stack.push(new_call, cheated_data);
     }
 
+    /// Returns the entry point of the last call made from the current one
+    pub fn last_nested_call_entry_point(&mut self) -> Option<CallEntryPoint> {
+        let current_call = self.current_call_stack.top();
+        let last_call = current_call
+            .borrow()
+            .nested_calls
+            .last()?
+            .extract_entry_point_call()?
+            .clone();
+
+        let entry_point = last_call.borrow().entry_point.clone();
+        Some(entry_point)
+    }
+
     pub fn set_class_hash_for_current_call(&mut self, class_hash: ClassHash) {
         let current_call = self.current_call_stack.top();
         current_call.borrow_mut().entry_point.class_hash = Some(class_hash);
@@ -0,0 +1,140 @@
+use crate::common::assertions::assert_success;
+use crate::common::recover_data;
+use cheatnet::constants::{DEFAULT_BLOCK_TIME, default_block_hash};
+use runtime::starknet::context::{DEFAULT_BLOCK_NUMBER, build_context};
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+
+use super::test_environment::TestEnvironment;
+
+trait BlockProgressionTrait {
+    fn warp(&mut self, block_timestamp: u64);
+    fn roll(&mut self, block_number: u64);
+    fn advance_blocks(&mut self, count: u64);
+    fn set_sequencer(&mut self, sequencer_address: ContractAddress);
+    fn read_block(&mut self, contract_address: &ContractAddress) -> Vec<Felt>;
+}
+
+impl BlockProgressionTrait for TestEnvironment {
+    fn warp(&mut self, block_timestamp: u64) {
+        let mut context = build_context(&self.cheatnet_state.block_info, None);
+        self.cheatnet_state.warp(block_timestamp, &mut context);
+    }
+
+    fn roll(&mut self, block_number: u64) {
+        let mut context = build_context(&self.cheatnet_state.block_info, None);
+        self.cheatnet_state.roll(block_number, &mut context);
+    }
+
+    fn advance_blocks(&mut self, count: u64) {
+        let mut context = build_context(&self.cheatnet_state.block_info, None);
+        self.cheatnet_state
+            .advance_blocks(count, &mut context)
+            .unwrap();
+    }
+
+    fn set_sequencer(&mut self, sequencer_address: ContractAddress) {
+        let mut context = build_context(&self.cheatnet_state.block_info, None);
+        self.cheatnet_state
+            .set_sequencer(sequencer_address, &mut context);
+    }
+
+    /// Returns block number, timestamp, sequencer address and hash of the block 10 blocks back
+    fn read_block(&mut self, contract_address: &ContractAddress) -> Vec<Felt> {
+        assert_success(
+            self.call_contract(contract_address, "write_block", &[]),
+            &[],
+        );
+
+        [
+            "read_block_number",
+            "read_block_timestamp",
+            "read_sequencer_address",
+            "read_block_hash",
+        ]
+        .iter()
+        .map(|selector| recover_data(self.call_contract(contract_address, selector, &[]))[0])
+        .collect()
+    }
+}
+
+#[test]
+fn warp_and_roll() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.warp(1000);
+    test_env.roll(DEFAULT_BLOCK_NUMBER + 100);
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[0], Felt::from(DEFAULT_BLOCK_NUMBER + 100));
+    assert_eq!(block[1], Felt::from(1000));
+    assert_eq!(block[3], default_block_hash(DEFAULT_BLOCK_NUMBER + 90));
+}
+
+#[test]
+fn advance_blocks() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.warp(1000);
+    test_env.advance_blocks(5);
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[0], Felt::from(DEFAULT_BLOCK_NUMBER + 5));
+    assert_eq!(block[1], Felt::from(1000 + 5 * DEFAULT_BLOCK_TIME));
+    assert_eq!(block[3], default_block_hash(DEFAULT_BLOCK_NUMBER - 5));
+}
+
+#[test]
+fn advance_blocks_with_block_time() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.warp(1000);
+    test_env.cheatnet_state.set_block_time(60);
+    test_env.advance_blocks(2);
+    test_env.advance_blocks(1);
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[0], Felt::from(DEFAULT_BLOCK_NUMBER + 3));
+    assert_eq!(block[1], Felt::from(1180));
+}
+
+#[test]
+fn set_sequencer() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.set_sequencer(ContractAddress::from(123_u128));
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[2], Felt::from(123));
+}
+
+#[test]
+fn cheats_take_precedence() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env
+        .cheatnet_state
+        .start_cheat_block_number(contract_address, 123);
+    test_env.advance_blocks(5);
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[0], Felt::from(123));
+
+    test_env
+        .cheatnet_state
+        .stop_cheat_block_number(contract_address);
+
+    let block = test_env.read_block(&contract_address);
+    assert_eq!(block[0], Felt::from(DEFAULT_BLOCK_NUMBER + 5));
+    assert_eq!(block[1], Felt::from(5 * DEFAULT_BLOCK_TIME));
+}
@@ -0,0 +1,175 @@
+use crate::common::assertions::assert_success;
+use crate::common::recover_data;
+use cheatnet::constants::default_block_hash;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    CheatArguments, Operation,
+};
+use cheatnet::state::CheatSpan;
+use runtime::starknet::context::DEFAULT_BLOCK_NUMBER;
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+
+use super::test_environment::TestEnvironment;
+
+const BLOCK_NUMBER: u64 = DEFAULT_BLOCK_NUMBER - 10;
+
+trait CheatBlockHashTrait {
+    fn cheat_block_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        block_number: u64,
+        block_hash: Felt,
+        span: CheatSpan,
+    );
+    fn start_cheat_block_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        block_number: u64,
+        block_hash: Felt,
+    );
+    fn stop_cheat_block_hash(&mut self, contract_address: ContractAddress, block_number: u64);
+    fn start_cheat_block_hash_global(&mut self, block_number: u64, block_hash: Felt);
+    fn get_block_hash(&mut self, contract_address: &ContractAddress) -> Felt;
+}
+
+impl CheatBlockHashTrait for TestEnvironment {
+    fn cheat_block_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        block_number: u64,
+        block_hash: Felt,
+        span: CheatSpan,
+    ) {
+        self.cheatnet_state.cheat_block_hash(
+            block_number,
+            Operation::Start(CheatArguments {
+                value: block_hash,
+                span,
+                target: contract_address,
+            }),
+        );
+    }
+
+    fn start_cheat_block_hash(
+        &mut self,
+        contract_address: ContractAddress,
+        block_number: u64,
+        block_hash: Felt,
+    ) {
+        self.cheatnet_state
+            .start_cheat_block_hash(contract_address, block_number, block_hash);
+    }
+
+    fn stop_cheat_block_hash(&mut self, contract_address: ContractAddress, block_number: u64) {
+        self.cheatnet_state
+            .stop_cheat_block_hash(contract_address, block_number);
+    }
+
+    fn start_cheat_block_hash_global(&mut self, block_number: u64, block_hash: Felt) {
+        self.cheatnet_state
+            .cheat_block_hash(block_number, Operation::StartGlobal(block_hash));
+    }
+
+    fn get_block_hash(&mut self, contract_address: &ContractAddress) -> Felt {
+        assert_success(
+            self.call_contract(contract_address, "write_block", &[]),
+            &[],
+        );
+
+        recover_data(self.call_contract(contract_address, "read_block_hash", &[]))[0]
+    }
+}
+
+#[test]
+fn default_block_hash_history() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    assert_eq!(
+        test_env.get_block_hash(&contract_address),
+        default_block_hash(BLOCK_NUMBER)
+    );
+}
+
+#[test]
+fn cheat_block_hash_simple() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.start_cheat_block_hash(contract_address, BLOCK_NUMBER, Felt::from(123));
+
+    assert_eq!(test_env.get_block_hash(&contract_address), Felt::from(123));
+}
+
+#[test]
+fn cheat_block_hash_stop() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.start_cheat_block_hash(contract_address, BLOCK_NUMBER, Felt::from(123));
+    assert_eq!(test_env.get_block_hash(&contract_address), Felt::from(123));
+
+    test_env.stop_cheat_block_hash(contract_address, BLOCK_NUMBER);
+    assert_eq!(
+        test_env.get_block_hash(&contract_address),
+        default_block_hash(BLOCK_NUMBER)
+    );
+}
+
+#[test]
+fn cheat_block_hash_other_block() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.start_cheat_block_hash(contract_address, BLOCK_NUMBER - 1, Felt::from(123));
+
+    assert_eq!(
+        test_env.get_block_hash(&contract_address),
+        default_block_hash(BLOCK_NUMBER)
+    );
+}
+
+#[test]
+fn cheat_block_hash_global() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address_1 = test_env.deploy("Blocker", &[]);
+    let contract_address_2 = test_env.deploy("Blocker", &[]);
+
+    test_env.start_cheat_block_hash_global(BLOCK_NUMBER, Felt::from(123));
+    test_env.start_cheat_block_hash(contract_address_2, BLOCK_NUMBER, Felt::from(456));
+
+    assert_eq!(
+        test_env.get_block_hash(&contract_address_1),
+        Felt::from(123)
+    );
+    assert_eq!(
+        test_env.get_block_hash(&contract_address_2),
+        Felt::from(456)
+    );
+}
+
+#[test]
+fn cheat_block_hash_with_span() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("Blocker", &[]);
+
+    test_env.cheat_block_hash(
+        contract_address,
+        BLOCK_NUMBER,
+        Felt::from(123),
+        CheatSpan::TargetCalls(2),
+    );
+
+    // `write_block` and `read_block_hash` are two calls to the target
+    assert_eq!(test_env.get_block_hash(&contract_address), Felt::from(123));
+    assert_eq!(
+        test_env.get_block_hash(&contract_address),
+        default_block_hash(BLOCK_NUMBER)
+    );
+}
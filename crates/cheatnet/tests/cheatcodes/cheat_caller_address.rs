@@ -1,5 +1,5 @@
 use crate::common::assertions::assert_success;
-use crate::common::get_contracts;
+use crate::common::{felt_selector_from_name, get_contracts};
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::spy_events::Event;
 use cheatnet::state::CheatSpan;
@@ -457,3 +457,80 @@ fn cheat_caller_address_library_call_with_span() {
         &[TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap()],
     );
 }
+
+#[test]
+fn cheat_caller_address_with_span_from_caller() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatCallerAddressChecker", &[]);
+    let proxy_address = test_env.deploy("CheatCallerAddressCheckerProxy", &[]);
+
+    test_env.cheat_caller_address(
+        contract_address,
+        123,
+        CheatSpan::TargetCallsFrom(proxy_address, 1),
+    );
+
+    // Calls from other contracts are neither cheated nor counted
+    assert_success(
+        test_env.call_contract(&contract_address, "get_caller_address", &[]),
+        &[TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap()],
+    );
+
+    let selector = "get_cheated_caller_address";
+    assert_success(
+        test_env.call_contract(&proxy_address, selector, &[contract_address.into_()]),
+        &[Felt::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&proxy_address, selector, &[contract_address.into_()]),
+        &[proxy_address.into_()],
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "cannot cheat 0 calls, number of calls in CheatSpan must be greater than 0"
+)]
+fn cheat_caller_address_with_span_from_caller_zero_calls() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatCallerAddressChecker", &[]);
+    let proxy_address = test_env.deploy("CheatCallerAddressCheckerProxy", &[]);
+
+    test_env.cheat_caller_address(
+        contract_address,
+        123,
+        CheatSpan::TargetCallsFrom(proxy_address, 0),
+    );
+}
+
+#[test]
+fn cheat_caller_address_until_selector() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("CheatCallerAddressChecker", &[]);
+
+    test_env.cheat_caller_address(
+        contract_address,
+        123,
+        CheatSpan::UntilSelector(felt_selector_from_name("get_caller_address_and_emit_event")),
+    );
+
+    assert_success(
+        test_env.call_contract(&contract_address, "get_caller_address", &[]),
+        &[Felt::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_caller_address", &[]),
+        &[Felt::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_caller_address_and_emit_event", &[]),
+        &[Felt::from(123)],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_caller_address", &[]),
+        &[TryFromHexStr::try_from_hex_str(TEST_ADDRESS).unwrap()],
+    );
+}
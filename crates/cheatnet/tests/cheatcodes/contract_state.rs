@@ -0,0 +1,152 @@
+use crate::{
+    cheatcodes::test_environment::TestEnvironment,
+    common::{assertions::assert_success, get_contracts},
+};
+use blockifier::state::state_api::StateReader;
+use cheatnet::constants::build_testing_state;
+use cheatnet::forking::multi_fork_state::MultiForkState;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::contract_state::get_nonce;
+use cheatnet::state::{CheatnetState, ExtendedStateReader};
+use starknet_api::core::{ClassHash, ContractAddress, Nonce};
+use starknet_types_core::felt::Felt;
+
+trait ContractStateTrait {
+    fn set_class_hash(
+        &mut self,
+        target: ContractAddress,
+        class_hash: ClassHash,
+    ) -> anyhow::Result<()>;
+}
+
+impl ContractStateTrait for TestEnvironment {
+    fn set_class_hash(
+        &mut self,
+        target: ContractAddress,
+        class_hash: ClassHash,
+    ) -> anyhow::Result<()> {
+        self.cheatnet_state
+            .set_class_hash(&mut self.cached_state, target, class_hash)
+    }
+}
+
+/// Nonces set with `set_nonce` are written by `MultiForkState`, which `TestEnvironment` does not use
+fn create_multi_fork_state(cheatnet_state: &CheatnetState) -> MultiForkState {
+    MultiForkState::new(
+        ExtendedStateReader {
+            dict_state_reader: build_testing_state(),
+            fork_state_reader: None,
+        },
+        cheatnet_state.forks.clone(),
+        cheatnet_state.state_changes.clone(),
+        cheatnet_state.pending_nonces.clone(),
+    )
+}
+
+#[test]
+fn set_nonce_increases_nonce() {
+    let mut cheatnet_state = CheatnetState::default();
+    let mut state = create_multi_fork_state(&cheatnet_state);
+    let contract_address = ContractAddress::from(123_u128);
+
+    assert_eq!(
+        get_nonce(&mut state, contract_address).unwrap(),
+        Nonce::default()
+    );
+
+    cheatnet_state.set_nonce(contract_address, Nonce(Felt::from(5)));
+
+    assert_eq!(
+        get_nonce(&mut state, contract_address).unwrap(),
+        Nonce(Felt::from(5))
+    );
+}
+
+#[test]
+fn set_nonce_decreases_nonce() {
+    let mut cheatnet_state = CheatnetState::default();
+    let mut state = create_multi_fork_state(&cheatnet_state);
+    let contract_address = ContractAddress::from(123_u128);
+
+    cheatnet_state.set_nonce(contract_address, Nonce(Felt::from(5)));
+    assert_eq!(
+        get_nonce(&mut state, contract_address).unwrap(),
+        Nonce(Felt::from(5))
+    );
+
+    cheatnet_state.set_nonce(contract_address, Nonce(Felt::from(2)));
+
+    assert_eq!(
+        get_nonce(&mut state, contract_address).unwrap(),
+        Nonce(Felt::from(2))
+    );
+}
+
+#[test]
+fn set_class_hash_of_deployed_contract() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash_a = test_env.declare("ReplaceBytecodeA", &contracts_data);
+    let class_hash_b = test_env.declare("ReplaceBytecodeB", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash_a, &[]);
+
+    test_env
+        .set_class_hash(contract_address, class_hash_b)
+        .unwrap();
+
+    assert_eq!(
+        test_env
+            .cached_state
+            .get_class_hash_at(contract_address)
+            .unwrap(),
+        class_hash_b
+    );
+    let output = test_env.call_contract(&contract_address, "get_const", &[]);
+    assert_success(output, &[Felt::from(420)]);
+}
+
+#[test]
+fn set_class_hash_cancels_replace_bytecode() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash_a = test_env.declare("ReplaceBytecodeA", &contracts_data);
+    let class_hash_b = test_env.declare("ReplaceBytecodeB", &contracts_data);
+    let contract_address = test_env.deploy_wrapper(&class_hash_a, &[]);
+
+    test_env
+        .cheatnet_state
+        .replace_class_for_contract(contract_address, class_hash_b);
+    test_env
+        .set_class_hash(contract_address, class_hash_a)
+        .unwrap();
+
+    let output = test_env.call_contract(&contract_address, "get_const", &[]);
+    assert_success(output, &[Felt::from(2137)]);
+}
+
+#[test]
+fn set_class_hash_at_empty_address() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    let class_hash = test_env.declare("ReplaceBytecodeB", &contracts_data);
+    let contract_address = ContractAddress::from(123_u128);
+
+    test_env
+        .set_class_hash(contract_address, class_hash)
+        .unwrap();
+
+    let output = test_env.call_contract(&contract_address, "get_const", &[]);
+    assert_success(output, &[Felt::from(420)]);
+}
+
+#[test]
+fn set_undeclared_class_hash() {
+    let mut test_env = TestEnvironment::new();
+    let contract_address = test_env.deploy("HelloStarknet", &[]);
+
+    let result = test_env.set_class_hash(contract_address, ClassHash(Felt::from(123)));
+
+    assert!(result.is_err());
+}
@@ -5,11 +5,14 @@ use starknet_types_core::felt::Felt;
 
 mod test_environment;
 
+mod block_progression;
+mod cheat_block_hash;
 mod cheat_block_number;
 mod cheat_block_timestamp;
 mod cheat_caller_address;
 mod cheat_execution_info;
 mod cheat_sequencer_address;
+mod contract_state;
 mod declare;
 mod deploy;
 mod generate_random_felt;
@@ -18,9 +21,11 @@ mod load;
 mod mock_call;
 mod multiple_writes_same_storage;
 mod precalculate_address;
+mod record_storage_accesses;
 mod replace_bytecode;
 mod spy_events;
 mod store;
+mod transaction;
 
 pub fn map_entry_address(var_name: &str, key: &[Felt]) -> Felt {
     calculate_variable_address(felt_selector_from_name(var_name).into_(), Some(key))
@@ -0,0 +1,107 @@
+use crate::cheatcodes::variable_address;
+use crate::common::assertions::assert_success;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::storage_accesses::{
+    StorageAccess, StorageAccessKind, StorageRead, StorageWrite,
+};
+use starknet_api::core::ContractAddress;
+use starknet_types_core::felt::Felt;
+
+use super::test_environment::TestEnvironment;
+
+fn read(contract_address: ContractAddress, key: Felt, value: u8) -> StorageAccess {
+    StorageAccess {
+        contract_address,
+        kind: StorageAccessKind::Read(StorageRead {
+            key,
+            value: Felt::from(value),
+        }),
+    }
+}
+
+fn write(
+    contract_address: ContractAddress,
+    key: Felt,
+    old_value: u8,
+    new_value: u8,
+) -> StorageAccess {
+    StorageAccess {
+        contract_address,
+        kind: StorageAccessKind::Write(StorageWrite {
+            key,
+            old_value: Felt::from(old_value),
+            new_value: Felt::from(new_value),
+        }),
+    }
+}
+
+#[test]
+fn record_storage_accesses_simple() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("HelloStarknet", &[]);
+    let balance = variable_address("balance");
+
+    let access_offset = test_env.cheatnet_state.record_storage_accesses();
+
+    assert_success(
+        test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(5)]),
+        &[],
+    );
+    assert_success(
+        test_env.call_contract(&contract_address, "get_balance", &[]),
+        &[Felt::from(5)],
+    );
+
+    assert_eq!(
+        test_env.cheatnet_state.get_storage_accesses(access_offset),
+        vec![
+            read(contract_address, balance, 0),
+            write(contract_address, balance, 0, 5),
+            read(contract_address, balance, 5),
+        ]
+    );
+}
+
+#[test]
+fn record_storage_accesses_not_started() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("HelloStarknet", &[]);
+
+    assert_success(
+        test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(5)]),
+        &[],
+    );
+
+    assert_eq!(test_env.cheatnet_state.get_storage_accesses(0), vec![]);
+}
+
+#[test]
+fn record_storage_accesses_with_offset() {
+    let mut test_env = TestEnvironment::new();
+
+    let contract_address = test_env.deploy("HelloStarknet", &[]);
+    let balance = variable_address("balance");
+
+    test_env.cheatnet_state.record_storage_accesses();
+
+    assert_success(
+        test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(5)]),
+        &[],
+    );
+
+    let access_offset = test_env.cheatnet_state.record_storage_accesses();
+
+    assert_success(
+        test_env.call_contract(&contract_address, "increase_balance", &[Felt::from(2)]),
+        &[],
+    );
+
+    assert_eq!(
+        test_env.cheatnet_state.get_storage_accesses(access_offset),
+        vec![
+            read(contract_address, balance, 5),
+            write(contract_address, balance, 5, 7),
+        ]
+    );
+}
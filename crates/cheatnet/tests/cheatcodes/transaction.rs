@@ -0,0 +1,111 @@
+use super::test_environment::TestEnvironment;
+use crate::cheatcodes::map_entry_address;
+use crate::common::{get_contracts, recover_data};
+use blockifier::state::state_api::State;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::transaction::{
+    ResourceBound, TransactionParams, TransactionResult, TransactionSigner,
+};
+use conversions::IntoConv;
+use conversions::string::TryFromHexStr;
+use runtime::starknet::context::{ERC20_CONTRACT_ADDRESS, build_context};
+use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
+use starknet_types_core::felt::Felt;
+
+const INITIAL_BALANCE: u128 = 10_u128.pow(22);
+
+fn balance_of(
+    test_env: &mut TestEnvironment,
+    fee_token: &ContractAddress,
+    account: ContractAddress,
+) -> u128 {
+    let balance = recover_data(test_env.call_contract(fee_token, "balance_of", &[account.into_()]));
+    assert_eq!(balance[1], Felt::ZERO);
+
+    u128::try_from(balance[0]).unwrap()
+}
+
+#[test]
+fn invoke_transaction_charges_fee() {
+    let mut test_env = TestEnvironment::new();
+    let contracts_data = get_contracts();
+
+    // Class of the fee token from the testing state is not declared, so it cannot be called
+    let fee_token = ContractAddress::try_from_hex_str(ERC20_CONTRACT_ADDRESS).unwrap();
+    let fee_token_class_hash = test_env.declare("FeeToken", &contracts_data);
+    test_env
+        .cached_state
+        .set_class_hash_at(fee_token, fee_token_class_hash)
+        .unwrap();
+
+    let account = test_env.deploy("Account", &[]);
+    test_env
+        .cached_state
+        .set_storage_at(
+            fee_token,
+            StorageKey::try_from(map_entry_address("ERC20_balances", &[account.into_()])).unwrap(),
+            Felt::from(INITIAL_BALANCE),
+        )
+        .unwrap();
+
+    let gas_prices = test_env
+        .cheatnet_state
+        .block_info
+        .gas_prices
+        .strk_gas_prices
+        .clone();
+    let params = TransactionParams {
+        nonce: None,
+        signer: TransactionSigner::Signature(vec![]),
+        l1_gas: ResourceBound {
+            max_amount: 10_000,
+            max_price_per_unit: gas_prices.l1_gas_price.get().0,
+        },
+        l2_gas: ResourceBound {
+            max_amount: 100_000_000,
+            max_price_per_unit: gas_prices.l2_gas_price.get().0,
+        },
+        l1_data_gas: ResourceBound {
+            max_amount: 10_000,
+            max_price_per_unit: gas_prices.l1_data_gas_price.get().0,
+        },
+        tip: 0,
+    };
+    let block_context = build_context(&test_env.cheatnet_state.block_info, None)
+        .tx_context
+        .block_context
+        .clone();
+
+    let result = test_env
+        .cheatnet_state
+        .invoke_transaction(
+            &mut test_env.cached_state,
+            &block_context,
+            account,
+            vec![Felt::ZERO],
+            &params,
+        )
+        .unwrap();
+    let TransactionResult::Accepted(receipt) = result else {
+        panic!("Transaction was rejected: {result:?}");
+    };
+
+    assert!(receipt.revert_reason.is_none());
+    assert!(receipt.actual_fee > 0);
+    assert_eq!(
+        receipt.actual_fee,
+        u128::from(receipt.gas_consumed.l1_gas) * gas_prices.l1_gas_price.get().0
+            + u128::from(receipt.gas_consumed.l1_data_gas) * gas_prices.l1_data_gas_price.get().0
+            + u128::from(receipt.gas_consumed.l2_gas) * gas_prices.l2_gas_price.get().0
+    );
+
+    let sequencer_address = test_env.cheatnet_state.block_info.sequencer_address;
+    assert_eq!(
+        balance_of(&mut test_env, &fee_token, account),
+        INITIAL_BALANCE - receipt.actual_fee
+    );
+    assert_eq!(
+        balance_of(&mut test_env, &fee_token, sequencer_address),
+        receipt.actual_fee
+    );
+}
@@ -0,0 +1,2 @@
+mod account;
+mod fee_token;
@@ -0,0 +1,21 @@
+// Account accepting any transaction, its `__execute__` does not make any calls
+#[starknet::contract(account)]
+mod Account {
+    #[storage]
+    struct Storage {}
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calldata: Array<felt252>) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        starknet::VALIDATED
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, calldata: Array<felt252>) -> Array<felt252> {
+        calldata
+    }
+}
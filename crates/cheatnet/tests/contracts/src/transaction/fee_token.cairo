@@ -0,0 +1,26 @@
+// Fee token storing balances under the name read by blockifier before charging the fee
+#[starknet::contract]
+mod FeeToken {
+    use starknet::{
+        ContractAddress, get_caller_address,
+        storage::{StorageMapReadAccess, StorageMapWriteAccess, Map}
+    };
+
+    #[storage]
+    struct Storage {
+        ERC20_balances: Map<ContractAddress, u256>,
+    }
+
+    #[external(v0)]
+    fn balance_of(self: @ContractState, account: ContractAddress) -> u256 {
+        self.ERC20_balances.read(account)
+    }
+
+    #[external(v0)]
+    fn transfer(ref self: ContractState, recipient: ContractAddress, amount: u256) -> bool {
+        let sender = get_caller_address();
+        self.ERC20_balances.write(sender, self.ERC20_balances.read(sender) - amount);
+        self.ERC20_balances.write(recipient, self.ERC20_balances.read(recipient) + amount);
+        true
+    }
+}
@@ -3,10 +3,11 @@ use crate::common::cache::{purge_cache, read_cache};
 use crate::common::state::{create_fork_cached_state, create_fork_cached_state_at};
 use crate::common::{call_contract, deploy_contract, deploy_wrapper, felt_selector_from_name};
 use blockifier::state::cached_state::CachedState;
+use blockifier::state::state_api::StateReader;
 use cairo_vm::vm::errors::hint_errors::HintError;
 use camino::Utf8Path;
 use cheatnet::constants::build_testing_state;
-use cheatnet::forking::cache::cache_version;
+use cheatnet::forking::cache::{cache_version, export_fork_caches, import_fork_caches};
 use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::CheatcodeError;
 use cheatnet::state::{BlockInfoReader, CheatnetState, ExtendedStateReader};
@@ -16,8 +17,11 @@ use conversions::string::TryFromHexStr;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use runtime::EnhancedHintError;
 use serde_json::Value;
+use shared::test_utils::node_url::node_rpc_url;
+use starknet::core::types::TransactionTrace;
 use starknet_api::block::BlockNumber;
 use starknet_api::core::ContractAddress;
+use starknet_api::state::StorageKey;
 use starknet_types_core::felt::Felt;
 use tempfile::TempDir;
 
@@ -635,6 +639,159 @@ fn test_cached_block_info_merging() {
     assert_cached_block_info(true);
 }
 
+#[test]
+fn offline_fork_without_cache() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let error = ForkStateReader::new_offline(
+        node_rpc_url(),
+        BlockNumber(54_060),
+        Utf8Path::from_path(temp_dir.path()).unwrap(),
+    )
+    .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("it cannot be fetched in offline mode")
+    );
+}
+
+#[test]
+fn fork_cache_without_block_hashes() {
+    let temp_dir = TempDir::new().unwrap();
+    let cache_dir = Utf8Path::from_path(temp_dir.path()).unwrap();
+
+    // Dropping the reader saves an empty cache
+    drop(ForkStateReader::new(node_rpc_url(), BlockNumber(54_060), cache_dir).unwrap());
+
+    let cache_file = glob::glob(
+        cache_dir
+            .join(format!("*v{}.json", cache_version()))
+            .as_str(),
+    )
+    .unwrap()
+    .next()
+    .unwrap()
+    .unwrap();
+    let mut cache = read_cache(cache_file.to_str().unwrap());
+    cache.remove("block_hash_at").unwrap();
+    std::fs::write(&cache_file, Value::Object(cache).to_string()).unwrap();
+
+    let fork_state_reader =
+        ForkStateReader::new_offline(node_rpc_url(), BlockNumber(54_060), cache_dir).unwrap();
+    let error = fork_state_reader.get_block_hash(54_059).unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("does not contain hash of block 54059")
+    );
+}
+
+#[test]
+fn offline_fork_uses_imported_cache() {
+    let online_cache_dir = TempDir::new().unwrap();
+    let offline_cache_dir = TempDir::new().unwrap();
+    let bundle_dir = TempDir::new().unwrap();
+    let bundle = Utf8Path::from_path(bundle_dir.path())
+        .unwrap()
+        .join("fork_cache.json");
+
+    let contract_address = ContractAddress::try_from_hex_str(
+        "0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9",
+    )
+    .unwrap();
+    let selector = felt_selector_from_name("get_balance");
+
+    {
+        let mut cached_state = create_fork_cached_state(online_cache_dir.path().to_str().unwrap());
+        let output = call_contract(
+            &mut cached_state,
+            &mut CheatnetState::default(),
+            &contract_address,
+            selector,
+            &[],
+        );
+        assert_success(output, &[Felt::from(0)]);
+    }
+
+    let exported = export_fork_caches(
+        Utf8Path::from_path(online_cache_dir.path()).unwrap(),
+        &bundle,
+    )
+    .unwrap();
+    let imported = import_fork_caches(
+        Utf8Path::from_path(offline_cache_dir.path()).unwrap(),
+        &bundle,
+    )
+    .unwrap();
+    assert_eq!(exported, 1);
+    assert_eq!(imported, 1);
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(
+            ForkStateReader::new_offline(
+                node_rpc_url(),
+                BlockNumber(54_060),
+                Utf8Path::from_path(offline_cache_dir.path()).unwrap(),
+            )
+            .unwrap(),
+        ),
+    });
+    let mut cheatnet_state = CheatnetState::default();
+
+    let output = call_contract(
+        &mut cached_state,
+        &mut cheatnet_state,
+        &contract_address,
+        selector,
+        &[],
+    );
+    assert_success(output, &[Felt::from(0)]);
+
+    assert!(
+        cached_state
+            .state
+            .fork_state_reader
+            .as_ref()
+            .unwrap()
+            .cache_misses()
+            .is_empty()
+    );
+
+    // Missing data is read as zero and recorded, so all misses can be reported together
+    let uncached_address = ContractAddress::try_from_hex_str("0x123").unwrap();
+    let output = call_contract(
+        &mut cached_state,
+        &mut cheatnet_state,
+        &uncached_address,
+        selector,
+        &[],
+    );
+    let msg = "Contract not deployed at address: 0x123";
+    let panic_data_felts: Vec<Felt> = ByteArray::from(msg).serialize_with_magic();
+    assert_panic(output, &panic_data_felts);
+
+    cached_state
+        .get_storage_at(uncached_address, StorageKey::try_from(Felt::ONE).unwrap())
+        .unwrap();
+
+    assert_eq!(
+        cached_state
+            .state
+            .fork_state_reader
+            .as_ref()
+            .unwrap()
+            .cache_misses(),
+        vec![
+            "class hash of contract 0x123 at block 54060".to_string(),
+            "storage of contract 0x123 at key 0x1 at block 54060".to_string(),
+        ]
+    );
+}
+
 #[test]
 fn test_calling_nonexistent_url() {
     let temp_dir = TempDir::new().unwrap();
@@ -672,3 +829,49 @@ fn test_calling_nonexistent_url() {
         "Unable to reach the node. Check your internet connection and node url",
     );
 }
+
+#[test]
+fn fork_at_transaction_index_reads_replayed_state() {
+    let cache_dir = TempDir::new().unwrap();
+    let cache_dir = Utf8Path::from_path(cache_dir.path()).unwrap();
+    let block_number = BlockNumber(54_061);
+
+    let traces = ForkStateReader::new(node_rpc_url(), block_number, cache_dir)
+        .unwrap()
+        .block_transaction_traces()
+        .unwrap();
+    let state_diff = match &traces[0].trace_root {
+        TransactionTrace::Invoke(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::DeployAccount(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::L1Handler(trace) => trace.state_diff.as_ref(),
+        TransactionTrace::Declare(trace) => trace.state_diff.as_ref(),
+    }
+    .unwrap();
+    let storage_diff = &state_diff.storage_diffs[0];
+    let storage_entry = &storage_diff.storage_entries[0];
+
+    let mut fork_state_reader =
+        ForkStateReader::new_at_transaction(node_rpc_url(), block_number, 1, cache_dir, false)
+            .unwrap();
+    assert_eq!(
+        fork_state_reader.get_block_info().unwrap().block_number,
+        block_number
+    );
+
+    let mut cached_state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: build_testing_state(),
+        fork_state_reader: Some(fork_state_reader),
+    });
+    let value = cached_state
+        .get_storage_at(
+            storage_diff.address.into_(),
+            StorageKey::try_from(storage_entry.key).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(value, storage_entry.value);
+
+    // Replayed transactions are a part of the forked state, not changes made by the test
+    let state_maps = cached_state.to_state_diff().unwrap().state_maps;
+    assert!(state_maps.storage.is_empty());
+    assert!(state_maps.nonces.is_empty());
+}
@@ -2,7 +2,7 @@ use super::{BufferReadError, BufferReadResult, BufferReader, CairoDeserialize};
 use crate::{IntoConv, byte_array::ByteArray};
 use num_traits::cast::ToPrimitive;
 use starknet::providers::Url;
-use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector, Nonce};
+use starknet_api::core::{ClassHash, ContractAddress, EntryPointSelector, EthAddress, Nonce};
 use starknet_types_core::felt::{Felt, NonZeroFelt};
 use std::num::NonZero;
 
@@ -72,6 +72,13 @@ impl CairoDeserialize for NonZeroFelt {
     }
 }
 
+impl CairoDeserialize for EthAddress {
+    fn deserialize(reader: &mut BufferReader<'_>) -> BufferReadResult<Self> {
+        let felt = reader.read::<Felt>()?;
+        EthAddress::try_from(felt).map_err(|_| BufferReadError::ParseFailed)
+    }
+}
+
 macro_rules! impl_deserialize_for_nonzero_num_type {
     ($type:ty) => {
         impl CairoDeserialize for NonZero<$type> {
@@ -1,7 +1,9 @@
 pub mod cairo_types;
 mod calldata;
+mod reverse_transformer;
 mod sierra_abi;
 mod transformer;
 
 pub use calldata::Calldata;
-pub use transformer::transform;
+pub use reverse_transformer::{AbiDecoder, DecodedCall, DecodedEvent};
+pub use transformer::{transform, transform_for_function};
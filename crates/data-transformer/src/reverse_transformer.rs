@@ -0,0 +1,630 @@
+use anyhow::{Context, Result, bail, ensure};
+use conversions::byte_array::ByteArray;
+use conversions::serde::deserialize::BufferReader;
+use num_bigint::BigUint;
+use serde::Serialize;
+use starknet::core::types::contract::{
+    AbiEntry, AbiEvent, AbiFunction, AbiNamedMember, EventField, EventFieldKind, TypedAbiEvent,
+    UntypedAbiEvent,
+};
+use starknet::core::types::{ContractClass, Felt};
+use starknet::core::utils::get_selector_from_name;
+use std::collections::HashSet;
+
+/// Event decoded from its keys and data, with field values represented in Cairo syntax
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedEvent {
+    /// Full path of the event type, e.g. `contract::Contract::Transfer`
+    pub name: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl DecodedEvent {
+    /// Checks whether the event is named `name`, either by a full path or just the type name
+    #[must_use]
+    pub fn is_named(&self, name: &str) -> bool {
+        is_event_named(&self.name, name)
+    }
+}
+
+/// Checks whether `full_name` of an event is `name`, either as a whole or just the type name
+fn is_event_named(full_name: &str, name: &str) -> bool {
+    full_name == name || full_name.rsplit("::").next() == Some(name)
+}
+
+/// Function call decoded from its selector, calldata and return data,
+/// with values represented in Cairo syntax
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedCall {
+    pub name: String,
+    pub arguments: Vec<(String, String)>,
+    pub outputs: Vec<String>,
+}
+
+/// Decodes function calls and events of a contract using its ABI
+pub struct AbiDecoder {
+    abi: Vec<AbiEntry>,
+}
+
+impl AbiDecoder {
+    pub fn new(class_definition: &ContractClass) -> Result<Self> {
+        let sierra_class = match class_definition {
+            ContractClass::Sierra(class) => class,
+            ContractClass::Legacy(_) => {
+                bail!("Decoding is not available for Cairo Zero contracts")
+            }
+        };
+
+        let abi: Vec<AbiEntry> = serde_json::from_str(sierra_class.abi.as_str())
+            .context("Couldn't deserialize ABI received from chain")?;
+
+        Ok(Self { abi })
+    }
+
+    #[must_use]
+    pub fn from_abi(abi: Vec<AbiEntry>) -> Self {
+        Self { abi }
+    }
+
+    /// Names of functions and L1 handlers of the contract
+    #[must_use]
+    pub fn function_names(&self) -> Vec<&str> {
+        self.functions()
+            .into_iter()
+            .map(|function| function.name.as_str())
+            .collect()
+    }
+
+    /// Serialized default values of outputs of the function: zeros for numeric types,
+    /// empty arrays and strings, `Option::None` and first variants of other enums
+    pub fn default_outputs(&self, function_name: &str) -> Result<Vec<Felt>> {
+        let function = self
+            .functions()
+            .into_iter()
+            .find(|function| function.name == function_name)
+            .with_context(|| {
+                format!(r#"Function "{function_name}" not found in ABI of the contract"#)
+            })?;
+
+        let mut output = vec![];
+        for value_type in &function.outputs {
+            self.default_value(&value_type.r#type, &mut output)?;
+        }
+        Ok(output)
+    }
+
+    /// Decodes a call to the function with `selector`, `ret_data` is decoded only if provided
+    pub fn decode_call(
+        &self,
+        selector: &Felt,
+        calldata: &[Felt],
+        ret_data: Option<&[Felt]>,
+    ) -> Result<DecodedCall> {
+        let function = self
+            .functions()
+            .into_iter()
+            .find(|function| {
+                get_selector_from_name(&function.name).is_ok_and(|name| name == *selector)
+            })
+            .with_context(|| {
+                format!("Function with selector {selector:#x} not found in ABI of the contract")
+            })?;
+
+        let mut reader = BufferReader::new(calldata);
+        let arguments = self.decode_members(&function.inputs, &mut reader)?;
+
+        let outputs = match ret_data {
+            Some(ret_data) => {
+                let mut reader = BufferReader::new(ret_data);
+                function
+                    .outputs
+                    .iter()
+                    .map(|output| self.decode_value(&output.r#type, &mut reader))
+                    .collect::<Result<_>>()?
+            }
+            None => vec![],
+        };
+
+        Ok(DecodedCall {
+            name: function.name.clone(),
+            arguments,
+            outputs,
+        })
+    }
+
+    pub fn decode_event(&self, keys: &[Felt], data: &[Felt]) -> Result<DecodedEvent> {
+        let selector = keys.first().context("Event has no keys")?;
+
+        for root in self.root_event_enums() {
+            if let Some(event) = self.decode_enum_variant(root, keys, data)? {
+                return Ok(event);
+            }
+        }
+
+        for event in self.untyped_events() {
+            if get_selector_from_name(&event.name)? == *selector {
+                let mut reader = BufferReader::new(data);
+                return Ok(DecodedEvent {
+                    name: event.name.clone(),
+                    fields: self.decode_members(&event.inputs, &mut reader)?,
+                });
+            }
+        }
+
+        bail!("Event with selector {selector:#x} not found in ABI of the contract")
+    }
+
+    /// Formats `value` serialized with `Serde` as a Cairo expression of the type of the field of
+    /// the event named `event_name`, so it can be compared with fields returned by `decode_event`
+    pub fn decode_event_field(
+        &self,
+        event_name: &str,
+        field_name: &str,
+        value: &[Felt],
+    ) -> Result<String> {
+        let field_type = self.event_field_type(event_name, field_name)?;
+
+        let mut reader = BufferReader::new(value);
+        let decoded = self.decode_value(field_type, &mut reader)?;
+        ensure!(
+            reader.read_felt().is_err(),
+            r#"Value of field "{field_name}" is too long for type "{field_type}""#
+        );
+
+        Ok(decoded)
+    }
+
+    fn event_field_type(&self, event_name: &str, field_name: &str) -> Result<&str> {
+        let typed_fields = self.typed_events().filter_map(|event| match event {
+            TypedAbiEvent::Struct(event_struct)
+                if is_event_named(&event_struct.name, event_name) =>
+            {
+                Some(
+                    event_struct
+                        .members
+                        .iter()
+                        .map(|member| (member.name.as_str(), member.r#type.as_str()))
+                        .collect::<Vec<_>>(),
+                )
+            }
+            _ => None,
+        });
+        let untyped_fields = self
+            .untyped_events()
+            .filter(|event| is_event_named(&event.name, event_name))
+            .map(|event| {
+                event
+                    .inputs
+                    .iter()
+                    .map(|input| (input.name.as_str(), input.r#type.as_str()))
+                    .collect::<Vec<_>>()
+            });
+
+        let mut found_event = false;
+        for fields in typed_fields.chain(untyped_fields) {
+            found_event = true;
+            if let Some((_, field_type)) = fields.into_iter().find(|(name, _)| *name == field_name)
+            {
+                return Ok(field_type);
+            }
+        }
+
+        if found_event {
+            bail!(r#"Field "{field_name}" not found in event "{event_name}""#)
+        }
+        bail!(r#"Event "{event_name}" not found in ABI of the contract"#)
+    }
+
+    /// Event enums which are not variants of other event enums, i.e. the `Event` enum of the contract
+    fn root_event_enums(&self) -> Vec<&[EventField]> {
+        let nested_types: HashSet<&str> = self
+            .typed_events()
+            .filter_map(|event| match event {
+                TypedAbiEvent::Enum(event_enum) => Some(&event_enum.variants),
+                TypedAbiEvent::Struct(_) => None,
+            })
+            .flatten()
+            .map(|variant| variant.r#type.as_str())
+            .collect();
+
+        self.typed_events()
+            .filter_map(|event| match event {
+                TypedAbiEvent::Enum(event_enum)
+                    if !nested_types.contains(event_enum.name.as_str()) =>
+                {
+                    Some(event_enum.variants.as_slice())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn decode_enum_variant(
+        &self,
+        variants: &[EventField],
+        keys: &[Felt],
+        data: &[Felt],
+    ) -> Result<Option<DecodedEvent>> {
+        for variant in variants {
+            let remaining_keys = match variant.kind {
+                EventFieldKind::Nested => {
+                    if keys.first() != Some(&get_selector_from_name(&variant.name)?) {
+                        continue;
+                    }
+                    &keys[1..]
+                }
+                EventFieldKind::Flat => keys,
+                EventFieldKind::Key | EventFieldKind::Data => {
+                    bail!(
+                        r#"Unexpected kind of event enum variant "{}". Contract ABI may be invalid"#,
+                        variant.name
+                    )
+                }
+            };
+
+            match self.find_typed_event(&variant.r#type)? {
+                TypedAbiEvent::Enum(event_enum) => {
+                    if let Some(event) =
+                        self.decode_enum_variant(&event_enum.variants, remaining_keys, data)?
+                    {
+                        return Ok(Some(event));
+                    }
+                }
+                TypedAbiEvent::Struct(event_struct) => {
+                    let mut keys_reader = BufferReader::new(remaining_keys);
+                    let mut data_reader = BufferReader::new(data);
+
+                    let fields = event_struct
+                        .members
+                        .iter()
+                        .map(|member| {
+                            let reader = match member.kind {
+                                EventFieldKind::Key => &mut keys_reader,
+                                EventFieldKind::Data => &mut data_reader,
+                                EventFieldKind::Nested | EventFieldKind::Flat => bail!(
+                                    r#"Nested events in event struct fields are not supported, found in "{}""#,
+                                    event_struct.name
+                                ),
+                            };
+                            Ok((
+                                member.name.clone(),
+                                self.decode_value(&member.r#type, reader)?,
+                            ))
+                        })
+                        .collect::<Result<_>>()?;
+
+                    return Ok(Some(DecodedEvent {
+                        name: event_struct.name.clone(),
+                        fields,
+                    }));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Functions and L1 handlers, including ones defined in interfaces
+    fn functions(&self) -> Vec<&AbiFunction> {
+        self.abi
+            .iter()
+            .flat_map(|entry| match entry {
+                AbiEntry::Interface(interface) => interface.items.iter().collect(),
+                entry => vec![entry],
+            })
+            .filter_map(|entry| match entry {
+                AbiEntry::Function(function) | AbiEntry::L1Handler(function) => Some(function),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn typed_events(&self) -> impl Iterator<Item = &TypedAbiEvent> {
+        self.abi.iter().filter_map(|entry| match entry {
+            AbiEntry::Event(AbiEvent::Typed(event)) => Some(event),
+            _ => None,
+        })
+    }
+
+    fn untyped_events(&self) -> impl Iterator<Item = &UntypedAbiEvent> {
+        self.abi.iter().filter_map(|entry| match entry {
+            AbiEntry::Event(AbiEvent::Untyped(event)) => Some(event),
+            _ => None,
+        })
+    }
+
+    fn find_typed_event(&self, name: &str) -> Result<&TypedAbiEvent> {
+        self.typed_events()
+            .find(|event| match event {
+                TypedAbiEvent::Struct(event_struct) => event_struct.name == name,
+                TypedAbiEvent::Enum(event_enum) => event_enum.name == name,
+            })
+            .with_context(|| format!(r#"Event "{name}" not found in ABI"#))
+    }
+
+    fn decode_members(
+        &self,
+        members: &[AbiNamedMember],
+        reader: &mut BufferReader<'_>,
+    ) -> Result<Vec<(String, String)>> {
+        members
+            .iter()
+            .map(|member| {
+                Ok((
+                    member.name.clone(),
+                    self.decode_value(&member.r#type, reader)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Reads a value of `value_type` from the `reader` and formats it as a Cairo expression
+    fn decode_value(&self, value_type: &str, reader: &mut BufferReader<'_>) -> Result<String> {
+        if value_type == "()" {
+            return Ok("()".to_string());
+        }
+        if let Some(inner) = value_type
+            .strip_prefix('(')
+            .and_then(|value_type| value_type.strip_suffix(')'))
+        {
+            let elements = split_type_list(inner)
+                .into_iter()
+                .map(|element_type| self.decode_value(element_type, reader))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(format!("({})", elements.join(", ")));
+        }
+
+        let (base_type, generic_args) = split_generic_args(value_type);
+
+        if matches!(base_type, "core::array::Array" | "core::array::Span") {
+            let element_type = generic_args.with_context(|| {
+                format!(r#"Missing element type of "{value_type}". Contract ABI may be invalid"#)
+            })?;
+            let length = read_u128(reader, value_type)?;
+            let elements = (0..length)
+                .map(|_| self.decode_value(element_type, reader))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(format!("array![{}]", elements.join(", ")));
+        }
+
+        if let Some(value) = decode_primitive(base_type, reader)? {
+            return Ok(value);
+        }
+
+        for entry in &self.abi {
+            match entry {
+                AbiEntry::Struct(abi_struct) if abi_struct.name == value_type => {
+                    let fields = self
+                        .decode_members(&abi_struct.members, reader)?
+                        .into_iter()
+                        .map(|(name, value)| format!("{name}: {value}"))
+                        .collect::<Vec<_>>();
+                    return Ok(format!(
+                        "{} {{ {} }}",
+                        short_name(base_type),
+                        fields.join(", ")
+                    ));
+                }
+                AbiEntry::Enum(abi_enum) if abi_enum.name == value_type => {
+                    let position = read_u128(reader, value_type)?;
+                    let variant = usize::try_from(position)
+                        .ok()
+                        .and_then(|position| abi_enum.variants.get(position))
+                        .with_context(|| {
+                            format!(r#"Invalid variant {position} of enum "{value_type}""#)
+                        })?;
+                    let enum_name = short_name(base_type);
+
+                    return if variant.r#type == "()" {
+                        Ok(format!("{enum_name}::{}", variant.name))
+                    } else {
+                        let value = self.decode_value(&variant.r#type, reader)?;
+                        Ok(format!("{enum_name}::{}({value})", variant.name))
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        bail!(r#"Decoding of type "{value_type}" is not supported"#)
+    }
+
+    /// Appends serialized default value of `value_type` to the `output`
+    fn default_value(&self, value_type: &str, output: &mut Vec<Felt>) -> Result<()> {
+        if value_type == "()" {
+            return Ok(());
+        }
+        if let Some(inner) = value_type
+            .strip_prefix('(')
+            .and_then(|value_type| value_type.strip_suffix(')'))
+        {
+            for element_type in split_type_list(inner) {
+                self.default_value(element_type, output)?;
+            }
+            return Ok(());
+        }
+
+        let (base_type, _) = split_generic_args(value_type);
+
+        match base_type {
+            // Empty array
+            "core::array::Array" | "core::array::Span" => {
+                output.push(Felt::ZERO);
+                return Ok(());
+            }
+            "core::option::Option" => {
+                output.push(Felt::ONE);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let zeros_count = match short_name(base_type) {
+            "felt252" | "felt" | "ContractAddress" | "ClassHash" | "StorageAddress"
+            | "EthAddress" | "bytes31" | "bool" | "u8" | "u16" | "u32" | "u64" | "u96" | "u128"
+            | "usize" | "i8" | "i16" | "i32" | "i64" | "i128" => Some(1),
+            "u256" => Some(2),
+            "u384" | "u512" => Some(4),
+            // Empty data array, pending word and its length
+            "ByteArray" => Some(3),
+            _ => None,
+        };
+        if let Some(zeros_count) = zeros_count {
+            output.extend(std::iter::repeat_n(Felt::ZERO, zeros_count));
+            return Ok(());
+        }
+
+        for entry in &self.abi {
+            match entry {
+                AbiEntry::Struct(abi_struct) if abi_struct.name == value_type => {
+                    for member in &abi_struct.members {
+                        self.default_value(&member.r#type, output)?;
+                    }
+                    return Ok(());
+                }
+                AbiEntry::Enum(abi_enum) if abi_enum.name == value_type => {
+                    let variant = abi_enum
+                        .variants
+                        .first()
+                        .with_context(|| format!(r#"Enum "{value_type}" has no variants"#))?;
+                    output.push(Felt::ZERO);
+                    return self.default_value(&variant.r#type, output);
+                }
+                _ => {}
+            }
+        }
+
+        bail!(r#"Default value of type "{value_type}" is not supported"#)
+    }
+}
+
+fn decode_primitive(base_type: &str, reader: &mut BufferReader<'_>) -> Result<Option<String>> {
+    let value = match short_name(base_type) {
+        "felt252" | "felt" | "ContractAddress" | "ClassHash" | "StorageAddress" | "EthAddress"
+        | "bytes31" => format!("{:#x}", read_felt(reader, base_type)?),
+        "bool" => {
+            let value = read_felt(reader, base_type)?;
+            ensure!(
+                value == Felt::ZERO || value == Felt::ONE,
+                r#"Invalid value {value:#x} of type "bool""#
+            );
+            (value == Felt::ONE).to_string()
+        }
+        "u8" | "u16" | "u32" | "u64" | "u96" | "u128" | "usize" => {
+            read_felt(reader, base_type)?.to_biguint().to_string()
+        }
+        "i8" | "i16" | "i32" | "i64" | "i128" => {
+            let value = read_felt(reader, base_type)?;
+            let negated = -value;
+            if negated.to_biguint() < value.to_biguint() {
+                format!("-{}", negated.to_biguint())
+            } else {
+                value.to_biguint().to_string()
+            }
+        }
+        "u256" => read_limbs(reader, base_type, 2, 128)?.to_string(),
+        "u384" => read_limbs(reader, base_type, 4, 96)?.to_string(),
+        "u512" => read_limbs(reader, base_type, 4, 128)?.to_string(),
+        "ByteArray" => {
+            let byte_array: ByteArray = reader
+                .read()
+                .with_context(|| format!(r#"Failed to read value of type "{base_type}""#))?;
+            format!("{:?}", byte_array.to_string())
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(value))
+}
+
+fn read_felt(reader: &mut BufferReader<'_>, value_type: &str) -> Result<Felt> {
+    reader
+        .read_felt()
+        .with_context(|| format!(r#"Not enough felts to read value of type "{value_type}""#))
+}
+
+fn read_u128(reader: &mut BufferReader<'_>, value_type: &str) -> Result<u128> {
+    let value = read_felt(reader, value_type)?;
+    u128::try_from(value).with_context(|| format!(r#"Invalid length or index in "{value_type}""#))
+}
+
+/// Reads a number serialized as `limbs_count` little-endian limbs, each `limb_bits` wide
+fn read_limbs(
+    reader: &mut BufferReader<'_>,
+    value_type: &str,
+    limbs_count: usize,
+    limb_bits: usize,
+) -> Result<BigUint> {
+    (0..limbs_count).try_fold(BigUint::ZERO, |result, limb_index| {
+        Ok(result + (read_felt(reader, value_type)?.to_biguint() << (limb_index * limb_bits)))
+    })
+}
+
+/// Splits `core::array::Array::<core::felt252>` into `core::array::Array` and `core::felt252`
+fn split_generic_args(value_type: &str) -> (&str, Option<&str>) {
+    match value_type.split_once("::<") {
+        Some((base_type, generic_args)) => (base_type, generic_args.strip_suffix('>')),
+        None => (value_type, None),
+    }
+}
+
+/// Splits comma separated types, ignoring commas inside of generic arguments and tuples
+fn split_type_list(types: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, character) in types.char_indices() {
+        match character {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(types[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    let last = types[start..].trim();
+    if !last.is_empty() {
+        result.push(last);
+    }
+
+    result
+}
+
+fn short_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_generic_args, split_type_list};
+
+    #[test]
+    fn test_split_type_list() {
+        assert_eq!(
+            split_type_list(
+                "core::felt252, (core::integer::u8, core::bool), core::array::Array::<(core::felt252, core::felt252)>"
+            ),
+            vec![
+                "core::felt252",
+                "(core::integer::u8, core::bool)",
+                "core::array::Array::<(core::felt252, core::felt252)>"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_generic_args() {
+        assert_eq!(
+            split_generic_args("core::array::Span::<core::array::Array::<core::felt252>>"),
+            (
+                "core::array::Span",
+                Some("core::array::Array::<core::felt252>")
+            )
+        );
+        assert_eq!(split_generic_args("core::felt252"), ("core::felt252", None));
+    }
+}
@@ -35,11 +35,21 @@ pub fn transform(
             )
         })?;
 
+    transform_for_function(calldata, function, &abi)
+}
+
+/// Interpret `calldata` as a comma-separated series of expressions in Cairo syntax and serialize it
+/// as arguments of `function`, user defined types are looked up in `abi`
+pub fn transform_for_function(
+    calldata: &str,
+    function: &AbiFunction,
+    abi: &[AbiEntry],
+) -> Result<Vec<Felt>> {
     let db = SimpleParserDatabase::default();
 
     let calldata = split_expressions(calldata, &db)?;
 
-    process(calldata, function, &abi, &db).context("Error while processing Cairo-like calldata")
+    process(calldata, function, abi, &db).context("Error while processing Cairo-like calldata")
 }
 
 fn split_expressions(input: &str, db: &SimpleParserDatabase) -> Result<Vec<Expr>> {
@@ -0,0 +1,216 @@
+use data_transformer::{AbiDecoder, DecodedCall};
+use indoc::indoc;
+use starknet::core::types::{ContractClass, EntryPointsByType, FlattenedSierraClass};
+use starknet::core::utils::get_selector_from_name;
+use starknet_types_core::felt::Felt;
+
+const ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "struct",
+        "name": "core::integer::u256",
+        "members": [
+          { "name": "low", "type": "core::integer::u128" },
+          { "name": "high", "type": "core::integer::u128" }
+        ]
+      },
+      {
+        "type": "interface",
+        "name": "calls::IToken",
+        "items": [
+          {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+              { "name": "recipient", "type": "core::starknet::contract_address::ContractAddress" },
+              { "name": "amount", "type": "core::integer::u256" }
+            ],
+            "outputs": [{ "type": "core::bool" }],
+            "state_mutability": "external"
+          }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "get_values",
+        "inputs": [],
+        "outputs": [{ "type": "core::array::Span::<core::felt252>" }],
+        "state_mutability": "view"
+      },
+      {
+        "type": "enum",
+        "name": "calls::Status",
+        "variants": [
+          { "name": "Active", "type": "core::integer::u64" },
+          { "name": "Paused", "type": "()" }
+        ]
+      },
+      {
+        "type": "function",
+        "name": "get_state",
+        "inputs": [],
+        "outputs": [
+          {
+            "type": "(core::integer::u256, core::option::Option::<core::felt252>, core::byte_array::ByteArray, calls::Status)"
+          }
+        ],
+        "state_mutability": "view"
+      },
+      {
+        "type": "l1_handler",
+        "name": "deposit",
+        "inputs": [
+          { "name": "from_address", "type": "core::felt252" },
+          { "name": "amount", "type": "core::integer::u64" }
+        ],
+        "outputs": [],
+        "state_mutability": "external"
+      }
+    ]
+"#};
+
+fn decoder() -> AbiDecoder {
+    let class = ContractClass::Sierra(FlattenedSierraClass {
+        sierra_program: vec![],
+        contract_class_version: "0.1.0".to_string(),
+        entry_points_by_type: EntryPointsByType {
+            constructor: vec![],
+            external: vec![],
+            l1_handler: vec![],
+        },
+        abi: ABI.to_string(),
+    });
+
+    AbiDecoder::new(&class).unwrap()
+}
+
+fn selector(name: &str) -> Felt {
+    get_selector_from_name(name).unwrap()
+}
+
+#[test]
+fn test_decode_interface_function() {
+    let call = decoder()
+        .decode_call(
+            &selector("transfer"),
+            &[Felt::from(0x123), Felt::from(100), Felt::ZERO],
+            Some(&[Felt::ONE]),
+        )
+        .unwrap();
+
+    assert_eq!(
+        call,
+        DecodedCall {
+            name: "transfer".to_string(),
+            arguments: vec![
+                ("recipient".to_string(), "0x123".to_string()),
+                ("amount".to_string(), "100".to_string()),
+            ],
+            outputs: vec!["true".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_decode_without_ret_data() {
+    let call = decoder()
+        .decode_call(&selector("get_values"), &[], None)
+        .unwrap();
+
+    assert_eq!(call.name, "get_values");
+    assert!(call.arguments.is_empty());
+    assert!(call.outputs.is_empty());
+}
+
+#[test]
+fn test_decode_outputs() {
+    let call = decoder()
+        .decode_call(
+            &selector("get_values"),
+            &[],
+            Some(&[Felt::TWO, Felt::from(0xa), Felt::from(0xb)]),
+        )
+        .unwrap();
+
+    assert_eq!(call.outputs, vec!["array![0xa, 0xb]".to_string()]);
+}
+
+#[test]
+fn test_decode_l1_handler() {
+    let call = decoder()
+        .decode_call(
+            &selector("deposit"),
+            &[Felt::from(0xabc), Felt::from(5)],
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(call.name, "deposit");
+    assert_eq!(
+        call.arguments,
+        vec![
+            ("from_address".to_string(), "0xabc".to_string()),
+            ("amount".to_string(), "5".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_decode_unknown_function() {
+    let error = decoder()
+        .decode_call(&selector("unknown"), &[], None)
+        .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("not found in ABI of the contract")
+    );
+}
+
+#[test]
+fn test_default_outputs() {
+    let decoder = decoder();
+
+    assert_eq!(
+        decoder.function_names(),
+        vec!["transfer", "get_values", "get_state", "deposit"]
+    );
+    assert_eq!(
+        decoder.default_outputs("transfer").unwrap(),
+        vec![Felt::ZERO]
+    );
+    assert_eq!(
+        decoder.default_outputs("get_values").unwrap(),
+        vec![Felt::ZERO]
+    );
+    assert_eq!(
+        decoder.default_outputs("get_state").unwrap(),
+        vec![
+            // u256
+            Felt::ZERO,
+            Felt::ZERO,
+            // Option::None
+            Felt::ONE,
+            // ByteArray
+            Felt::ZERO,
+            Felt::ZERO,
+            Felt::ZERO,
+            // Status::Active(0)
+            Felt::ZERO,
+            Felt::ZERO,
+        ]
+    );
+    assert!(decoder.default_outputs("deposit").unwrap().is_empty());
+}
+
+#[test]
+fn test_default_outputs_unknown_function() {
+    let error = decoder().default_outputs("unknown").unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("not found in ABI of the contract")
+    );
+}
@@ -0,0 +1,235 @@
+use conversions::byte_array::ByteArray;
+use conversions::serde::serialize::SerializeToFeltVec;
+use data_transformer::{AbiDecoder, DecodedEvent};
+use indoc::indoc;
+use starknet::core::types::{ContractClass, EntryPointsByType, FlattenedSierraClass};
+use starknet::core::utils::get_selector_from_name;
+use starknet_types_core::felt::Felt;
+
+const ABI: &str = indoc! {r#"
+    [
+      {
+        "type": "struct",
+        "name": "core::integer::u256",
+        "members": [
+          { "name": "low", "type": "core::integer::u128" },
+          { "name": "high", "type": "core::integer::u128" }
+        ]
+      },
+      {
+        "type": "enum",
+        "name": "core::option::Option::<core::integer::u8>",
+        "variants": [
+          { "name": "Some", "type": "core::integer::u8" },
+          { "name": "None", "type": "()" }
+        ]
+      },
+      {
+        "type": "struct",
+        "name": "events::Point",
+        "members": [
+          { "name": "x", "type": "core::integer::i32" },
+          { "name": "y", "type": "core::integer::i32" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "events::Contract::Transfer",
+        "kind": "struct",
+        "members": [
+          { "name": "from", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+          { "name": "to", "type": "core::starknet::contract_address::ContractAddress", "kind": "key" },
+          { "name": "amount", "type": "core::integer::u256", "kind": "data" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "events::Contract::Message",
+        "kind": "struct",
+        "members": [
+          { "name": "text", "type": "core::byte_array::ByteArray", "kind": "data" },
+          { "name": "points", "type": "core::array::Array::<events::Point>", "kind": "data" },
+          { "name": "flag", "type": "core::option::Option::<core::integer::u8>", "kind": "data" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "events::component::Paused",
+        "kind": "struct",
+        "members": [
+          { "name": "account", "type": "core::starknet::contract_address::ContractAddress", "kind": "data" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "events::component::Event",
+        "kind": "enum",
+        "variants": [
+          { "name": "Paused", "type": "events::component::Paused", "kind": "nested" }
+        ]
+      },
+      {
+        "type": "event",
+        "name": "events::Contract::Event",
+        "kind": "enum",
+        "variants": [
+          { "name": "Transfer", "type": "events::Contract::Transfer", "kind": "nested" },
+          { "name": "Message", "type": "events::Contract::Message", "kind": "nested" },
+          { "name": "ComponentEvent", "type": "events::component::Event", "kind": "flat" }
+        ]
+      }
+    ]
+"#};
+
+fn decoder() -> AbiDecoder {
+    let class = ContractClass::Sierra(FlattenedSierraClass {
+        sierra_program: vec![],
+        contract_class_version: "0.1.0".to_string(),
+        entry_points_by_type: EntryPointsByType {
+            constructor: vec![],
+            external: vec![],
+            l1_handler: vec![],
+        },
+        abi: ABI.to_string(),
+    });
+
+    AbiDecoder::new(&class).unwrap()
+}
+
+fn selector(name: &str) -> Felt {
+    get_selector_from_name(name).unwrap()
+}
+
+#[test]
+fn test_decode_keys_and_data() {
+    let event = decoder()
+        .decode_event(
+            &[selector("Transfer"), Felt::from(0x123), Felt::from(0x456)],
+            &[Felt::from(10), Felt::ONE],
+        )
+        .unwrap();
+
+    assert_eq!(
+        event,
+        DecodedEvent {
+            name: "events::Contract::Transfer".to_string(),
+            fields: vec![
+                ("from".to_string(), "0x123".to_string()),
+                ("to".to_string(), "0x456".to_string()),
+                (
+                    "amount".to_string(),
+                    "340282366920938463463374607431768211466".to_string()
+                ),
+            ],
+        }
+    );
+    assert!(event.is_named("Transfer"));
+    assert!(event.is_named("events::Contract::Transfer"));
+    assert!(!event.is_named("Message"));
+}
+
+#[test]
+fn test_decode_complex_types() {
+    let mut data = ByteArray::from("hello").serialize_to_vec();
+    data.extend([
+        Felt::TWO,
+        Felt::ONE,
+        -Felt::TWO,
+        Felt::from(3),
+        Felt::from(4),
+        Felt::ZERO,
+        Felt::from(7),
+    ]);
+
+    let event = decoder()
+        .decode_event(&[selector("Message")], &data)
+        .unwrap();
+
+    assert_eq!(
+        event.fields,
+        vec![
+            ("text".to_string(), r#""hello""#.to_string()),
+            (
+                "points".to_string(),
+                "array![Point { x: 1, y: -2 }, Point { x: 3, y: 4 }]".to_string()
+            ),
+            ("flag".to_string(), "Option::Some(7)".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_decode_flat_component_event() {
+    let event = decoder()
+        .decode_event(&[selector("Paused")], &[Felt::from(0xabc)])
+        .unwrap();
+
+    assert_eq!(event.name, "events::component::Paused");
+    assert_eq!(
+        event.fields,
+        vec![("account".to_string(), "0xabc".to_string())]
+    );
+}
+
+#[test]
+fn test_decode_unknown_event() {
+    let error = decoder()
+        .decode_event(&[selector("Unknown")], &[])
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("not found in ABI of the contract"));
+}
+
+#[test]
+fn test_decode_not_enough_data() {
+    let error = decoder()
+        .decode_event(&[selector("Transfer"), Felt::from(0x123)], &[])
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains(
+        r#"Not enough felts to read value of type "core::starknet::contract_address::ContractAddress""#
+    ));
+}
+
+#[test]
+fn test_decode_event_field() {
+    let decoder = decoder();
+
+    assert_eq!(
+        decoder
+            .decode_event_field("Transfer", "amount", &[Felt::from(10), Felt::ONE])
+            .unwrap(),
+        "340282366920938463463374607431768211466"
+    );
+    assert_eq!(
+        decoder
+            .decode_event_field("events::component::Paused", "account", &[Felt::from(0xabc)])
+            .unwrap(),
+        "0xabc"
+    );
+}
+
+#[test]
+fn test_decode_event_field_invalid() {
+    let decoder = decoder();
+
+    let error = decoder
+        .decode_event_field("Transfer", "value", &[Felt::from(10)])
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains(r#"Field "value" not found in event "Transfer""#));
+
+    let error = decoder
+        .decode_event_field("Unknown", "value", &[Felt::from(10)])
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains(r#"Event "Unknown" not found in ABI of the contract"#));
+
+    let error = decoder
+        .decode_event_field("Transfer", "from", &[Felt::from(10), Felt::ONE])
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains(r#"Value of field "from" is too long"#));
+}
@@ -0,0 +1,64 @@
+use conversions::byte_array::ByteArray;
+use conversions::serde::serialize::SerializeToFeltVec;
+use data_transformer::transform_for_function;
+use starknet::core::types::contract::{AbiFunction, AbiNamedMember, StateMutability};
+use starknet_types_core::felt::Felt;
+
+fn function(types: &[&str]) -> AbiFunction {
+    AbiFunction {
+        name: "main".to_string(),
+        inputs: types
+            .iter()
+            .enumerate()
+            .map(|(index, r#type)| AbiNamedMember {
+                name: format!("arg{index}"),
+                r#type: (*r#type).to_string(),
+            })
+            .collect(),
+        outputs: vec![],
+        state_mutability: StateMutability::External,
+    }
+}
+
+#[test]
+fn test_transform_primitives() {
+    let function = function(&[
+        "core::felt252",
+        "core::integer::u256",
+        "core::bool",
+        "core::byte_array::ByteArray",
+    ]);
+
+    let result = transform_for_function(r#"0x1, 2, true, "hello""#, &function, &[]).unwrap();
+
+    let mut expected = vec![Felt::ONE, Felt::TWO, Felt::ZERO, Felt::ONE];
+    expected.extend(ByteArray::from("hello").serialize_to_vec());
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_transform_arrays() {
+    let function = function(&[
+        "core::array::Array::<core::starknet::contract_address::ContractAddress>",
+        "core::array::Array::<core::integer::u8>",
+    ]);
+
+    let result = transform_for_function("array![0x12, 0x34], array![]", &function, &[]).unwrap();
+
+    assert_eq!(
+        result,
+        vec![Felt::TWO, Felt::from(0x12), Felt::from(0x34), Felt::ZERO]
+    );
+}
+
+#[test]
+fn test_transform_invalid_number_of_arguments() {
+    let function = function(&["core::felt252"]);
+
+    let error = transform_for_function("1, 2", &function, &[]).unwrap_err();
+
+    assert!(
+        format!("{error:#}").contains("Invalid number of arguments: passed 2, expected 1"),
+        "{error:#}"
+    );
+}
@@ -37,19 +37,25 @@ pub fn add_backtrace_footer(
         );
     }
 
-    BacktraceContractRepository::new(contracts_data, encountered_errors)
-        .map(|repository| {
-            encountered_errors
-                .iter()
-                .filter_map(|error| repository.get_backtrace(error.pc, error.class_hash))
-                .map(|backtrace| backtrace.to_string())
-                .collect::<Vec<_>>()
-                .join("\n")
-        })
-        .map_or_else(
-            |err| format!("{message}\nfailed to create backtrace: {err}"),
-            |backtraces| format!("{message}\n{backtraces}"),
-        )
+    get_backtrace(contracts_data, encountered_errors).map_or_else(
+        |err| format!("{message}\nfailed to create backtrace: {err}"),
+        |backtraces| format!("{message}\n{backtraces}"),
+    )
+}
+
+/// Builds backtraces of `encountered_errors` from debug info of contracts in `contracts_data`
+pub fn get_backtrace(
+    contracts_data: &ContractsData,
+    encountered_errors: &[EncounteredError],
+) -> Result<String> {
+    BacktraceContractRepository::new(contracts_data, encountered_errors).map(|repository| {
+        encountered_errors
+            .iter()
+            .filter_map(|error| repository.get_backtrace(error.pc, error.class_hash))
+            .map(|backtrace| backtrace.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
 }
 
 struct ContractBacktraceData {
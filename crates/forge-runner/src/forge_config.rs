@@ -19,6 +19,7 @@ pub struct TestRunnerConfig {
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: Utf8PathBuf,
+    pub offline: bool,
     pub contracts_data: ContractsData,
     pub environment_variables: HashMap<String, String>,
 }
@@ -64,6 +65,7 @@ pub struct RuntimeConfig<'a> {
     pub max_n_steps: Option<u32>,
     pub is_vm_trace_needed: bool,
     pub cache_dir: &'a Utf8PathBuf,
+    pub offline: bool,
     pub contracts_data: &'a ContractsData,
     pub environment_variables: &'a HashMap<String, String>,
 }
@@ -75,6 +77,7 @@ impl<'a> RuntimeConfig<'a> {
             max_n_steps: value.max_n_steps,
             is_vm_trace_needed: value.is_vm_trace_needed,
             cache_dir: &value.cache_dir,
+            offline: value.offline,
             contracts_data: &value.contracts_data,
             environment_variables: &value.environment_variables,
         }
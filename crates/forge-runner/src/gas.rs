@@ -8,18 +8,18 @@ use blockifier::fee::resources::{
 };
 use blockifier::state::cached_state::CachedState;
 use blockifier::state::errors::StateError;
+use blockifier::state::state_api::StateReader;
 use blockifier::transaction::objects::HasRelatedFeeType;
 use blockifier::utils::u64_from_usize;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
-use cheatnet::state::ExtendedStateReader;
 use starknet_api::execution_resources::{GasAmount, GasVector};
 use starknet_api::transaction::EventContent;
 use starknet_api::transaction::fields::GasVectorComputationMode;
 use std::collections::HashSet;
 
-pub fn calculate_used_gas(
+pub fn calculate_used_gas<S: StateReader>(
     transaction_context: &TransactionContext,
-    state: &mut CachedState<ExtendedStateReader>,
+    state: &mut CachedState<S>,
     resources: UsedResources,
 ) -> Result<GasVector, StateError> {
     let versioned_constants = transaction_context.block_context.versioned_constants();
@@ -130,9 +130,9 @@ fn get_messages_resources(
     }
 }
 
-fn get_state_resources(
+fn get_state_resources<S: StateReader>(
     transaction_context: &TransactionContext,
-    state: &mut CachedState<ExtendedStateReader>,
+    state: &mut CachedState<S>,
 ) -> Result<StateResources, StateError> {
     let mut state_changes = state.get_actual_state_changes()?;
     // compiled_class_hash_updates is used only for keeping track of declares
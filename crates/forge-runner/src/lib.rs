@@ -32,8 +32,8 @@ pub mod profiler_api;
 pub mod test_case_summary;
 pub mod test_target_summary;
 
-mod backtrace;
-mod gas;
+pub mod backtrace;
+pub mod gas;
 pub mod printing;
 pub mod running;
 
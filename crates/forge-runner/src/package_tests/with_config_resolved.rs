@@ -12,6 +12,8 @@ pub type TestCaseWithResolvedConfig = TestCase<TestCaseResolvedConfig>;
 pub struct ResolvedForkConfig {
     pub url: Url,
     pub block_number: BlockNumber,
+    /// Number of transactions from `block_number` replayed on top of the preceding block
+    pub transaction_index: Option<u64>,
 }
 
 /// Test case with config that has been resolved, that is
@@ -5,7 +5,6 @@ use crate::package_tests::with_config_resolved::{ResolvedForkConfig, TestCaseWit
 use crate::test_case_summary::{Single, TestCaseSummary};
 use anyhow::{Result, ensure};
 use blockifier::execution::entry_point::EntryPointExecutionContext;
-use blockifier::state::cached_state::CachedState;
 use cairo_lang_runner::{Arg, RunResult, SierraCasmRunner};
 use cairo_lang_sierra::extensions::NamedType;
 use cairo_lang_sierra::extensions::bitwise::BitwiseType;
@@ -25,10 +24,14 @@ use cairo_vm::vm::errors::vm_errors::VirtualMachineError;
 use camino::{Utf8Path, Utf8PathBuf};
 use casm::{get_assembled_program, run_assembled_program};
 use cheatnet::constants as cheatnet_constants;
+use cheatnet::forking::forks::{Fork, Forks};
+use cheatnet::forking::multi_fork_state::MultiForkState;
 use cheatnet::forking::state::ForkStateReader;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::CallToBlockifierExtension;
 use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::UsedResources;
 use cheatnet::runtime_extensions::cheatable_starknet_runtime_extension::CheatableStarknetRuntimeExtension;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::contract_state::SharedPendingNonces;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::state_diff::SharedStateChanges;
 use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
 use cheatnet::runtime_extensions::forge_runtime_extension::{
     ForgeExtension, ForgeRuntime, add_vm_execution_resources_to_top_call, get_all_used_resources,
@@ -45,7 +48,6 @@ use runtime::{ExtendedRuntime, StarknetRuntime};
 use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
 use std::default::Default;
-use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::Sender;
@@ -171,24 +173,49 @@ pub fn run_test_case(
     let string_to_hint = hints_by_representation(&assembled_program);
     let hints_dict = hints_to_params(&assembled_program);
 
+    let fork = case.config.fork_config.as_ref().map(
+        |ResolvedForkConfig {
+             url,
+             block_number,
+             transaction_index,
+         }| Fork {
+            url: url.clone(),
+            block_number: *block_number,
+            transaction_index: *transaction_index,
+        },
+    );
     let mut state_reader = ExtendedStateReader {
         dict_state_reader: cheatnet_constants::build_testing_state(),
         fork_state_reader: get_fork_state_reader(
             runtime_config.cache_dir,
-            case.config.fork_config.as_ref(),
+            runtime_config.offline,
+            fork.as_ref(),
         )?,
     };
     let block_info = state_reader.get_block_info()?;
     let chain_id = state_reader.get_chain_id()?;
 
+    let forks = Rc::new(RefCell::new(Forks::new(
+        runtime_config.cache_dir,
+        runtime_config.offline,
+        fork,
+    )));
+    let state_changes = SharedStateChanges::default();
+    let pending_nonces = SharedPendingNonces::default();
+    let mut state = MultiForkState::new(
+        state_reader,
+        forks.clone(),
+        state_changes.clone(),
+        pending_nonces.clone(),
+    );
+
     let mut context = build_context(&block_info, chain_id);
 
     if let Some(max_n_steps) = runtime_config.max_n_steps {
         set_max_steps(&mut context, max_n_steps);
     }
-    let mut cached_state = CachedState::new(state_reader);
     let syscall_handler = build_syscall_handler(
-        &mut cached_state,
+        &mut state,
         &string_to_hint,
         &mut context,
         &case.test_details.parameter_types,
@@ -197,6 +224,9 @@ pub fn run_test_case(
 
     let mut cheatnet_state = CheatnetState {
         block_info,
+        forks,
+        state_changes,
+        pending_nonces,
         ..Default::default()
     };
     cheatnet_state.trace_data.is_vm_trace_needed = runtime_config.is_vm_trace_needed;
@@ -218,7 +248,7 @@ pub fn run_test_case(
 
     let call_to_blockifier_runtime = ExtendedRuntime {
         extension: CallToBlockifierExtension {
-            lifetime: &PhantomData,
+            contracts_data: runtime_config.contracts_data,
         },
         extended_runtime: cheatable_runtime,
     };
@@ -296,10 +326,27 @@ pub fn run_test_case(
     let used_resources = get_all_used_resources(forge_runtime, &transaction_context);
     let gas = calculate_used_gas(
         &transaction_context,
-        &mut cached_state,
+        state.cached_state()?,
         used_resources.clone(),
     )?;
 
+    let cache_misses = state
+        .cached_state()?
+        .state
+        .fork_state_reader
+        .as_ref()
+        .map(ForkStateReader::cache_misses)
+        .unwrap_or_default();
+    ensure!(
+        cache_misses.is_empty(),
+        "Fork cache does not contain data used by the test, it cannot be fetched in offline mode:\n{}",
+        cache_misses
+            .iter()
+            .map(|missing| format!("    {missing}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+
     Ok(RunResultWithInfo {
         run_result: run_result.map(|(gas_counter, memory, value)| RunResult {
             used_resources: used_resources.execution_resources.clone(),
@@ -419,6 +466,7 @@ fn extract_test_case_summary(
             }
         }
         // `ForkStateReader.get_block_info`, `get_fork_state_reader, `calculate_used_gas` may return an error
+        // Data missing from the fork cache in offline mode is reported once the test finishes
         // `available_gas` may be specified with Scarb ~2.4
         Err(error) => TestCaseSummary::Failed {
             name: case.name.clone(),
@@ -432,13 +480,10 @@ fn extract_test_case_summary(
 
 fn get_fork_state_reader(
     cache_dir: &Utf8Path,
-    fork_config: Option<&ResolvedForkConfig>,
+    offline: bool,
+    fork: Option<&Fork>,
 ) -> Result<Option<ForkStateReader>> {
-    fork_config
-        .as_ref()
-        .map(|ResolvedForkConfig { url, block_number }| {
-            ForkStateReader::new(url.clone(), *block_number, cache_dir)
-        })
+    fork.map(|fork| fork.state_reader(cache_dir, offline))
         .transpose()
 }
 
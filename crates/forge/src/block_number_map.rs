@@ -1,4 +1,4 @@
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, ensure};
 use conversions::{IntoConv, string::IntoHexStr};
 use starknet::{
     core::types::{BlockId, MaybePendingBlockWithTxHashes},
@@ -14,13 +14,27 @@ use url::Url;
 pub struct BlockNumberMap {
     url_to_latest_block_number: HashMap<Url, BlockNumber>,
     url_and_hash_to_block_number: HashMap<(Url, Felt), BlockNumber>,
+    /// Block numbers cannot be fetched from the network
+    offline: bool,
 }
 
 impl BlockNumberMap {
+    #[must_use]
+    pub fn new(offline: bool) -> Self {
+        Self {
+            offline,
+            ..Default::default()
+        }
+    }
+
     pub async fn get_latest_block_number(&mut self, url: Url) -> Result<BlockNumber> {
         let block_number = if let Some(block_number) = self.url_to_latest_block_number.get(&url) {
             *block_number
         } else {
+            ensure!(
+                !self.offline,
+                "Latest block cannot be fetched in offline mode, fork at a block number instead"
+            );
             let latest_block_number = fetch_latest_block_number(url.clone()).await?;
 
             self.url_to_latest_block_number
@@ -38,6 +52,10 @@ impl BlockNumberMap {
         {
             *block_number
         } else {
+            ensure!(
+                !self.offline,
+                "Block with hash {hash:#x} cannot be fetched in offline mode, fork at a block number instead"
+            );
             let block_number = fetch_block_number_for_hash(url.clone(), hash).await?;
 
             self.url_and_hash_to_block_number
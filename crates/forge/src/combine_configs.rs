@@ -23,6 +23,7 @@ pub fn combine_configs(
     max_n_steps: Option<u32>,
     contracts_data: ContractsData,
     cache_dir: Utf8PathBuf,
+    offline: bool,
     forge_config_from_scarb: &ForgeConfigFromScarb,
     additional_args: &[OsString],
 ) -> ForgeConfig {
@@ -45,6 +46,7 @@ pub fn combine_configs(
             max_n_steps: max_n_steps.or(forge_config_from_scarb.max_n_steps),
             is_vm_trace_needed: execution_data_to_save.is_vm_trace_needed(),
             cache_dir,
+            offline,
             contracts_data,
             environment_variables: env::vars().collect(),
         }),
@@ -72,6 +74,7 @@ mod tests {
             None,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -86,6 +89,7 @@ mod tests {
             None,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -111,6 +115,7 @@ mod tests {
             None,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
             &ForgeConfigFromScarb::default(),
             &[],
         );
@@ -124,6 +129,7 @@ mod tests {
                     max_n_steps: None,
                     is_vm_trace_needed: false,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: false,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -160,6 +166,7 @@ mod tests {
             None,
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
             &config_from_scarb,
             &[],
         );
@@ -173,6 +180,7 @@ mod tests {
                     max_n_steps: Some(1_000_000),
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: false,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -213,6 +221,7 @@ mod tests {
             Some(1_000_000),
             ContractsData::default(),
             Utf8PathBuf::default(),
+            false,
             &config_from_scarb,
             &[],
         );
@@ -227,6 +236,7 @@ mod tests {
                     max_n_steps: Some(1_000_000),
                     is_vm_trace_needed: true,
                     cache_dir: Utf8PathBuf::default(),
+                    offline: false,
                     contracts_data: ContractsData::default(),
                     environment_variables: config.test_runner_config.environment_variables.clone(),
                 }),
@@ -0,0 +1,23 @@
+use crate::ForkCacheCommand;
+use anyhow::Result;
+use cheatnet::forking::cache::{export_fork_caches, import_fork_caches};
+use forge_runner::CACHE_DIR;
+use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
+
+pub fn fork_cache(command: ForkCacheCommand) -> Result<()> {
+    let scarb_metadata = ScarbCommand::metadata().inherit_stderr().run()?;
+    let cache_dir = scarb_metadata.workspace.root.join(CACHE_DIR);
+
+    match command {
+        ForkCacheCommand::Export { path } => {
+            let count = export_fork_caches(&cache_dir, &path)?;
+            println!("Exported {count} fork cache(s) to {path}");
+        }
+        ForkCacheCommand::Import { path } => {
+            let count = import_fork_caches(&cache_dir, &path)?;
+            println!("Imported {count} fork cache(s) to {cache_dir}");
+        }
+    }
+
+    Ok(())
+}
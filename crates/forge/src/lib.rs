@@ -9,20 +9,24 @@ use scarb_api::{ScarbCommand, metadata::MetadataCommandExt};
 use scarb_ui::args::{FeaturesSpec, PackagesFilter};
 use semver::Version;
 use shared::print::print_as_warning;
+use starknet_types_core::felt::Felt;
 use std::cell::RefCell;
 use std::ffi::OsString;
 use std::process::Command;
 use std::{fs, num::NonZeroU32, thread::available_parallelism};
 use tokio::runtime::Builder;
 use universal_sierra_compiler_api::UniversalSierraCompilerCommand;
+use url::Url;
 
 pub mod block_number_map;
 mod clean;
 mod combine_configs;
 mod compatibility_check;
+mod fork_cache;
 mod init;
 mod new;
 pub mod pretty_printing;
+mod replay;
 pub mod run_tests;
 pub mod scarb;
 pub mod shared_cache;
@@ -98,6 +102,16 @@ enum ForgeSubcommand {
     },
     /// Clean Forge cache directory
     CleanCache {},
+    /// Export or import fork caches of the workspace
+    ForkCache {
+        #[command(subcommand)]
+        command: ForkCacheCommand,
+    },
+    /// Replay a transaction from the network and print its call trace
+    Replay {
+        #[command(flatten)]
+        args: ReplayArgs,
+    },
     /// Check if all `snforge` requirements are installed
     CheckRequirements,
 }
@@ -108,6 +122,36 @@ pub struct CleanArgs {
     pub clean_components: Vec<CleanComponent>,
 }
 
+#[derive(Subcommand, Debug)]
+pub enum ForkCacheCommand {
+    /// Write all fork caches of the workspace to a single file
+    Export {
+        /// Path of the file to write fork caches to
+        path: Utf8PathBuf,
+    },
+    /// Merge fork caches from a file created with `snforge fork-cache export` into the workspace
+    Import {
+        /// Path of the file to read fork caches from
+        path: Utf8PathBuf,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct ReplayArgs {
+    /// Hash of the invoke transaction to replay
+    pub transaction_hash: Felt,
+
+    /// RPC url of the network the transaction was sent to
+    #[arg(short, long)]
+    pub url: Url,
+
+    #[command(flatten)]
+    pub packages_filter: PackagesFilter,
+
+    #[command(flatten)]
+    pub features: FeaturesSpec,
+}
+
 #[derive(ValueEnum, Debug, Clone, PartialEq, Eq)]
 pub enum CleanComponent {
     /// Clean the `coverage` directory
@@ -195,6 +239,10 @@ pub struct TestArgs {
     #[arg(long)]
     no_optimization: bool,
 
+    /// Run forked tests using only fork caches, without fetching data from the network
+    #[arg(long)]
+    offline: bool,
+
     /// Additional arguments for cairo-coverage or cairo-profiler
     #[clap(last = true)]
     additional_args: Vec<OsString>,
@@ -249,6 +297,14 @@ pub fn main_execution() -> Result<ExitStatus> {
 
             Ok(ExitStatus::Success)
         }
+        ForgeSubcommand::ForkCache { command } => {
+            fork_cache::fork_cache(command)?;
+            Ok(ExitStatus::Success)
+        }
+        ForgeSubcommand::Replay { args } => {
+            replay::replay(&args)?;
+            Ok(ExitStatus::Success)
+        }
         ForgeSubcommand::Test { args } => {
             check_requirements(false)?;
             let cores = if let Ok(available_cores) = available_parallelism() {
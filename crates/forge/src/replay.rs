@@ -0,0 +1,450 @@
+use crate::ReplayArgs;
+use crate::scarb::{build_artifacts_with_scarb, should_compile_starknet_contract_target};
+use anyhow::{Context, Result, anyhow, bail};
+use blockifier::execution::entry_point::{CallEntryPoint, CallType};
+use blockifier::state::cached_state::CachedState;
+use blockifier::state::state_api::StateReader;
+use camino::Utf8PathBuf;
+use cheatnet::forking::state::ForkStateReader;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_call_entry_point;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    AddressOrClassHash, CallFailure, CallResult,
+};
+use cheatnet::runtime_extensions::common::create_execute_calldata;
+use cheatnet::runtime_extensions::forge_runtime_extension::cheatcodes::cheat_execution_info::{
+    ExecutionInfoMockOperations, Operation, TxInfoMockOperations,
+};
+use cheatnet::runtime_extensions::forge_runtime_extension::contracts_data::ContractsData;
+use cheatnet::state::{
+    BlockInfoReader, CallTrace, CallTraceNode, CheatnetState, ExtendedStateReader,
+};
+use console::style;
+use conversions::{FromConv, IntoConv};
+use data_transformer::AbiDecoder;
+use forge_runner::CACHE_DIR;
+use forge_runner::backtrace::get_backtrace;
+use runtime::starknet::context::build_context;
+use runtime::starknet::state::DictStateReader;
+use scarb_api::metadata::{Metadata, MetadataCommandExt};
+use scarb_api::{
+    ScarbCommand, get_contracts_artifacts_and_source_sierra_paths, target_dir_for_workspace,
+};
+use scarb_ui::args::PackagesFilter;
+use shared::print::print_as_warning;
+use shared::utils::{build_readable_text, format_felts};
+use starknet::core::types::{
+    BlockId, ExecutionResult, InvokeTransaction, MaybePendingBlockWithTxHashes, ReceiptBlock,
+    Transaction,
+};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_api::block::BlockNumber;
+use starknet_api::contract_class::EntryPointType;
+use starknet_api::core::{ClassHash, ContractAddress};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::fmt::Write;
+use tokio::runtime::Runtime;
+
+/// Invoke transaction fetched from the network, with the position in its block
+struct ReplayedTransaction {
+    sender_address: Felt,
+    calldata: Vec<Felt>,
+    tx_info: TxInfoMockOperations,
+    block_number: u64,
+    transaction_index: u64,
+    execution_result: ExecutionResult,
+}
+
+/// Contracts built from the Scarb project in the current directory
+struct LocalProject {
+    contracts_data: ContractsData,
+    cache_dir: Utf8PathBuf,
+}
+
+pub fn replay(args: &ReplayArgs) -> Result<()> {
+    let runtime = Runtime::new()?;
+    let client = JsonRpcClient::new(HttpTransport::new(args.url.clone()));
+
+    let transaction = fetch_transaction(&client, &runtime, args.transaction_hash)?;
+
+    let local_project = load_local_project(args)?;
+    let cache_dir = match &local_project {
+        Some(local_project) => local_project.cache_dir.clone(),
+        None => Utf8PathBuf::from(CACHE_DIR),
+    };
+
+    let mut fork_state_reader = ForkStateReader::new_at_transaction(
+        args.url.clone(),
+        BlockNumber(transaction.block_number),
+        transaction.transaction_index,
+        &cache_dir,
+        false,
+    )?;
+    let block_info = fork_state_reader.get_block_info()?;
+    let chain_id = fork_state_reader.chain_id()?;
+    let mut state = CachedState::new(ExtendedStateReader {
+        dict_state_reader: DictStateReader::default(),
+        fork_state_reader: Some(fork_state_reader),
+    });
+
+    let mut cheatnet_state = CheatnetState {
+        block_info: block_info.clone(),
+        ..Default::default()
+    };
+    cheatnet_state.cheat_execution_info(ExecutionInfoMockOperations {
+        tx_info: transaction.tx_info.clone(),
+        ..Default::default()
+    });
+
+    let sender_address = ContractAddress::from_(transaction.sender_address);
+    let mut entry_point = CallEntryPoint {
+        class_hash: None,
+        code_address: Some(sender_address),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: get_selector_from_name("__execute__")?.into_(),
+        calldata: create_execute_calldata(&transaction.calldata),
+        storage_address: sender_address,
+        caller_address: ContractAddress::default(),
+        call_type: CallType::Call,
+        initial_gas: i64::MAX as u64,
+    };
+    let mut context = build_context(&block_info, Some(chain_id));
+
+    let execution_result = execute_call_entry_point(
+        &mut entry_point,
+        &mut state,
+        &mut cheatnet_state,
+        &mut context,
+    );
+    let result = CallResult::from_execution_result(
+        &execution_result,
+        &AddressOrClassHash::ContractAddress(sender_address),
+    );
+
+    let mut decoders = AbiDecoders {
+        client: &client,
+        runtime: &runtime,
+        block_id: BlockId::Number(transaction.block_number),
+        decoders: HashMap::new(),
+    };
+
+    println!(
+        "Replaying transaction {:#x} from block {} at index {}\n",
+        args.transaction_hash, transaction.block_number, transaction.transaction_index
+    );
+
+    println!("{}", style("Call trace:").bold());
+    let full_trace = cheatnet_state
+        .trace_data
+        .current_call_stack
+        .borrow_full_trace();
+    let mut output = String::new();
+    for node in &full_trace.nested_calls {
+        format_call_tree(node, 0, &mut decoders, &mut output)?;
+    }
+    println!("{output}");
+
+    if !cheatnet_state.detected_events.is_empty() {
+        println!("{}", style("Events:").bold());
+        for (index, event) in cheatnet_state.detected_events.iter().enumerate() {
+            let class_hash = state.get_class_hash_at(event.from)?;
+            let decoded = decoders
+                .get(class_hash)
+                .and_then(|decoder| decoder.decode_event(&event.keys, &event.data).ok());
+
+            let formatted = match decoded {
+                Some(decoded) => {
+                    format!("{} {{ {} }}", decoded.name, format_fields(&decoded.fields))
+                }
+                None => format!(
+                    "keys: {}, data: {}",
+                    format_felts(&event.keys),
+                    format_felts(&event.data)
+                ),
+            };
+            println!("[{index}] {:#x}: {formatted}", Felt::from_(event.from));
+        }
+        println!();
+    }
+
+    match &result {
+        CallResult::Success { .. } => println!("{}: succeeded", style("Replay result").bold()),
+        CallResult::Failure(failure) => println!(
+            "{}: failed with {}",
+            style("Replay result").bold(),
+            failure_message(failure)
+        ),
+    }
+    match &transaction.execution_result {
+        ExecutionResult::Succeeded => println!("{}: succeeded", style("Result on chain").bold()),
+        ExecutionResult::Reverted { reason } => {
+            println!(
+                "{}: reverted with {reason}",
+                style("Result on chain").bold()
+            );
+        }
+    }
+
+    if let Some(local_project) = &local_project {
+        print_backtrace(&local_project.contracts_data, &cheatnet_state);
+    }
+
+    Ok(())
+}
+
+fn fetch_transaction(
+    client: &JsonRpcClient<HttpTransport>,
+    runtime: &Runtime,
+    transaction_hash: Felt,
+) -> Result<ReplayedTransaction> {
+    let transaction = runtime
+        .block_on(client.get_transaction_by_hash(transaction_hash))
+        .with_context(|| format!("Failed to fetch transaction {transaction_hash:#x}"))?;
+    let receipt = runtime
+        .block_on(client.get_transaction_receipt(transaction_hash))
+        .with_context(|| format!("Failed to fetch receipt of transaction {transaction_hash:#x}"))?;
+
+    let ReceiptBlock::Block { block_number, .. } = receipt.block else {
+        bail!("Transaction {transaction_hash:#x} is not included in a block yet")
+    };
+    let MaybePendingBlockWithTxHashes::Block(block) = runtime
+        .block_on(client.get_block_with_tx_hashes(BlockId::Number(block_number)))
+        .with_context(|| format!("Failed to fetch block {block_number}"))?
+    else {
+        bail!("Failed to fetch block {block_number}")
+    };
+    let transaction_index = block
+        .transactions
+        .iter()
+        .position(|hash| *hash == transaction_hash)
+        .with_context(|| {
+            format!("Transaction {transaction_hash:#x} not found in block {block_number}")
+        })?;
+
+    let Transaction::Invoke(invoke) = transaction else {
+        bail!("Only invoke transactions can be replayed")
+    };
+    let (sender_address, calldata, tx_info) = match invoke {
+        InvokeTransaction::V0(_) => bail!("Invoke transactions V0 cannot be replayed"),
+        InvokeTransaction::V1(transaction) => (
+            transaction.sender_address,
+            transaction.calldata,
+            TxInfoMockOperations {
+                version: Operation::StartGlobal(Felt::ONE),
+                account_contract_address: Operation::StartGlobal(transaction.sender_address),
+                max_fee: Operation::StartGlobal(transaction.max_fee),
+                signature: Operation::StartGlobal(transaction.signature),
+                transaction_hash: Operation::StartGlobal(transaction_hash),
+                nonce: Operation::StartGlobal(transaction.nonce),
+                ..Default::default()
+            },
+        ),
+        InvokeTransaction::V3(transaction) => (
+            transaction.sender_address,
+            transaction.calldata,
+            TxInfoMockOperations {
+                version: Operation::StartGlobal(Felt::THREE),
+                account_contract_address: Operation::StartGlobal(transaction.sender_address),
+                signature: Operation::StartGlobal(transaction.signature),
+                transaction_hash: Operation::StartGlobal(transaction_hash),
+                nonce: Operation::StartGlobal(transaction.nonce),
+                tip: Operation::StartGlobal(Felt::from(transaction.tip)),
+                paymaster_data: Operation::StartGlobal(transaction.paymaster_data),
+                account_deployment_data: Operation::StartGlobal(
+                    transaction.account_deployment_data,
+                ),
+                ..Default::default()
+            },
+        ),
+    };
+
+    Ok(ReplayedTransaction {
+        sender_address,
+        calldata,
+        tx_info,
+        block_number,
+        transaction_index: transaction_index as u64,
+        execution_result: receipt.receipt.execution_result().clone(),
+    })
+}
+
+/// Builds contracts of the Scarb project in the current directory, if there is one.
+/// Their artifacts are used for backtraces of errors in contracts with matching class hashes,
+/// so a project which fails to build is skipped with a warning.
+fn load_local_project(args: &ReplayArgs) -> Result<Option<LocalProject>> {
+    let Ok(scarb_metadata) = ScarbCommand::metadata().run() else {
+        return Ok(None);
+    };
+
+    let packages = args
+        .packages_filter
+        .match_many(&scarb_metadata)
+        .context("Failed to find any packages matching the specified filter")?;
+    let filter = PackagesFilter::generate_for::<Metadata>(packages.iter());
+    let scarb_version = &scarb_metadata.app_version_info.version;
+
+    if let Err(error) =
+        build_artifacts_with_scarb(filter, args.features.clone(), scarb_version, false)
+    {
+        print_as_warning(&anyhow!(
+            "Failed to build the project in the current directory, backtraces will not be printed: {error:#}"
+        ));
+        return Ok(None);
+    }
+
+    let artifacts_dir =
+        target_dir_for_workspace(&scarb_metadata).join(&scarb_metadata.current_profile);
+    let mut contracts = HashMap::new();
+    for package in &packages {
+        contracts.extend(get_contracts_artifacts_and_source_sierra_paths(
+            &artifacts_dir,
+            package,
+            !should_compile_starknet_contract_target(scarb_version, false),
+        )?);
+    }
+
+    Ok(Some(LocalProject {
+        contracts_data: ContractsData::try_from(contracts)?,
+        cache_dir: scarb_metadata.workspace.root.join(CACHE_DIR),
+    }))
+}
+
+/// ABI decoders of classes fetched from the network
+struct AbiDecoders<'a> {
+    client: &'a JsonRpcClient<HttpTransport>,
+    runtime: &'a Runtime,
+    block_id: BlockId,
+    decoders: HashMap<ClassHash, Option<AbiDecoder>>,
+}
+
+impl AbiDecoders<'_> {
+    /// Returns `None` for classes which cannot be decoded, e.g. Cairo Zero classes
+    fn get(&mut self, class_hash: ClassHash) -> Option<&AbiDecoder> {
+        let Self {
+            client,
+            runtime,
+            block_id,
+            decoders,
+        } = self;
+
+        decoders
+            .entry(class_hash)
+            .or_insert_with(|| {
+                runtime
+                    .block_on(client.get_class(*block_id, class_hash.0))
+                    .ok()
+                    .and_then(|class| AbiDecoder::new(&class).ok())
+            })
+            .as_ref()
+    }
+}
+
+fn format_call_tree(
+    node: &CallTraceNode,
+    depth: usize,
+    decoders: &mut AbiDecoders,
+    output: &mut String,
+) -> Result<()> {
+    let indent = "  ".repeat(depth);
+
+    let CallTraceNode::EntryPointCall(trace) = node else {
+        writeln!(output, "{indent}[deploy] without constructor")?;
+        return Ok(());
+    };
+    let trace = trace.borrow();
+
+    writeln!(output, "{indent}{}", format_call(&trace, decoders))?;
+
+    for nested_call in &trace.nested_calls {
+        format_call_tree(nested_call, depth + 1, decoders, output)?;
+    }
+
+    Ok(())
+}
+
+fn format_call(trace: &CallTrace, decoders: &mut AbiDecoders) -> String {
+    let entry_point = &trace.entry_point;
+    let call_type = match entry_point.call_type {
+        CallType::Call => "[call]",
+        CallType::Delegate => "[library call]",
+    };
+    let address = Felt::from_(entry_point.storage_address);
+    let selector = entry_point.entry_point_selector.0;
+    let calldata = entry_point.calldata.0.as_slice();
+    let ret_data = match &trace.result {
+        CallResult::Success { ret_data } => Some(ret_data.as_slice()),
+        CallResult::Failure(_) => None,
+    };
+
+    let decoded = entry_point
+        .class_hash
+        .and_then(|class_hash| decoders.get(class_hash))
+        .and_then(|decoder| decoder.decode_call(&selector, calldata, ret_data).ok());
+
+    let (call, outputs) = match decoded {
+        Some(decoded) => (
+            format!(
+                "{address:#x}::{}({})",
+                decoded.name,
+                format_fields(&decoded.arguments)
+            ),
+            format!("({})", decoded.outputs.join(", ")),
+        ),
+        None => (
+            format!("{address:#x}::{selector:#x}({})", format_felts(calldata)),
+            ret_data.map(format_felts).unwrap_or_default(),
+        ),
+    };
+
+    match &trace.result {
+        CallResult::Success { .. } => format!("{call_type} {call} -> {outputs}"),
+        CallResult::Failure(failure) => {
+            format!(
+                "{call_type} {call} failed with {}",
+                failure_message(failure)
+            )
+        }
+    }
+}
+
+fn format_fields(fields: &[(String, String)]) -> String {
+    fields
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn failure_message(failure: &CallFailure) -> String {
+    match failure {
+        CallFailure::Panic { panic_data } => {
+            build_readable_text(panic_data).unwrap_or_else(|| format_felts(panic_data))
+        }
+        CallFailure::Error { msg } => msg.to_string(),
+    }
+}
+
+/// Prints backtraces of errors which happened in contracts built from the local project
+fn print_backtrace(contracts_data: &ContractsData, cheatnet_state: &CheatnetState) {
+    let local_errors: Vec<_> = cheatnet_state
+        .encountered_errors
+        .iter()
+        .filter(|error| {
+            contracts_data
+                .get_contract_name(&error.class_hash)
+                .is_some()
+        })
+        .cloned()
+        .collect();
+
+    if local_errors.is_empty() {
+        return;
+    }
+
+    match get_backtrace(contracts_data, &local_errors) {
+        Ok(backtrace) => println!("\n{backtrace}"),
+        Err(error) => println!("\nfailed to create backtrace: {error}"),
+    }
+}
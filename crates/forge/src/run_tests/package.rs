@@ -74,6 +74,7 @@ impl RunForPackageArgs {
             args.max_n_steps,
             contracts_data,
             cache_dir.clone(),
+            args.offline,
             &forge_config_from_scarb,
             &args.additional_args,
         ));
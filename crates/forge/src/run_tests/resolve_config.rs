@@ -1,5 +1,5 @@
 use crate::{block_number_map::BlockNumberMap, scarb::config::ForkTarget};
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, ensure};
 use cheatnet::runtime_extensions::forge_config_extension::config::{
     BlockId, InlineForkConfig, OverriddenForkConfig, RawForkConfig,
 };
@@ -75,7 +75,16 @@ async fn resolve_fork_config(
         }
     };
 
-    Ok(Some(ResolvedForkConfig { url, block_number }))
+    ensure!(
+        raw_fork_params.transaction_index.is_none() || block_number.0 > 0,
+        "`transaction_index` cannot be used when forking the genesis block"
+    );
+
+    Ok(Some(ResolvedForkConfig {
+        url,
+        block_number,
+        transaction_index: raw_fork_params.transaction_index,
+    }))
 }
 
 fn get_fork_target_from_runner_config<'a>(
@@ -106,15 +115,24 @@ fn replace_id_with_params(
             Ok(InlineForkConfig {
                 url: fork_target_from_runner_config.url.clone(),
                 block: block_id,
+                transaction_index: None,
             })
         }
-        RawForkConfig::Overridden(OverriddenForkConfig { name, block }) => {
+        RawForkConfig::Overridden(OverriddenForkConfig {
+            name,
+            block,
+            transaction_index,
+        }) => {
             let fork_target_from_runner_config =
                 get_fork_target_from_runner_config(fork_targets, &name)?;
 
             let url = fork_target_from_runner_config.url.clone();
 
-            Ok(InlineForkConfig { url, block })
+            Ok(InlineForkConfig {
+                url,
+                block,
+                transaction_index,
+            })
         }
     }
 }
@@ -62,7 +62,7 @@ pub async fn run_for_workspace(args: TestArgs) -> Result<ExitStatus> {
         args.no_optimization,
     )?;
 
-    let mut block_number_map = BlockNumberMap::default();
+    let mut block_number_map = BlockNumberMap::new(args.offline);
     let mut all_failed_tests = vec![];
 
     let workspace_root = &scarb_metadata.workspace.root;
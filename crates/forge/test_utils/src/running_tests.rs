@@ -65,6 +65,7 @@ pub fn run_test_case(test: &TestCase) -> Vec<TestTargetSummary> {
                     cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                         .unwrap()
                         .join(CACHE_DIR),
+                    offline: false,
                     contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                     environment_variables: test.env().clone(),
                 }),
@@ -0,0 +1,50 @@
+use starknet::{ContractAddress, EthAddress};
+
+#[starknet::interface]
+trait IL1Bridge<TContractState> {
+    fn get_balance(self: @TContractState, account: ContractAddress) -> felt252;
+    fn withdraw(ref self: TContractState, l1_recipient: EthAddress, amount: felt252);
+}
+
+#[starknet::contract]
+mod L1Bridge {
+    use starknet::{ContractAddress, EthAddress, get_caller_address, send_message_to_l1_syscall};
+    use starknet::storage::{
+        Map, StoragePointerReadAccess, StoragePointerWriteAccess, StorageMapReadAccess,
+        StorageMapWriteAccess
+    };
+
+    #[storage]
+    struct Storage {
+        l1_bridge: felt252,
+        balances: Map<ContractAddress, felt252>,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, l1_bridge: felt252) {
+        self.l1_bridge.write(l1_bridge);
+    }
+
+    #[abi(embed_v0)]
+    impl L1BridgeImpl of super::IL1Bridge<ContractState> {
+        fn get_balance(self: @ContractState, account: ContractAddress) -> felt252 {
+            self.balances.read(account)
+        }
+
+        fn withdraw(ref self: ContractState, l1_recipient: EthAddress, amount: felt252) {
+            let caller = get_caller_address();
+            self.balances.write(caller, self.balances.read(caller) - amount);
+
+            send_message_to_l1_syscall(
+                self.l1_bridge.read(), array![l1_recipient.into(), amount].span()
+            )
+                .unwrap();
+        }
+    }
+
+    #[l1_handler]
+    fn deposit(ref self: ContractState, from_address: felt252, account: ContractAddress, amount: felt252) {
+        assert(from_address == self.l1_bridge.read(), 'Unauthorized l1 bridge');
+        self.balances.write(account, self.balances.read(account) + amount);
+    }
+}
@@ -0,0 +1,68 @@
+#[starknet::contract(account)]
+mod SimpleAccount {
+    use array::ArrayTrait;
+    use core::ecdsa::check_ecdsa_signature;
+    use starknet::account::Call;
+    use starknet::{get_tx_info, get_caller_address, syscalls::call_contract_syscall};
+
+    #[storage]
+    struct Storage {
+        public_key: felt252,
+    }
+
+    #[constructor]
+    fn constructor(ref self: ContractState, public_key: felt252) {
+        self.public_key.write(public_key);
+    }
+
+    #[external(v0)]
+    fn __validate__(self: @ContractState, calls: Array<Call>) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __validate_declare__(self: @ContractState, class_hash: felt252) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __validate_deploy__(
+        self: @ContractState, class_hash: felt252, contract_address_salt: felt252, public_key: felt252
+    ) -> felt252 {
+        self.validate_transaction()
+    }
+
+    #[external(v0)]
+    fn __execute__(ref self: ContractState, mut calls: Array<Call>) -> Array<Span<felt252>> {
+        assert(get_caller_address().is_zero(), 'Invalid caller');
+
+        let mut results = array![];
+        while let Option::Some(call) = calls.pop_front() {
+            let result = call_contract_syscall(call.to, call.selector, call.calldata).unwrap();
+            results.append(result);
+        };
+        results
+    }
+
+    #[external(v0)]
+    fn get_public_key(self: @ContractState) -> felt252 {
+        self.public_key.read()
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        // Checks that the transaction is signed with the key of the account
+        fn validate_transaction(self: @ContractState) -> felt252 {
+            let tx_info = get_tx_info().unbox();
+            let signature = tx_info.signature;
+            assert(signature.len() == 2, 'Invalid signature length');
+            assert(
+                check_ecdsa_signature(
+                    tx_info.transaction_hash, self.public_key.read(), *signature[0], *signature[1]
+                ),
+                'Invalid signature'
+            );
+            starknet::VALIDATED
+        }
+    }
+}
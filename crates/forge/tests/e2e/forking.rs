@@ -1,6 +1,8 @@
 use super::common::runner::{
-    BASE_FILE_PATTERNS, runner, setup_package_with_file_patterns, test_runner,
+    BASE_FILE_PATTERNS, runner, setup_package, setup_package_with_file_patterns, test_runner,
 };
+use assert_fs::TempDir;
+use assert_fs::fixture::{FileWriteStr, PathChild};
 use forge_runner::CACHE_DIR;
 use indoc::{formatdoc, indoc};
 use shared::test_utils::node_url::node_rpc_url;
@@ -130,3 +132,157 @@ fn printing_latest_block_number() {
         "},
     );
 }
+
+#[test]
+/// Exported caches are the fabricated ones from `forking/$CACHE_DIR`, so the imported
+/// cache is verified to be used in the same way as in `with_cache`.
+fn export_and_import_cache() {
+    let temp = setup_package_with_file_patterns(
+        "forking",
+        &[BASE_FILE_PATTERNS, &[&format!("{CACHE_DIR}/*.json")]].concat(),
+    );
+
+    let output = runner(&temp)
+        .args(["fork-cache", "export", "fork_cache.json"])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Exported 1 fork cache(s) to fork_cache.json
+        "},
+    );
+
+    runner(&temp).args(["clean", "cache"]).assert().code(0);
+
+    let output = runner(&temp)
+        .args(["fork-cache", "import", "fork_cache.json"])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        Imported 1 fork cache(s) to [..]
+        "},
+    );
+
+    let output = test_runner(&temp)
+        .args(["--exact", "forking::tests::test_fork_simple"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] forking::tests::test_fork_simple
+
+        Failure data:
+            0x42616c616e63652073686f756c642062652030 ('Balance should be 0')
+        "},
+    );
+}
+
+#[test]
+fn export_without_cache() {
+    let temp = setup_package_with_file_patterns("forking", BASE_FILE_PATTERNS);
+
+    let output = runner(&temp)
+        .args(["fork-cache", "export", "fork_cache.json"])
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [ERROR] No fork caches found in [..], run forked tests to create them first
+        "},
+    );
+}
+
+fn write_offline_tests(temp: &TempDir) {
+    let node_rpc_url = node_rpc_url();
+
+    temp.child("tests/offline.cairo")
+        .write_str(&formatdoc! {r#"
+            use snforge_std::load;
+            use starknet::contract_address_const;
+
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_number: 54060)]
+            fn test_cached_storage() {{
+                let balance = load(
+                    contract_address_const::<
+                        0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9
+                    >(),
+                    selector!("balance"),
+                    1,
+                );
+                assert(*balance.at(0) == 2, 'Balance should be 2');
+            }}
+
+            #[test]
+            #[fork(url: "{node_rpc_url}", block_number: 54060)]
+            fn test_uncached_storage() {{
+                load(contract_address_const::<0x123>(), 0x1, 1);
+            }}
+        "#})
+        .unwrap();
+}
+
+#[test]
+fn offline_with_cache() {
+    let temp = setup_package("empty");
+    write_offline_tests(&temp);
+
+    test_runner(&temp)
+        .arg("test_cached_storage")
+        .assert()
+        .code(0);
+
+    let output = test_runner(&temp).arg("--offline").assert().code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [..]Compiling[..]
+        [..]Finished[..]
+
+
+        Collected 2 test(s) from empty package
+        Running 0 test(s) from src/
+        Running 2 test(s) from tests/
+        [PASS] empty_integrationtest::offline::test_cached_storage [..]
+        [FAIL] empty_integrationtest::offline::test_uncached_storage
+
+        Failure data:Fork cache does not contain data used by the test, it cannot be fetched in offline mode:
+            storage of contract 0x123 at key 0x1 at block 54060
+
+        Tests: 1 passed, 1 failed, 0 skipped, 0 ignored, 0 filtered out
+
+        Failures:
+            empty_integrationtest::offline::test_uncached_storage
+        "},
+    );
+}
+
+#[test]
+fn offline_without_cache() {
+    let temp = setup_package("empty");
+    write_offline_tests(&temp);
+
+    let output = test_runner(&temp)
+        .args(["--offline", "test_cached_storage"])
+        .assert()
+        .code(1);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [FAIL] empty_integrationtest::offline::test_cached_storage
+
+        Failure data:Fork cache of [..] at block 54060 not found in [..], it cannot be fetched in offline mode
+        "},
+    );
+}
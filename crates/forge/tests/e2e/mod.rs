@@ -21,6 +21,7 @@ mod forking;
 mod fuzzing;
 mod io_operations;
 mod new;
+mod replay;
 mod requirements;
 mod running;
 mod steps;
@@ -0,0 +1,153 @@
+use super::common::runner::{runner, setup_package};
+use assert_fs::TempDir;
+use assert_fs::fixture::{FileWriteStr, PathChild};
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
+use shared::test_utils::output_assert::assert_stdout_contains;
+use starknet::core::types::{
+    BlockId, ContractClass, ExecutionResult, InvokeTransaction, MaybePendingBlockWithTxs,
+    Transaction,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_types_core::felt::Felt;
+use tokio::runtime::Runtime;
+
+const BLOCK_NUMBER: u64 = 54_061;
+
+/// Invoke transaction of `BLOCK_NUMBER` which succeeded on chain
+struct InvokeTransactionInBlock {
+    transaction_hash: Felt,
+    sender_address: Felt,
+    transaction_index: usize,
+}
+
+/// Finds the first succeeded invoke transaction of `BLOCK_NUMBER` sent from an account
+/// with a Sierra class, so its `__execute__` call can be decoded
+fn find_invoke_transaction() -> InvokeTransactionInBlock {
+    let runtime = Runtime::new().unwrap();
+    let client = JsonRpcClient::new(HttpTransport::new(node_rpc_url()));
+
+    let MaybePendingBlockWithTxs::Block(block) = runtime
+        .block_on(client.get_block_with_txs(BlockId::Number(BLOCK_NUMBER)))
+        .unwrap()
+    else {
+        panic!("Block {BLOCK_NUMBER} is pending")
+    };
+
+    block
+        .transactions
+        .iter()
+        .enumerate()
+        .find_map(|(transaction_index, transaction)| {
+            let (transaction_hash, sender_address) = match transaction {
+                Transaction::Invoke(InvokeTransaction::V1(transaction)) => {
+                    (transaction.transaction_hash, transaction.sender_address)
+                }
+                Transaction::Invoke(InvokeTransaction::V3(transaction)) => {
+                    (transaction.transaction_hash, transaction.sender_address)
+                }
+                _ => return None,
+            };
+
+            let receipt = runtime
+                .block_on(client.get_transaction_receipt(transaction_hash))
+                .unwrap();
+            let class = runtime
+                .block_on(client.get_class_at(BlockId::Number(BLOCK_NUMBER), sender_address))
+                .unwrap();
+
+            (matches!(
+                receipt.receipt.execution_result(),
+                ExecutionResult::Succeeded
+            ) && matches!(class, ContractClass::Sierra(_)))
+            .then_some(InvokeTransactionInBlock {
+                transaction_hash,
+                sender_address,
+                transaction_index,
+            })
+        })
+        .unwrap_or_else(|| panic!("Block {BLOCK_NUMBER} has no invoke transaction to replay"))
+}
+
+#[test]
+fn replay_invoke_transaction() {
+    let temp = TempDir::new().unwrap();
+    let InvokeTransactionInBlock {
+        transaction_hash,
+        sender_address,
+        transaction_index,
+    } = find_invoke_transaction();
+
+    let output = runner(&temp)
+        .args([
+            "replay",
+            &format!("{transaction_hash:#x}"),
+            "--url",
+            node_rpc_url().as_str(),
+        ])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        Replaying transaction {transaction_hash:#x} from block {BLOCK_NUMBER} at index {transaction_index}
+
+        Call trace:
+        [call] {sender_address:#x}::__execute__([..]) -> [..]
+
+        Replay result: succeeded
+        Result on chain: succeeded
+        "},
+    );
+}
+
+#[test]
+fn replay_in_project_that_does_not_build() {
+    let temp = setup_package("empty");
+    temp.child("src/lib.cairo")
+        .write_str("fn broken( {")
+        .unwrap();
+    let InvokeTransactionInBlock {
+        transaction_hash,
+        transaction_index,
+        ..
+    } = find_invoke_transaction();
+
+    let output = runner(&temp)
+        .args([
+            "replay",
+            &format!("{transaction_hash:#x}"),
+            "--url",
+            node_rpc_url().as_str(),
+        ])
+        .assert()
+        .code(0);
+
+    assert_stdout_contains(
+        output,
+        formatdoc! {r"
+        [WARNING] Failed to build the project in the current directory, backtraces will not be printed: [..]
+        Replaying transaction {transaction_hash:#x} from block {BLOCK_NUMBER} at index {transaction_index}
+        Replay result: succeeded
+        "},
+    );
+}
+
+#[test]
+fn replay_unknown_transaction() {
+    let temp = TempDir::new().unwrap();
+
+    let output = runner(&temp)
+        .args(["replay", "0x123", "--url", node_rpc_url().as_str()])
+        .assert()
+        .code(2);
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        [ERROR] Failed to fetch transaction 0x123: [..]
+        "},
+    );
+}
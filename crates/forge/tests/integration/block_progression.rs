@@ -0,0 +1,95 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn block_progression() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::{get_block_info, get_block_hash_syscall, SyscallResultTrait};
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, warp, roll, advance_blocks,
+                set_block_time, set_sequencer, start_cheat_block_timestamp,
+            };
+
+            #[starknet::interface]
+            trait ICheatBlockTimestampChecker<TContractState> {
+                fn get_block_timestamp_and_number(ref self: TContractState) -> (u64, u64);
+            }
+
+            fn deploy_checker() -> ICheatBlockTimestampCheckerDispatcher {
+                let contract = declare("CheatBlockTimestampChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                ICheatBlockTimestampCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn warp_and_roll() {
+                let checker = deploy_checker();
+
+                warp(1000);
+                roll(500);
+
+                assert_eq!(checker.get_block_timestamp_and_number(), (1000, 500));
+                let block_info = get_block_info().unbox();
+                assert_eq!(block_info.block_timestamp, 1000);
+                assert_eq!(block_info.block_number, 500);
+            }
+
+            #[test]
+            fn advance_blocks_with_block_time() {
+                let checker = deploy_checker();
+
+                warp(1000);
+                roll(500);
+                advance_blocks(10);
+                assert_eq!(checker.get_block_timestamp_and_number(), (1060, 510));
+
+                set_block_time(30);
+                advance_blocks(2);
+                assert_eq!(checker.get_block_timestamp_and_number(), (1120, 512));
+            }
+
+            #[test]
+            fn default_block_hash_history() {
+                roll(500);
+                let hash = get_block_hash_syscall(480).unwrap_syscall();
+
+                advance_blocks(100);
+                assert_eq!(get_block_hash_syscall(480).unwrap_syscall(), hash);
+                assert_ne!(get_block_hash_syscall(580).unwrap_syscall(), 0);
+            }
+
+            #[test]
+            fn sequencer() {
+                set_sequencer(123.try_into().unwrap());
+
+                assert_eq!(get_block_info().unbox().sequencer_address, 123.try_into().unwrap());
+            }
+
+            #[test]
+            fn cheats_take_precedence() {
+                let checker = deploy_checker();
+
+                start_cheat_block_timestamp(checker.contract_address, 5);
+                warp(1000);
+                roll(500);
+
+                assert_eq!(checker.get_block_timestamp_and_number(), (5, 500));
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "CheatBlockTimestampChecker".to_string(),
+            Path::new("tests/data/contracts/cheat_block_timestamp_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
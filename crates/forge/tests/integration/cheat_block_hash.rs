@@ -0,0 +1,169 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn cheat_block_hash_basic() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use result::ResultTrait;
+            use array::ArrayTrait;
+            use starknet::{ContractAddress, SyscallResultTrait, get_block_hash_syscall};
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, start_cheat_block_hash,
+                stop_cheat_block_hash, start_cheat_block_hash_global, stop_cheat_block_hash_global
+            };
+
+            #[starknet::interface]
+            trait BlockHashChecker<TContractState> {
+                fn write_block(ref self: TContractState);
+                fn read_block_hash(self: @TContractState) -> felt252;
+            }
+
+            fn deploy_block_hash_checker() -> BlockHashCheckerDispatcher {
+                let (contract_address, _) = declare("BlockHashChecker").unwrap().contract_class().deploy(@ArrayTrait::new()).unwrap();
+                BlockHashCheckerDispatcher { contract_address }
+            }
+
+            fn get_block_hash(dispatcher: BlockHashCheckerDispatcher) -> felt252 {
+                dispatcher.write_block();
+                dispatcher.read_block_hash()
+            }
+
+            fn checked_block_number() -> u64 {
+                starknet::get_block_info().unbox().block_number - 10
+            }
+
+            #[test]
+            fn test_stop_cheat_block_hash() {
+                let dispatcher = deploy_block_hash_checker();
+
+                let old_block_hash = get_block_hash(dispatcher);
+
+                start_cheat_block_hash(dispatcher.contract_address, checked_block_number(), 123);
+
+                let new_block_hash = get_block_hash(dispatcher);
+                assert(new_block_hash == 123, 'Wrong block hash');
+
+                stop_cheat_block_hash(dispatcher.contract_address, checked_block_number());
+
+                let new_block_hash = get_block_hash(dispatcher);
+                assert(new_block_hash == old_block_hash, 'Block hash did not change back');
+            }
+
+            #[test]
+            fn test_cheat_block_hash_multiple() {
+                let dispatcher1 = deploy_block_hash_checker();
+                let dispatcher2 = deploy_block_hash_checker();
+
+                let old_block_hash2 = get_block_hash(dispatcher2);
+
+                start_cheat_block_hash(dispatcher1.contract_address, checked_block_number(), 123);
+
+                assert(get_block_hash(dispatcher1) == 123, 'Wrong block hash #1');
+                assert(get_block_hash(dispatcher2) == old_block_hash2, 'Wrong block hash #2');
+            }
+
+            #[test]
+            fn test_cheat_block_hash_global() {
+                let dispatcher1 = deploy_block_hash_checker();
+                let dispatcher2 = deploy_block_hash_checker();
+
+                let old_block_hash = get_block_hash(dispatcher1);
+
+                start_cheat_block_hash_global(checked_block_number(), 123);
+
+                assert(get_block_hash(dispatcher1) == 123, 'Wrong block hash #1');
+                assert(get_block_hash(dispatcher2) == 123, 'Wrong block hash #2');
+
+                stop_cheat_block_hash_global(checked_block_number());
+
+                assert(get_block_hash(dispatcher1) == old_block_hash, 'Wrong block hash #1');
+                assert(get_block_hash(dispatcher2) == old_block_hash, 'Wrong block hash #2');
+            }
+
+            #[test]
+            fn test_cheat_block_hash_other_block() {
+                let dispatcher = deploy_block_hash_checker();
+
+                let old_block_hash = get_block_hash(dispatcher);
+
+                start_cheat_block_hash(dispatcher.contract_address, checked_block_number() - 1, 123);
+
+                assert(get_block_hash(dispatcher) == old_block_hash, 'Wrong block hash');
+            }
+
+            #[test]
+            fn test_cheat_block_hash_recent_block() {
+                let block_number = starknet::get_block_info().unbox().block_number;
+
+                start_cheat_block_hash(snforge_std::test_address(), block_number, 123);
+
+                let block_hash = get_block_hash_syscall(block_number).unwrap_syscall();
+                assert(block_hash == 123, 'Wrong block hash');
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "BlockHashChecker".to_string(),
+            Path::new("tests/data/contracts/block_hash_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn cheat_block_hash_with_span() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use result::ResultTrait;
+            use array::ArrayTrait;
+            use snforge_std::{ declare, ContractClassTrait, DeclareResultTrait, cheat_block_hash, CheatSpan };
+
+            #[starknet::interface]
+            trait BlockHashChecker<TContractState> {
+                fn write_block(ref self: TContractState);
+                fn read_block_hash(self: @TContractState) -> felt252;
+            }
+
+            fn deploy_block_hash_checker() -> BlockHashCheckerDispatcher {
+                let (contract_address, _) = declare("BlockHashChecker").unwrap().contract_class().deploy(@ArrayTrait::new()).unwrap();
+                BlockHashCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn test_cheat_block_hash_once() {
+                let block_number = starknet::get_block_info().unbox().block_number - 10;
+                let old_block_hash = core::pedersen::pedersen('BLOCK_HASH', block_number.into());
+
+                let dispatcher = deploy_block_hash_checker();
+
+                cheat_block_hash(dispatcher.contract_address, block_number, 123, CheatSpan::TargetCalls(1));
+
+                dispatcher.write_block();
+                assert_eq!(dispatcher.read_block_hash(), 123);
+
+                dispatcher.write_block();
+                assert_eq!(dispatcher.read_block_hash(), old_block_hash);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "BlockHashChecker".to_string(),
+            Path::new("tests/data/contracts/block_hash_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
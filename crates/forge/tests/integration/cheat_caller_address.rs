@@ -211,6 +211,22 @@ fn cheat_caller_address_with_span() {
                 let caller_address = starknet::get_caller_address();
                 assert(caller_address == old_caller_address, 'Wrong caller address');
             }
+
+            #[test]
+            #[should_panic(expected: "cannot cheat 0 calls, number of calls in CheatSpan must be greater than 0")]
+            fn test_cheat_caller_address_zero_calls() {
+                let dispatcher = deploy_cheat_caller_address_checker();
+
+                cheat_caller_address(dispatcher.contract_address, 123.try_into().unwrap(), CheatSpan::TargetCalls(0));
+            }
+
+            #[test]
+            #[should_panic(expected: "cannot cheat 0 calls, number of calls in CheatSpan must be greater than 0")]
+            fn test_cheat_caller_address_zero_calls_from_caller() {
+                let dispatcher = deploy_cheat_caller_address_checker();
+
+                cheat_caller_address(dispatcher.contract_address, 123.try_into().unwrap(), CheatSpan::TargetCallsFrom((test_address(), 0)));
+            }
         "#
         ),
         Contract::from_code_path(
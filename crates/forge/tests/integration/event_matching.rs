@@ -0,0 +1,324 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn event_matching() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_events, EventMatcherTrait,
+                EventSpyMatchingTrait,
+            };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+                fn emit_two_events(
+                    ref self: TContractState, some_data: felt252, some_more_data: ContractAddress
+                );
+            }
+
+            fn deploy_spy_events_checker() -> ISpyEventsCheckerDispatcher {
+                let contract = declare("SpyEventsChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                ISpyEventsCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn partial_fields() {
+                let dispatcher = deploy_spy_events_checker();
+                let some_more_data: ContractAddress = 789.try_into().unwrap();
+
+                let mut spy = spy_events();
+                dispatcher.emit_two_events(123, some_more_data);
+
+                spy
+                    .assert_emitted_matching(
+                        @EventMatcherTrait::new("SecondEvent")
+                            .emitted_by(dispatcher.contract_address)
+                            .with_field("some_more_data", some_more_data)
+                    );
+                spy
+                    .assert_emitted_matching(
+                        @EventMatcherTrait::new("SecondEvent")
+                            .with_field("some_data", 123)
+                            .with_field("some_more_data", some_more_data)
+                    );
+                spy
+                    .assert_not_emitted_matching(
+                        @EventMatcherTrait::new("SecondEvent").with_field("some_data", 456)
+                    );
+                spy
+                    .assert_not_emitted_matching(
+                        @EventMatcherTrait::new("SecondEvent").emitted_by(some_more_data)
+                    );
+            }
+
+            #[test]
+            fn counting() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(123);
+                dispatcher.emit_one_event(456);
+                dispatcher.emit_one_event(123);
+
+                let matcher = EventMatcherTrait::new("FirstEvent").with_field("some_data", 123);
+                spy.assert_emitted_matching_times(@matcher, 2);
+
+                assert(spy.count_matching(@EventMatcherTrait::new("FirstEvent")) == 3, 'Wrong count');
+                assert(spy.count_matching(@EventMatcherTrait::new("ThirdEvent")) == 0, 'Wrong count');
+
+                let events = spy.get_matching_events(@matcher);
+                assert(events.events.len() == 2, 'Wrong events');
+                let (from, _) = events.events.at(0);
+                assert(*from == dispatcher.contract_address, 'Wrong emitter');
+            }
+
+            #[test]
+            fn order() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(123);
+                dispatcher.emit_one_event(456);
+
+                spy
+                    .assert_emitted_in_order(
+                        @array![
+                            EventMatcherTrait::new("FirstEvent").with_field("some_data", 123),
+                            EventMatcherTrait::new("FirstEvent").with_field("some_data", 456),
+                        ]
+                    );
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn same_named_events_of_different_contracts() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_events, EventMatcherTrait,
+                EventSpyMatchingTrait,
+            };
+
+            #[starknet::interface]
+            trait ITransferrer<TContractState> {
+                fn transfer(ref self: TContractState, amount: u256);
+            }
+
+            fn deploy(name: ByteArray) -> ITransferrerDispatcher {
+                let contract = declare(name).unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                ITransferrerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn same_named_events() {
+                let erc20 = deploy("Erc20");
+                let erc721 = deploy("Erc721");
+
+                let mut spy = spy_events();
+                erc20.transfer(5);
+                erc721.transfer(7);
+
+                spy.assert_emitted_matching(@EventMatcherTrait::new("Transfer").with_field("value", 5_u256));
+                spy.assert_emitted_matching(@EventMatcherTrait::new("Transfer").with_field("token_id", 7_u256));
+                spy.assert_not_emitted_matching(@EventMatcherTrait::new("Transfer").with_field("value", 7_u256));
+                assert(spy.count_matching(@EventMatcherTrait::new("Transfer")) == 2, 'Wrong count');
+            }
+        "#
+        ),
+        Contract::new(
+            "Erc20",
+            indoc!(
+                r"
+                #[starknet::contract]
+                mod Erc20 {
+                    #[storage]
+                    struct Storage {}
+
+                    #[event]
+                    #[derive(Drop, starknet::Event)]
+                    enum Event {
+                        Transfer: Transfer,
+                    }
+
+                    #[derive(Drop, starknet::Event)]
+                    struct Transfer {
+                        value: u256,
+                    }
+
+                    #[external(v0)]
+                    fn transfer(ref self: ContractState, amount: u256) {
+                        self.emit(Transfer { value: amount });
+                    }
+                }
+                "
+            )
+        ),
+        Contract::new(
+            "Erc721",
+            indoc!(
+                r"
+                #[starknet::contract]
+                mod Erc721 {
+                    #[storage]
+                    struct Storage {}
+
+                    #[event]
+                    #[derive(Drop, starknet::Event)]
+                    enum Event {
+                        Transfer: Transfer,
+                    }
+
+                    #[derive(Drop, starknet::Event)]
+                    struct Transfer {
+                        token_id: u256,
+                    }
+
+                    #[external(v0)]
+                    fn transfer(ref self: ContractState, amount: u256) {
+                        self.emit(Transfer { token_id: amount });
+                    }
+                }
+                "
+            )
+        )
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn event_matching_failures() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::ContractAddress;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, spy_events, EventMatcherTrait,
+                EventSpyMatchingTrait,
+            };
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+                fn emit_two_events(
+                    ref self: TContractState, some_data: felt252, some_more_data: ContractAddress
+                );
+            }
+
+            fn deploy_spy_events_checker() -> ISpyEventsCheckerDispatcher {
+                let contract = declare("SpyEventsChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                ISpyEventsCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn mismatched_field() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_two_events(123, 789.try_into().unwrap());
+
+                spy
+                    .assert_emitted_matching(
+                        @EventMatcherTrait::new("SecondEvent").with_field("some_data", 456)
+                    );
+            }
+
+            #[test]
+            fn wrong_count() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(123);
+
+                spy.assert_emitted_matching_times(@EventMatcherTrait::new("FirstEvent"), 2);
+            }
+
+            #[test]
+            fn wrong_order() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(123);
+                dispatcher.emit_one_event(456);
+
+                spy
+                    .assert_emitted_in_order(
+                        @array![
+                            EventMatcherTrait::new("FirstEvent").with_field("some_data", 456),
+                            EventMatcherTrait::new("FirstEvent").with_field("some_data", 123),
+                        ]
+                    );
+            }
+
+            #[test]
+            fn unknown_field() {
+                let dispatcher = deploy_spy_events_checker();
+
+                let mut spy = spy_events();
+                dispatcher.emit_one_event(123);
+
+                spy
+                    .assert_emitted_matching(
+                        @EventMatcherTrait::new("FirstEvent").with_field("other_data", 123)
+                    );
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "mismatched_field",
+        "Event matching the expected one was not emitted",
+    );
+    assert_case_output_contains(&result, "mismatched_field", "closest:  ");
+    assert_case_output_contains(&result, "mismatched_field", "mismatched fields: some_data");
+    assert_case_output_contains(
+        &result,
+        "wrong_count",
+        "Event matching the expected one was emitted 1 times instead of 2",
+    );
+    assert_case_output_contains(
+        &result,
+        "wrong_order",
+        "Event matching the expected one at position 1 was not emitted after the previous ones",
+    );
+    assert_case_output_contains(
+        &result,
+        "unknown_field",
+        r#"Field "other_data" not found in event"#,
+    );
+}
@@ -0,0 +1,321 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn expect_revert() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+                fn do_a_panic(self: @TContractState);
+            }
+
+            #[test]
+            fn expect_revert_with_panic_data() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                expect_revert(array!['PANIC', 'DAYTAH']);
+                dispatcher.do_a_panic();
+
+                // Expectation applies only to the next call
+                dispatcher.increase_balance(5);
+                assert_eq!(dispatcher.get_balance(), 5);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_revert_of_value_returning_function() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IBank<TContractState> {
+                fn withdraw(ref self: TContractState, amount: u256) -> (u256, bool);
+            }
+
+            #[test]
+            fn expect_revert_of_value_returning_function() {
+                let contract = declare("Bank").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IBankDispatcher { contract_address };
+
+                expect_revert(array!['Not enough balance']);
+                let (balance, success) = dispatcher.withdraw(100);
+
+                assert_eq!(balance, 0);
+                assert!(!success);
+            }
+        "#
+        ),
+        Contract::new(
+            "Bank",
+            indoc!(
+                r"
+                #[starknet::interface]
+                trait IBank<TContractState> {
+                    fn withdraw(ref self: TContractState, amount: u256) -> (u256, bool);
+                }
+
+                #[starknet::contract]
+                mod Bank {
+                    #[storage]
+                    struct Storage {}
+
+                    #[abi(embed_v0)]
+                    impl BankImpl of super::IBank<ContractState> {
+                        fn withdraw(ref self: ContractState, amount: u256) -> (u256, bool) {
+                            panic_with_felt252('Not enough balance')
+                        }
+                    }
+                }
+                "
+            )
+        )
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_revert_fails() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_revert};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn do_a_panic(self: @TContractState);
+            }
+
+            fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                IHelloStarknetDispatcher { contract_address }
+            }
+
+            #[test]
+            fn call_succeeded() {
+                let dispatcher = deploy_hello_starknet();
+
+                expect_revert(array!['PANIC']);
+                dispatcher.increase_balance(5);
+            }
+
+            #[test]
+            fn other_panic_data() {
+                let dispatcher = deploy_hello_starknet();
+
+                expect_revert(array!['PANIC']);
+                dispatcher.do_a_panic();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "call_succeeded",
+        "Call was expected to revert but it succeeded",
+    );
+    assert_case_output_contains(
+        &result,
+        "call_succeeded",
+        "expected panic data: 0x50414e4943 ('PANIC')",
+    );
+    assert_case_output_contains(
+        &result,
+        "other_panic_data",
+        "Call reverted with unexpected panic data",
+    );
+    assert_case_output_contains(
+        &result,
+        "other_panic_data",
+        "expected: 0x50414e4943 ('PANIC')",
+    );
+    assert_case_output_contains(
+        &result,
+        "other_panic_data",
+        "actual:   (0x50414e4943 ('PANIC'), 0x444159544148 ('DAYTAH'))",
+    );
+}
+
+#[test]
+fn expect_emit() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_emit};
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+            }
+
+            #[starknet::contract]
+            mod SpyEventsChecker {
+                #[storage]
+                struct Storage {}
+
+                #[event]
+                #[derive(Drop, starknet::Event)]
+                enum Event {
+                    FirstEvent: FirstEvent
+                }
+
+                #[derive(Drop, starknet::Event)]
+                struct FirstEvent {
+                    some_data: felt252
+                }
+            }
+
+            fn deploy_spy_events_checker() -> ISpyEventsCheckerDispatcher {
+                let contract = declare("SpyEventsChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                ISpyEventsCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn event_emitted() {
+                let dispatcher = deploy_spy_events_checker();
+
+                expect_emit(
+                    @array![
+                        (
+                            dispatcher.contract_address,
+                            SpyEventsChecker::Event::FirstEvent(
+                                SpyEventsChecker::FirstEvent { some_data: 123 }
+                            )
+                        )
+                    ]
+                );
+                dispatcher.emit_one_event(123);
+
+                // Expectation applies only to the next call
+                dispatcher.emit_one_event(456);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn expect_emit_fails() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{declare, ContractClassTrait, DeclareResultTrait, expect_emit};
+
+            #[starknet::interface]
+            trait ISpyEventsChecker<TContractState> {
+                fn emit_one_event(ref self: TContractState, some_data: felt252);
+            }
+
+            #[starknet::contract]
+            mod SpyEventsChecker {
+                #[storage]
+                struct Storage {}
+
+                #[event]
+                #[derive(Drop, starknet::Event)]
+                enum Event {
+                    FirstEvent: FirstEvent
+                }
+
+                #[derive(Drop, starknet::Event)]
+                struct FirstEvent {
+                    some_data: felt252
+                }
+            }
+
+            fn deploy_spy_events_checker() -> ISpyEventsCheckerDispatcher {
+                let contract = declare("SpyEventsChecker").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                ISpyEventsCheckerDispatcher { contract_address }
+            }
+
+            #[test]
+            fn event_not_emitted() {
+                let dispatcher = deploy_spy_events_checker();
+
+                expect_emit(
+                    @array![
+                        (
+                            dispatcher.contract_address,
+                            SpyEventsChecker::Event::FirstEvent(
+                                SpyEventsChecker::FirstEvent { some_data: 123 }
+                            )
+                        )
+                    ]
+                );
+                dispatcher.emit_one_event(456);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SpyEventsChecker".to_string(),
+            Path::new("tests/data/contracts/spy_events_checker.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "event_not_emitted",
+        "Expected event was not emitted by the call",
+    );
+    assert_case_output_contains(&result, "event_not_emitted", "data: [0x7b]");
+    assert_case_output_contains(&result, "event_not_emitted", "data: [0x1c8]");
+}
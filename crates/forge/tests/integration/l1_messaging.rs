@@ -0,0 +1,158 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn l1_messaging_round_trip() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use core::keccak::keccak_u256s_be_inputs;
+            use core::integer::u128_byte_reverse;
+            use starknet::{ContractAddress, EthAddress, contract_address_const};
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, send_message_to_l2,
+                consume_message_from_l2, pending_messages_to_l1, start_cheat_caller_address,
+            };
+
+            #[starknet::interface]
+            trait IL1Bridge<TContractState> {
+                fn get_balance(self: @TContractState, account: ContractAddress) -> felt252;
+                fn withdraw(ref self: TContractState, l1_recipient: EthAddress, amount: felt252);
+            }
+
+            const L1_BRIDGE: felt252 = 0x1234;
+            const L1_RECIPIENT: felt252 = 0x5678;
+
+            fn deploy_bridge() -> IL1BridgeDispatcher {
+                let contract = declare("L1Bridge").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![L1_BRIDGE]).unwrap();
+
+                IL1BridgeDispatcher { contract_address }
+            }
+
+            fn keccak(words: Span<u256>) -> u256 {
+                let hash = keccak_u256s_be_inputs(words);
+                u256 { low: u128_byte_reverse(hash.high), high: u128_byte_reverse(hash.low) }
+            }
+
+            fn deposit(bridge: ContractAddress, account: ContractAddress, amount: felt252) -> felt252 {
+                let receipt = send_message_to_l2(
+                    L1_BRIDGE.try_into().unwrap(),
+                    bridge,
+                    selector!("deposit"),
+                    array![account.into(), amount].span(),
+                );
+                assert(receipt.result.is_ok(), 'Deposit failed');
+
+                let bridge: felt252 = bridge.into();
+                let account: felt252 = account.into();
+                let expected_hash = keccak(
+                    array![
+                        L1_BRIDGE.into(),
+                        bridge.into(),
+                        receipt.nonce.into(),
+                        selector!("deposit").into(),
+                        2,
+                        account.into(),
+                        amount.into(),
+                    ]
+                        .span()
+                );
+                assert_eq!(receipt.message_hash, expected_hash);
+
+                receipt.nonce
+            }
+
+            #[test]
+            fn messages_to_l2_have_sequential_nonces() {
+                let bridge = deploy_bridge();
+                let account = contract_address_const::<0x123>();
+
+                assert_eq!(deposit(bridge.contract_address, account, 100), 0);
+                assert_eq!(deposit(bridge.contract_address, account, 50), 1);
+                assert_eq!(bridge.get_balance(account), 150);
+            }
+
+            #[test]
+            fn failing_handler_uses_nonce() {
+                let bridge = deploy_bridge();
+
+                let receipt = send_message_to_l2(
+                    0x999.try_into().unwrap(), bridge.contract_address, selector!("deposit"), array![0x123, 100].span(),
+                );
+                assert(receipt.result.is_err(), 'Deposit should fail');
+                assert_eq!(receipt.nonce, 0);
+
+                assert_eq!(deposit(bridge.contract_address, contract_address_const::<0x123>(), 100), 1);
+            }
+
+            #[test]
+            fn messages_to_l1_are_consumed() {
+                let bridge = deploy_bridge();
+                let account = contract_address_const::<0x123>();
+                deposit(bridge.contract_address, account, 100);
+
+                start_cheat_caller_address(bridge.contract_address, account);
+                bridge.withdraw(L1_RECIPIENT.try_into().unwrap(), 30);
+                bridge.withdraw(L1_RECIPIENT.try_into().unwrap(), 30);
+
+                let pending = pending_messages_to_l1();
+                assert_eq!(pending.len(), 2);
+
+                let message = pending.at(0);
+                let bridge_address: felt252 = bridge.contract_address.into();
+                let expected_hash = keccak(
+                    array![bridge_address.into(), L1_BRIDGE.into(), 2, L1_RECIPIENT.into(), 30]
+                        .span()
+                );
+                assert_eq!(*message.from_address, bridge.contract_address);
+                assert(message.payload == @array![L1_RECIPIENT, 30], 'Invalid payload');
+                assert_eq!(*message.message_hash, expected_hash);
+
+                let payload = array![L1_RECIPIENT, 30].span();
+                let l1_bridge: EthAddress = L1_BRIDGE.try_into().unwrap();
+                assert_eq!(consume_message_from_l2(bridge.contract_address, l1_bridge, payload).unwrap(), expected_hash);
+                assert_eq!(pending_messages_to_l1().len(), 1);
+                consume_message_from_l2(bridge.contract_address, l1_bridge, payload).unwrap();
+                assert_eq!(pending_messages_to_l1().len(), 0);
+
+                // Both messages were already consumed
+                consume_message_from_l2(bridge.contract_address, l1_bridge, payload).unwrap_err();
+            }
+
+            #[test]
+            fn consuming_with_wrong_payload_fails() {
+                let bridge = deploy_bridge();
+                let account = contract_address_const::<0x123>();
+                deposit(bridge.contract_address, account, 100);
+
+                start_cheat_caller_address(bridge.contract_address, account);
+                bridge.withdraw(L1_RECIPIENT.try_into().unwrap(), 30);
+
+                let l1_bridge: EthAddress = L1_BRIDGE.try_into().unwrap();
+                let err = consume_message_from_l2(bridge.contract_address, l1_bridge, array![L1_RECIPIENT, 31].span())
+                    .unwrap_err();
+                assert!(err.len() > 0);
+
+                // Message sent to a different L1 contract
+                consume_message_from_l2(bridge.contract_address, L1_RECIPIENT.try_into().unwrap(), array![L1_RECIPIENT, 30].span())
+                    .unwrap_err();
+
+                assert_eq!(pending_messages_to_l1().len(), 1);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "L1Bridge".to_string(),
+            Path::new("tests/data/contracts/l1_bridge.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
@@ -0,0 +1,133 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn mock_contract() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::{ContractAddress, contract_address_const};
+            use snforge_std::{mock_contract, ContractMockTrait};
+
+            #[starknet::interface]
+            trait IERC20<TContractState> {
+                fn get_name(self: @TContractState) -> felt252;
+                fn get_total_supply(self: @TContractState) -> u256;
+                fn balance_of(self: @TContractState, account: ContractAddress) -> u256;
+                fn transfer(ref self: TContractState, recipient: ContractAddress, amount: u256);
+            }
+
+            #[starknet::interface]
+            trait INotERC20<TContractState> {
+                fn not_in_abi(self: @TContractState);
+            }
+
+            #[test]
+            fn default_values() {
+                let contract_address = contract_address_const::<0x123>();
+                mock_contract(contract_address, "ERC20");
+
+                let dispatcher = IERC20Dispatcher { contract_address };
+                assert_eq!(dispatcher.get_name(), 0);
+                assert_eq!(dispatcher.get_total_supply(), 0);
+                assert_eq!(dispatcher.balance_of(contract_address_const::<0x1>()), 0);
+                dispatcher.transfer(contract_address_const::<0x1>(), 100);
+            }
+
+            #[test]
+            fn overrides_and_calls_count() {
+                let contract_address = contract_address_const::<0x123>();
+                let token = mock_contract(contract_address, "ERC20");
+                token.mock_function("balance_of", 1000_u256);
+
+                let dispatcher = IERC20Dispatcher { contract_address };
+                assert_eq!(dispatcher.balance_of(contract_address_const::<0x1>()), 1000);
+                dispatcher.transfer(contract_address_const::<0x1>(), 100);
+                dispatcher.transfer(contract_address_const::<0x2>(), 100);
+
+                assert_eq!(token.calls_count("balance_of"), 1);
+                assert_eq!(token.calls_count("transfer"), 2);
+                assert_eq!(token.calls_count("get_name"), 0);
+            }
+
+            #[test]
+            #[feature("safe_dispatcher")]
+            fn function_not_in_abi() {
+                let contract_address = contract_address_const::<0x123>();
+                mock_contract(contract_address, "ERC20");
+
+                let dispatcher = INotERC20SafeDispatcher { contract_address };
+                match dispatcher.not_in_abi() {
+                    Result::Ok(_) => panic!("Call should fail"),
+                    Result::Err(panic_data) => {
+                        assert_eq!(panic_data.len(), 4);
+                    },
+                };
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "ERC20".to_string(),
+            Path::new("tests/data/contracts/erc20.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn mock_contract_invalid_usage() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use starknet::contract_address_const;
+            use snforge_std::{mock_contract, ContractMockTrait};
+
+            #[test]
+            fn stopped_mock() {
+                let token = mock_contract(contract_address_const::<0x123>(), "ERC20");
+                token.stop();
+
+                token.mock_function("get_name", 'mocked');
+            }
+
+            #[test]
+            fn typo_in_function_name() {
+                let token = mock_contract(contract_address_const::<0x123>(), "ERC20");
+                token.mock_function("balanceOf", 1000_u256);
+            }
+
+            #[test]
+            fn unknown_contract() {
+                mock_contract(contract_address_const::<0x123>(), "NotERC20");
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "ERC20".to_string(),
+            Path::new("tests/data/contracts/erc20.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(&result, "stopped_mock", "Contract at 0x123 is not mocked");
+    assert_case_output_contains(
+        &result,
+        "typo_in_function_name",
+        "Function balanceOf not found in ABI of contract mocked at 0x123",
+    );
+    assert_case_output_contains(
+        &result,
+        "unknown_contract",
+        "Contract with name NotERC20 not found in the project",
+    );
+}
@@ -1,5 +1,7 @@
 mod available_gas;
+mod block_progression;
 mod builtins;
+mod cheat_block_hash;
 mod cheat_block_number;
 mod cheat_block_timestamp;
 mod cheat_caller_address;
@@ -13,15 +15,22 @@ mod deploy_at;
 mod dict;
 mod dispatchers;
 mod env;
+mod event_matching;
+mod expect;
 mod fuzzing;
 mod gas;
 mod generate_random_felt;
 mod get_class_hash;
 mod l1_handler_executor;
+mod l1_messaging;
 mod message_to_l1;
 mod mock_call;
+mod mock_contract;
+#[cfg(not(target_os = "windows"))]
+mod multi_fork;
 mod precalculate_address;
 mod pure_cairo;
+mod record_storage_accesses;
 mod replace_bytecode;
 mod resources;
 mod runtime;
@@ -30,8 +39,10 @@ mod setup_fork;
 mod should_panic;
 mod signing;
 mod spy_events;
+mod state_diff;
 mod store_load;
 mod syscalls;
 mod test_state;
 mod too_many_events;
 mod trace;
+mod transaction;
@@ -0,0 +1,154 @@
+use indoc::{formatdoc, indoc};
+use shared::test_utils::node_url::node_rpc_url;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn create_and_select_fork() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use starknet::contract_address_const;
+            use snforge_std::{{ForkBlockId, ForkId, active_fork, create_select_fork}};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {{
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            fn create_and_select_fork() {{
+                assert(active_fork().is_none(), 'fork should not be active');
+
+                let fork_id = create_select_fork("{}", ForkBlockId::Number(54060));
+                assert(fork_id == ForkId {{ id: 0 }}, 'wrong fork id');
+                assert(active_fork() == Option::Some(fork_id), 'fork not selected');
+
+                let dispatcher = IHelloStarknetDispatcher {{
+                    contract_address: contract_address_const::<0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9>()
+                }};
+
+                assert(dispatcher.get_balance() == 0, 'wrong balance');
+                assert(starknet::get_block_info().unbox().block_number == 54060, 'wrong block number');
+            }}
+        "#,
+        node_rpc_url(),
+    )
+    .as_str());
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn switching_forks_keeps_local_contracts() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            use starknet::contract_address_const;
+            use snforge_std::{{
+                ContractClassTrait, DeclareResultTrait, ForkBlockId, ForkId, active_fork,
+                create_fork, declare, roll_fork, select_fork,
+            }};
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {{
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{url}", block_number: 54060)]
+            fn switching_forks_keeps_local_contracts() {{
+                assert(active_fork() == Option::Some(ForkId {{ id: 0 }}), 'initial fork not active');
+
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@array![]).unwrap();
+                let local = IHelloStarknetDispatcher {{ contract_address }};
+                local.increase_balance(5);
+
+                let forked = IHelloStarknetDispatcher {{
+                    contract_address: contract_address_const::<0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9>()
+                }};
+                forked.increase_balance(10);
+                assert(forked.get_balance() == 10, 'forked balance not changed');
+
+                let other = create_fork("{url}", ForkBlockId::Number(54061));
+                select_fork(other);
+
+                assert(active_fork() == Option::Some(other), 'other fork not active');
+                assert(starknet::get_block_info().unbox().block_number == 54061, 'wrong block number');
+                assert(local.get_balance() == 5, 'local state lost');
+                assert(forked.get_balance() == 0, 'forked state not reset');
+
+                select_fork(ForkId {{ id: 0 }});
+                roll_fork(54062);
+
+                assert(active_fork() == Option::Some(ForkId {{ id: 0 }}), 'initial fork not active');
+                assert(starknet::get_block_info().unbox().block_number == 54062, 'fork not rolled');
+                assert(local.get_balance() == 5, 'local state lost after roll');
+            }}
+        "#,
+            url = node_rpc_url(),
+        )
+        .as_str(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn select_nonexistent_fork() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::{ForkId, select_fork};
+
+            #[test]
+            fn select_nonexistent_fork() {
+                select_fork(ForkId { id: 5 });
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "select_nonexistent_fork",
+        "Fork with id 5 does not exist",
+    );
+}
+
+#[test]
+fn roll_without_fork() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::roll_fork;
+
+            #[test]
+            fn roll_without_fork() {
+                roll_fork(100);
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "roll_without_fork",
+        "Cannot roll fork, no fork is selected",
+    );
+}
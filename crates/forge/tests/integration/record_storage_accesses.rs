@@ -0,0 +1,95 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn record_storage_accesses() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, record_storage_accesses,
+                StorageAccess, StorageAccessRecorderTrait, StorageAccessesFilterTrait, StorageRead,
+                StorageWrite,
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            fn deploy_hello_starknet() -> IHelloStarknetDispatcher {
+                let (contract_address, _) = declare("HelloStarknet").unwrap().contract_class().deploy(@ArrayTrait::new()).unwrap();
+                IHelloStarknetDispatcher { contract_address }
+            }
+
+            #[test]
+            fn test_record_storage_accesses() {
+                let dispatcher = deploy_hello_starknet();
+                let balance_key = selector!("balance");
+
+                let mut recorder = record_storage_accesses();
+
+                dispatcher.increase_balance(5);
+                dispatcher.get_balance();
+
+                let accesses = recorder.get_storage_accesses();
+                assert_eq!(
+                    accesses.accesses,
+                    array![
+                        (dispatcher.contract_address, StorageAccess::Read(StorageRead { key: balance_key, value: 0 })),
+                        (
+                            dispatcher.contract_address,
+                            StorageAccess::Write(StorageWrite { key: balance_key, old_value: 0, new_value: 5 }),
+                        ),
+                        (dispatcher.contract_address, StorageAccess::Read(StorageRead { key: balance_key, value: 5 })),
+                    ]
+                );
+            }
+
+            #[test]
+            fn test_record_storage_accesses_filtered() {
+                let dispatcher1 = deploy_hello_starknet();
+                let dispatcher2 = deploy_hello_starknet();
+                let balance_key = selector!("balance");
+
+                dispatcher1.increase_balance(1);
+
+                let mut recorder = record_storage_accesses();
+
+                dispatcher1.increase_balance(5);
+                dispatcher2.get_balance();
+
+                let accesses = recorder.get_storage_accesses();
+
+                assert_eq!(
+                    accesses.reads(dispatcher1.contract_address),
+                    array![StorageRead { key: balance_key, value: 1 }]
+                );
+                assert_eq!(
+                    accesses.writes(dispatcher1.contract_address),
+                    array![StorageWrite { key: balance_key, old_value: 1, new_value: 6 }]
+                );
+                assert_eq!(
+                    accesses.reads(dispatcher2.contract_address),
+                    array![StorageRead { key: balance_key, value: 0 }]
+                );
+                assert_eq!(accesses.writes(dispatcher2.contract_address), array![]);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
@@ -20,10 +20,13 @@ use forge_runner::CACHE_DIR;
 use forge_runner::forge_config::{
     ExecutionDataToSave, ForgeConfig, OutputConfig, TestRunnerConfig,
 };
+use forge_runner::test_case_summary::{AnyTestCaseSummary, TestCaseSummary};
 use scarb_api::ScarbCommand;
 use scarb_api::metadata::MetadataCommandExt;
 use shared::test_utils::node_url::node_rpc_url;
-use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::runner::{
+    Contract, TestCase, assert_case_output_contains, assert_failed, assert_passed,
+};
 use test_utils::running_tests::run_test_case;
 use test_utils::test_case;
 
@@ -151,6 +154,7 @@ fn fork_aliased_decorator() {
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        offline: false,
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         environment_variables: test.env().clone(),
                     }),
@@ -236,6 +240,7 @@ fn fork_aliased_decorator_overrding() {
                         cache_dir: Utf8PathBuf::from_path_buf(tempdir().unwrap().into_path())
                             .unwrap()
                             .join(CACHE_DIR),
+                        offline: false,
                         contracts_data: ContractsData::try_from(test.contracts().unwrap()).unwrap(),
                         environment_variables: test.env().clone(),
                     }),
@@ -413,6 +418,103 @@ fn fork_get_block_info_fails() {
     );
 }
 
+#[test]
+fn fork_at_transaction_index() {
+    let test = test_case!(formatdoc!(
+        r#"
+            use starknet::contract_address_const;
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {{
+                fn get_balance(self: @TContractState) -> felt252;
+            }}
+
+            #[test]
+            #[fork(url: "{}", block_number: 54061, transaction_index: 0)]
+            fn fork_at_transaction_index() {{
+                let block_info = starknet::get_block_info().unbox();
+                assert(block_info.block_number == 54061, 'Invalid block number');
+
+                let dispatcher = IHelloStarknetDispatcher {{
+                    contract_address: contract_address_const::<0x202de98471a4fae6bcbabb96cab00437d381abc58b02509043778074d6781e9>()
+                }};
+                assert(dispatcher.get_balance() == 0, 'Balance should be 0');
+            }}
+        "#,
+        node_rpc_url()
+    ).as_str());
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn fork_at_transaction_index_does_not_charge_replayed_transactions() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            #[test]
+            #[fork(url: "{url}", block_number: 54061, transaction_index: 0)]
+            fn without_replayed_transactions() {{
+                assert(starknet::get_block_info().unbox().block_number == 54061, 'Invalid block number');
+            }}
+
+            #[test]
+            #[fork(url: "{url}", block_number: 54061, transaction_index: 1)]
+            fn with_replayed_transactions() {{
+                assert(starknet::get_block_info().unbox().block_number == 54061, 'Invalid block number');
+            }}
+        "#,
+            url = node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+    let gas = TestCase::find_test_result(&result)
+        .test_case_summaries
+        .iter()
+        .map(|summary| {
+            let AnyTestCaseSummary::Single(TestCaseSummary::Passed { gas_info, .. }) = summary
+            else {
+                panic!()
+            };
+            *gas_info
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(gas.len(), 2);
+    assert_eq!(gas[0], gas[1]);
+}
+
+#[test]
+fn fork_at_transaction_index_out_of_range() {
+    let test = test_case!(
+        formatdoc!(
+            r#"
+            #[test]
+            #[fork(url: "{}", block_number: 54061, transaction_index: 100000)]
+            fn fork_at_transaction_index_out_of_range() {{
+                starknet::get_block_info();
+            }}
+        "#,
+            node_rpc_url()
+        )
+        .as_str()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "fork_at_transaction_index_out_of_range",
+        "cannot replay 100000 of them",
+    );
+}
+
 #[test]
 // found in: https://github.com/foundry-rs/starknet-foundry/issues/1175
 fn incompatible_abi() {
@@ -0,0 +1,128 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_case_output_contains, assert_failed, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn state_diff() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, get_state_diff, mark_state, store,
+                StateMarkerTrait, StateDiff, StorageDiff, ClassHashDiff,
+            };
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            #[test]
+            fn test_state_diff_since_first_marker() {
+                mark_state();
+
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                dispatcher.increase_balance(5);
+
+                assert_eq!(
+                    get_state_diff(),
+                    StateDiff {
+                        storage: array![
+                            StorageDiff {
+                                contract_address, key: selector!("balance"), old_value: 0, new_value: 5,
+                            },
+                        ],
+                        nonces: array![],
+                        class_hashes: array![
+                            ClassHashDiff {
+                                contract_address,
+                                old_class_hash: 0.try_into().unwrap(),
+                                new_class_hash: *contract.class_hash,
+                            },
+                        ],
+                        declared_classes: array![*contract.class_hash],
+                    }
+                );
+            }
+
+            #[test]
+            fn test_state_diff_since_marker() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                dispatcher.increase_balance(5);
+
+                let marker = mark_state();
+
+                dispatcher.increase_balance(2);
+                store(contract_address, selector!("balance"), array![10].span());
+
+                assert_eq!(
+                    marker.get_state_diff().storage,
+                    array![
+                        StorageDiff {
+                            contract_address, key: selector!("balance"), old_value: 5, new_value: 10,
+                        },
+                    ]
+                );
+                assert_eq!(marker.get_state_diff().class_hashes, array![]);
+                assert_eq!(marker.get_state_diff().declared_classes, array![]);
+            }
+
+            #[test]
+            fn test_state_diff_skips_restored_values() {
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                let marker = mark_state();
+
+                dispatcher.increase_balance(5);
+                dispatcher.increase_balance(-5);
+
+                assert_eq!(marker.get_state_diff().storage, array![]);
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
+
+#[test]
+fn state_diff_without_marker() {
+    let test = test_case!(indoc!(
+        r"
+            use snforge_std::get_state_diff;
+
+            #[test]
+            fn test_state_diff_without_marker() {
+                get_state_diff();
+            }
+        "
+    ));
+
+    let result = run_test_case(&test);
+
+    assert_failed(&result);
+    assert_case_output_contains(
+        &result,
+        "test_state_diff_without_marker",
+        "State changes are not recorded, call `mark_state` before `get_state_diff`",
+    );
+}
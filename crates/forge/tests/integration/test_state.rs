@@ -173,8 +173,11 @@ fn get_block_hash_syscall_in_dispatcher() {
 
             block_hash_checker_dispatcher.write_block();
 
+            let block_number = starknet::get_block_info().unbox().block_number;
+            let expected_blk_hash = core::pedersen::pedersen('BLOCK_HASH', (block_number - 10).into());
+
             let stored_blk_hash = block_hash_checker_dispatcher.read_block_hash();
-            assert(stored_blk_hash == 0, 'Wrong stored blk hash');
+            assert(stored_blk_hash == expected_blk_hash, 'Wrong stored blk hash');
         }
     "#
         ),
@@ -321,7 +324,8 @@ fn get_block_hash() {
         fn get_block_hash() {
             let block_info = get_block_info().unbox();
             let hash = get_block_hash_syscall(block_info.block_number - 10).unwrap();
-            assert(hash == 0, 'Hash not zero');
+            let expected_hash = core::pedersen::pedersen('BLOCK_HASH', (block_info.block_number - 10).into());
+            assert(hash == expected_hash, 'Wrong block hash');
         }
     "
     ));
@@ -0,0 +1,165 @@
+use indoc::indoc;
+use std::path::Path;
+use test_utils::runner::{Contract, assert_passed};
+use test_utils::running_tests::run_test_case;
+use test_utils::test_case;
+
+#[test]
+fn transactions_through_account() {
+    let test = test_case!(
+        indoc!(
+            r#"
+            use array::ArrayTrait;
+            use starknet::ContractAddress;
+            use starknet::account::Call;
+            use snforge_std::{
+                declare, ContractClassTrait, DeclareResultTrait, invoke_transaction,
+                deploy_account_transaction, declare_transaction, TransactionParams, TransactionSigner,
+            };
+            use snforge_std::signature::KeyPairTrait;
+            use snforge_std::signature::stark_curve::StarkCurveKeyPairImpl;
+
+            #[starknet::interface]
+            trait IHelloStarknet<TContractState> {
+                fn increase_balance(ref self: TContractState, amount: felt252);
+                fn get_balance(self: @TContractState) -> felt252;
+            }
+
+            const SECRET_KEY: felt252 = 0x1234;
+
+            fn deploy_account() -> ContractAddress {
+                let key_pair = KeyPairTrait::<felt252, felt252>::from_secret_key(SECRET_KEY);
+                let class_hash = *declare("SimpleAccount").unwrap().contract_class().class_hash;
+
+                let params = TransactionParams {
+                    signer: TransactionSigner::StarkKey(SECRET_KEY), ..Default::default()
+                };
+                let (account, receipt) = deploy_account_transaction(
+                    class_hash, 'salt', array![key_pair.public_key].span(), params
+                )
+                    .unwrap();
+                assert(receipt.revert_reason.is_none(), 'Deploy account reverted');
+
+                account
+            }
+
+            fn increase_balance_calldata(contract_address: ContractAddress, amount: felt252) -> Array<felt252> {
+                let calls = array![
+                    Call {
+                        to: contract_address,
+                        selector: selector!("increase_balance"),
+                        calldata: array![amount].span(),
+                    }
+                ];
+                let mut calldata = array![];
+                calls.serialize(ref calldata);
+                calldata
+            }
+
+            #[test]
+            fn invoke_through_account() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                let params = TransactionParams {
+                    signer: TransactionSigner::StarkKey(SECRET_KEY), ..Default::default()
+                };
+                let receipt = invoke_transaction(
+                    account, increase_balance_calldata(contract_address, 5).span(), params
+                )
+                    .unwrap();
+
+                assert(receipt.revert_reason.is_none(), 'Invoke reverted');
+                assert(receipt.actual_fee == 0, 'Fee charged');
+                assert_eq!(dispatcher.get_balance(), 5);
+            }
+
+            #[test]
+            fn invalid_signature_rejected() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                let params = TransactionParams {
+                    signer: TransactionSigner::Signature(array![1, 2]), ..Default::default()
+                };
+                let reason = invoke_transaction(
+                    account, increase_balance_calldata(contract_address, 5).span(), params
+                )
+                    .unwrap_err();
+
+                assert!(reason.len() > 0);
+                assert_eq!(dispatcher.get_balance(), 0);
+            }
+
+            #[test]
+            fn invalid_nonce_rejected() {
+                let account = deploy_account();
+                let contract = declare("HelloStarknet").unwrap().contract_class();
+                let (contract_address, _) = contract.deploy(@ArrayTrait::new()).unwrap();
+                let dispatcher = IHelloStarknetDispatcher { contract_address };
+
+                let params = TransactionParams {
+                    nonce: Option::Some(1),
+                    signer: TransactionSigner::StarkKey(SECRET_KEY),
+                    ..Default::default()
+                };
+                invoke_transaction(account, increase_balance_calldata(contract_address, 5).span(), params.clone())
+                    .unwrap();
+                // Nonce was already used
+                invoke_transaction(account, increase_balance_calldata(contract_address, 5).span(), params)
+                    .unwrap_err();
+
+                assert_eq!(dispatcher.get_balance(), 5);
+            }
+
+            #[test]
+            fn reverted_execution() {
+                let account = deploy_account();
+
+                let params = TransactionParams {
+                    signer: TransactionSigner::StarkKey(SECRET_KEY), ..Default::default()
+                };
+                // Calls a non-existing contract
+                let receipt = invoke_transaction(
+                    account, increase_balance_calldata(123.try_into().unwrap(), 5).span(), params
+                )
+                    .unwrap();
+
+                assert(receipt.revert_reason.is_some(), 'Invoke not reverted');
+            }
+
+            #[test]
+            fn declare_through_account() {
+                let account = deploy_account();
+
+                let params = TransactionParams {
+                    signer: TransactionSigner::StarkKey(SECRET_KEY), ..Default::default()
+                };
+                let receipt = declare_transaction(account, "HelloStarknet", params.clone()).unwrap();
+                assert(receipt.revert_reason.is_none(), 'Declare reverted');
+
+                // Class is already declared
+                declare_transaction(account, "HelloStarknet", params).unwrap_err();
+            }
+        "#
+        ),
+        Contract::from_code_path(
+            "SimpleAccount".to_string(),
+            Path::new("tests/data/contracts/simple_account.cairo"),
+        )
+        .unwrap(),
+        Contract::from_code_path(
+            "HelloStarknet".to_string(),
+            Path::new("tests/data/contracts/hello_starknet.cairo"),
+        )
+        .unwrap()
+    );
+
+    let result = run_test_case(&test);
+
+    assert_passed(&result);
+}
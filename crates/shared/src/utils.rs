@@ -26,6 +26,17 @@ pub fn build_readable_text(data: &[Felt]) -> Option<String> {
     Some(result)
 }
 
+/// Formats felts as a list of hex values, e.g. `[0x1, 0x2]`
+#[must_use]
+pub fn format_felts(felts: &[Felt]) -> String {
+    let felts = felts
+        .iter()
+        .map(|felt| format!("{felt:#x}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("[{felts}]")
+}
+
 fn indent_string(string: &str) -> String {
     let mut modified_string = string.to_string();
     let trailing_newline = if string.ends_with('\n') {
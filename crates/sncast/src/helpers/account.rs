@@ -1,7 +1,20 @@
 use crate::NestedMap;
+use crate::helpers::nonce_manager::NonceManager;
+use crate::helpers::signer::{AccountSigner, SignatureRequest, SignerError};
 use anyhow::Result;
+use async_trait::async_trait;
 use camino::Utf8PathBuf;
+use starknet::accounts::{
+    Account, ConnectedAccount, ExecutionEncoder, ExecutionEncoding, RawDeclarationV2,
+    RawDeclarationV3, RawExecutionV1, RawExecutionV3, RawLegacyDeclaration,
+};
+use starknet::core::types::contract::ComputeClassHashError;
+use starknet::core::types::{BlockId, BlockTag, Call};
+use starknet::providers::Provider;
+use starknet::signers::SignerInteractivityContext;
+use starknet_types_core::felt::Felt;
 use std::collections::HashSet;
+use thiserror::Error;
 
 use crate::{AccountData, read_and_parse_json_file};
 
@@ -32,3 +45,208 @@ pub fn generate_account_name(accounts_file: &Utf8PathBuf) -> Result<String> {
 
     Ok(format!("account-{id}"))
 }
+
+#[derive(Error, Debug)]
+pub enum SignError {
+    #[error(transparent)]
+    Signer(#[from] SignerError),
+    #[error(transparent)]
+    ClassHash(#[from] ComputeClassHashError),
+}
+
+// Mirrors `SingleOwnerAccount` from starknet-rs, but lets `AccountSigner` decide on the signature
+// format, so that accounts with multiple or non-stark signers can be used
+#[derive(Debug, Clone)]
+pub struct SncastAccount<P> {
+    provider: P,
+    signer: AccountSigner,
+    address: Felt,
+    chain_id: Felt,
+    encoding: ExecutionEncoding,
+    block_id: BlockId,
+    nonce_manager: Option<NonceManager>,
+}
+
+impl<P> SncastAccount<P> {
+    pub fn new(
+        provider: P,
+        signer: AccountSigner,
+        address: Felt,
+        chain_id: Felt,
+        encoding: ExecutionEncoding,
+    ) -> Self {
+        Self {
+            provider,
+            signer,
+            address,
+            chain_id,
+            encoding,
+            block_id: BlockId::Tag(BlockTag::Latest),
+            nonce_manager: None,
+        }
+    }
+
+    pub fn set_block_id(&mut self, block_id: BlockId) -> &Self {
+        self.block_id = block_id;
+        self
+    }
+
+    pub fn set_nonce_manager(&mut self, nonce_manager: NonceManager) -> &Self {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    fn sign_hash(&self, transaction_hash: Felt) -> Result<Vec<Felt>, SignError> {
+        Ok(self.signer.sign(&SignatureRequest {
+            transaction_hash,
+            account_address: self.address,
+            chain_id: self.chain_id,
+        })?)
+    }
+}
+
+impl<P> ExecutionEncoder for SncastAccount<P> {
+    fn encode_calls(&self, calls: &[Call]) -> Vec<Felt> {
+        let mut execute_calldata: Vec<Felt> = vec![calls.len().into()];
+
+        match self.encoding {
+            ExecutionEncoding::Legacy => {
+                let mut concated_calldata: Vec<Felt> = vec![];
+                for call in calls {
+                    execute_calldata.push(call.to);
+                    execute_calldata.push(call.selector);
+                    execute_calldata.push(concated_calldata.len().into());
+                    execute_calldata.push(call.calldata.len().into());
+                    concated_calldata.extend_from_slice(&call.calldata);
+                }
+
+                execute_calldata.push(concated_calldata.len().into());
+                execute_calldata.extend(concated_calldata);
+            }
+            ExecutionEncoding::New => {
+                for call in calls {
+                    execute_calldata.push(call.to);
+                    execute_calldata.push(call.selector);
+                    execute_calldata.push(call.calldata.len().into());
+                    execute_calldata.extend_from_slice(&call.calldata);
+                }
+            }
+        }
+
+        execute_calldata
+    }
+}
+
+#[async_trait]
+impl<P> Account for SncastAccount<P>
+where
+    P: Provider + Sync + Send,
+{
+    type SignError = SignError;
+
+    fn address(&self) -> Felt {
+        self.address
+    }
+
+    fn chain_id(&self) -> Felt {
+        self.chain_id
+    }
+
+    async fn sign_execution_v1(
+        &self,
+        execution: &RawExecutionV1,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = execution.transaction_hash(self.chain_id, self.address, query_only, self);
+        self.sign_hash(tx_hash)
+    }
+
+    async fn sign_execution_v3(
+        &self,
+        execution: &RawExecutionV3,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = execution.transaction_hash(self.chain_id, self.address, query_only, self);
+        self.sign_hash(tx_hash)
+    }
+
+    async fn sign_declaration_v2(
+        &self,
+        declaration: &RawDeclarationV2,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = declaration.transaction_hash(self.chain_id, self.address, query_only);
+        self.sign_hash(tx_hash)
+    }
+
+    async fn sign_declaration_v3(
+        &self,
+        declaration: &RawDeclarationV3,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = declaration.transaction_hash(self.chain_id, self.address, query_only);
+        self.sign_hash(tx_hash)
+    }
+
+    async fn sign_legacy_declaration(
+        &self,
+        legacy_declaration: &RawLegacyDeclaration,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash =
+            legacy_declaration.transaction_hash(self.chain_id, self.address, query_only)?;
+        self.sign_hash(tx_hash)
+    }
+
+    fn is_signer_interactive(&self, _context: SignerInteractivityContext<'_>) -> bool {
+        self.signer.is_interactive()
+    }
+}
+
+impl<P> SncastAccount<P>
+where
+    P: Provider + Sync + Send,
+{
+    /// Reserves a nonce with the nonce manager, if it is enabled and `nonce` was not passed explicitly.
+    /// The reservation must be completed with [`SncastAccount::complete_nonce_reservation`]
+    pub async fn reserve_nonce(&self, nonce: Option<Felt>) -> Result<Option<Felt>> {
+        let Some(nonce_manager) = &self.nonce_manager else {
+            return Ok(None);
+        };
+        if nonce.is_some() {
+            return Ok(None);
+        }
+
+        let chain_nonce = self
+            .provider
+            .get_nonce(BlockId::Tag(BlockTag::Pending), self.address)
+            .await?;
+        nonce_manager.reserve(chain_nonce).map(Some)
+    }
+
+    pub fn complete_nonce_reservation(
+        &self,
+        reserved_nonce: Option<Felt>,
+        transaction_hash: Option<Felt>,
+    ) -> Result<()> {
+        match (&self.nonce_manager, reserved_nonce) {
+            (Some(nonce_manager), Some(nonce)) => nonce_manager.complete(nonce, transaction_hash),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<P> ConnectedAccount for SncastAccount<P>
+where
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+}
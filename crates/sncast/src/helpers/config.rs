@@ -111,5 +111,17 @@ pub fn combine_cast_configs(global_config: &CastConfig, local_config: &CastConfi
             default_cast_config,
             show_explorer_links
         ),
+        nonce_manager: clone_field!(
+            global_config,
+            local_config,
+            default_cast_config,
+            nonce_manager
+        ),
+        verification: clone_field!(
+            global_config,
+            local_config,
+            default_cast_config,
+            verification
+        ),
     }
 }
@@ -46,6 +46,38 @@ pub struct CastConfig {
     )]
     /// Print links pointing to pages with transaction details in the chosen block explorer
     pub show_explorer_links: bool,
+
+    #[serde(
+        default,
+        rename(serialize = "nonce-manager", deserialize = "nonce-manager")
+    )]
+    /// Allocate nonces of the account locally, so that concurrent invocations do not collide
+    pub nonce_manager: bool,
+
+    #[serde(default)]
+    /// API base urls of the verification providers used by `verify`
+    pub verification: VerificationConfig,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Default)]
+pub struct VerificationConfig {
+    #[serde(
+        default,
+        rename(serialize = "walnut-api-url", deserialize = "walnut-api-url")
+    )]
+    pub walnut_api_url: Option<String>,
+
+    #[serde(
+        default,
+        rename(serialize = "voyager-api-url", deserialize = "voyager-api-url")
+    )]
+    pub voyager_api_url: Option<String>,
+
+    #[serde(
+        default,
+        rename(serialize = "starkscan-api-url", deserialize = "starkscan-api-url")
+    )]
+    pub starkscan_api_url: Option<String>,
 }
 
 impl Default for CastConfig {
@@ -58,6 +90,8 @@ impl Default for CastConfig {
             wait_params: ValidatedWaitParams::default(),
             block_explorer: Some(block_explorer::Service::default()),
             show_explorer_links: show_explorer_links_default(),
+            nonce_manager: false,
+            verification: VerificationConfig::default(),
         }
     }
 }
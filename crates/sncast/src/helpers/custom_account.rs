@@ -0,0 +1,120 @@
+use crate::helpers::signer::{AccountSigner, SignatureRequest, SignerError};
+use async_trait::async_trait;
+use starknet::{
+    accounts::{
+        AccountFactory, PreparedAccountDeploymentV1, PreparedAccountDeploymentV3,
+        RawAccountDeploymentV1, RawAccountDeploymentV3,
+    },
+    core::{
+        types::{BlockId, BlockTag},
+        utils::get_contract_address,
+    },
+    providers::Provider,
+};
+use starknet_types_core::felt::Felt;
+
+// Factory for account contracts that are not known to sncast, deployed with a constructor
+// calldata resolved from the user provided template
+pub struct CustomAccountFactory<P> {
+    class_hash: Felt,
+    chain_id: Felt,
+    constructor_calldata: Vec<Felt>,
+    signer: AccountSigner,
+    provider: P,
+    block_id: BlockId,
+}
+
+impl<P> CustomAccountFactory<P> {
+    pub fn new(
+        class_hash: Felt,
+        chain_id: Felt,
+        constructor_calldata: Vec<Felt>,
+        signer: AccountSigner,
+        provider: P,
+    ) -> Self {
+        Self {
+            class_hash,
+            chain_id,
+            constructor_calldata,
+            signer,
+            provider,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        }
+    }
+
+    pub fn set_block_id(&mut self, block_id: BlockId) -> &Self {
+        self.block_id = block_id;
+        self
+    }
+
+    #[must_use]
+    pub fn address(&self, salt: Felt) -> Felt {
+        get_contract_address(
+            salt,
+            self.class_hash,
+            &self.constructor_calldata,
+            Felt::ZERO,
+        )
+    }
+
+    fn sign_deployment(&self, tx_hash: Felt, salt: Felt) -> Result<Vec<Felt>, SignerError> {
+        self.signer.sign(&SignatureRequest {
+            transaction_hash: tx_hash,
+            account_address: self.address(salt),
+            chain_id: self.chain_id,
+        })
+    }
+}
+
+#[async_trait]
+impl<P> AccountFactory for CustomAccountFactory<P>
+where
+    P: Provider + Sync + Send,
+{
+    type Provider = P;
+    type SignError = SignerError;
+
+    fn class_hash(&self) -> Felt {
+        self.class_hash
+    }
+
+    fn calldata(&self) -> Vec<Felt> {
+        self.constructor_calldata.clone()
+    }
+
+    fn chain_id(&self) -> Felt {
+        self.chain_id
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.provider
+    }
+
+    fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn sign_deployment_v1(
+        &self,
+        deployment: &RawAccountDeploymentV1,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = PreparedAccountDeploymentV1::from_raw(deployment.clone(), self)
+            .transaction_hash(query_only);
+        self.sign_deployment(tx_hash, deployment.salt())
+    }
+
+    async fn sign_deployment_v3(
+        &self,
+        deployment: &RawAccountDeploymentV3,
+        query_only: bool,
+    ) -> Result<Vec<Felt>, Self::SignError> {
+        let tx_hash = PreparedAccountDeploymentV3::from_raw(deployment.clone(), self)
+            .transaction_hash(query_only);
+        self.sign_deployment(tx_hash, deployment.salt())
+    }
+
+    fn is_signer_interactive(&self) -> bool {
+        self.signer.is_interactive()
+    }
+}
@@ -4,7 +4,10 @@ pub mod braavos;
 pub mod config;
 pub mod configuration;
 pub mod constants;
+pub mod custom_account;
 pub mod fee;
 pub mod interactive;
+pub mod nonce_manager;
 pub mod rpc;
 pub mod scarb_utils;
+pub mod signer;
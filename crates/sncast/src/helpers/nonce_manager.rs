@@ -0,0 +1,220 @@
+use crate::chain_id_to_network_name;
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const NONCES_DIR: &str = ".starknet_accounts/nonces";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingTransaction {
+    pub nonce: Felt,
+    /// Not set until the transaction is accepted by the node
+    pub transaction_hash: Option<Felt>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct NonceState {
+    pub pending: Vec<PendingTransaction>,
+}
+
+impl NonceState {
+    /// Forgets transactions already included in the chain and returns the next free nonce
+    fn next_nonce(&mut self, chain_nonce: Felt) -> Felt {
+        self.pending
+            .retain(|transaction| transaction.nonce >= chain_nonce);
+
+        self.pending
+            .iter()
+            .map(|transaction| transaction.nonce + Felt::ONE)
+            .max()
+            .map_or(chain_nonce, |nonce| nonce.max(chain_nonce))
+    }
+}
+
+/// Allocates sequential nonces of an account shared by concurrent `sncast` invocations.
+/// The state is kept in a file locked for the time of every update
+#[derive(Debug, Clone)]
+pub struct NonceManager {
+    state_file: Utf8PathBuf,
+}
+
+impl NonceManager {
+    pub fn new(chain_id: Felt, address: Felt) -> Result<Self> {
+        let home_dir = dirs::home_dir().context("Could not determine home directory")?;
+        let nonces_dir = Utf8PathBuf::from_path_buf(home_dir.join(NONCES_DIR))
+            .expect("Failed to convert PathBuf to Utf8PathBuf for nonces directory");
+
+        Ok(Self::in_dir(&nonces_dir, chain_id, address))
+    }
+
+    #[must_use]
+    pub fn in_dir(nonces_dir: &Utf8PathBuf, chain_id: Felt, address: Felt) -> Self {
+        Self {
+            state_file: nonces_dir
+                .join(chain_id_to_network_name(chain_id))
+                .join(format!("{address:#x}.json")),
+        }
+    }
+
+    /// Reserves the next nonce, `chain_nonce` is the nonce of the account in the pending block
+    pub fn reserve(&self, chain_nonce: Felt) -> Result<Felt> {
+        self.update(|state| {
+            let nonce = state.next_nonce(chain_nonce);
+            state.pending.push(PendingTransaction {
+                nonce,
+                transaction_hash: None,
+            });
+            nonce
+        })
+    }
+
+    /// Records the hash of the transaction sent with the reserved `nonce`,
+    /// or releases the nonce if the transaction was not accepted by the node
+    pub fn complete(&self, nonce: Felt, transaction_hash: Option<Felt>) -> Result<()> {
+        self.update(|state| match transaction_hash {
+            Some(transaction_hash) => {
+                for transaction in &mut state.pending {
+                    if transaction.nonce == nonce {
+                        transaction.transaction_hash = Some(transaction_hash);
+                    }
+                }
+            }
+            None => state
+                .pending
+                .retain(|transaction| transaction.nonce != nonce),
+        })
+    }
+
+    pub fn pending(&self, chain_nonce: Felt) -> Result<Vec<PendingTransaction>> {
+        self.update(|state| {
+            state.next_nonce(chain_nonce);
+            state.pending.clone()
+        })
+    }
+
+    /// Removes the state of the account, returns `false` if there was nothing to remove
+    pub fn reset(&self) -> Result<bool> {
+        if !self.state_file.exists() {
+            return Ok(false);
+        }
+        fs::remove_file(&self.state_file)
+            .with_context(|| format!("Failed to remove nonce state file {}", self.state_file))?;
+        Ok(true)
+    }
+
+    fn update<T>(&self, action: impl FnOnce(&mut NonceState) -> T) -> Result<T> {
+        if let Some(parent) = self.state_file.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {parent}"))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.state_file)
+            .with_context(|| format!("Failed to open nonce state file {}", self.state_file))?;
+
+        file.lock_exclusive()
+            .context("Could not lock on nonce state file")?;
+        let result = Self::update_locked(&mut file, action);
+        fs2::FileExt::unlock(&file).context("Could not unlock nonce state file")?;
+
+        result.with_context(|| format!("Failed to update nonce state file {}", self.state_file))
+    }
+
+    fn update_locked<T>(file: &mut File, action: impl FnOnce(&mut NonceState) -> T) -> Result<T> {
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+
+        // File was just created
+        let mut state: NonceState = if content.is_empty() {
+            NonceState::default()
+        } else {
+            serde_json::from_str(&content)?
+        };
+
+        let result = action(&mut state);
+
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(serde_json::to_string_pretty(&state)?.as_bytes())?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn manager(temp_dir: &TempDir) -> NonceManager {
+        let nonces_dir = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        NonceManager::in_dir(
+            &nonces_dir,
+            Felt::from_hex("0x534e5f5345504f4c4941").unwrap(),
+            Felt::ONE,
+        )
+    }
+
+    #[test]
+    fn test_reserves_sequential_nonces() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = manager(&temp_dir);
+
+        assert_eq!(manager.reserve(Felt::from(5)).unwrap(), Felt::from(5));
+        assert_eq!(manager.reserve(Felt::from(5)).unwrap(), Felt::from(6));
+        assert_eq!(manager.reserve(Felt::from(5)).unwrap(), Felt::from(7));
+    }
+
+    #[test]
+    fn test_forgets_included_transactions() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = manager(&temp_dir);
+
+        manager.reserve(Felt::from(5)).unwrap();
+        manager
+            .complete(Felt::from(5), Some(Felt::from(0x123)))
+            .unwrap();
+
+        assert_eq!(manager.reserve(Felt::from(6)).unwrap(), Felt::from(6));
+        assert_eq!(
+            manager.pending(Felt::from(6)).unwrap(),
+            vec![PendingTransaction {
+                nonce: Felt::from(6),
+                transaction_hash: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_releases_nonce_of_failed_transaction() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = manager(&temp_dir);
+
+        manager.reserve(Felt::from(5)).unwrap();
+        manager.reserve(Felt::from(5)).unwrap();
+        manager.complete(Felt::from(6), None).unwrap();
+
+        assert_eq!(manager.reserve(Felt::from(5)).unwrap(), Felt::from(6));
+    }
+
+    #[test]
+    fn test_reset() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = manager(&temp_dir);
+
+        assert!(!manager.reset().unwrap());
+        manager.reserve(Felt::from(5)).unwrap();
+        manager.reserve(Felt::from(5)).unwrap();
+        assert!(manager.reset().unwrap());
+
+        assert_eq!(manager.reserve(Felt::from(5)).unwrap(), Felt::from(5));
+    }
+}
@@ -22,6 +22,16 @@ pub struct RpcArgs {
 
 impl RpcArgs {
     pub async fn get_provider(&self, config: &CastConfig) -> Result<JsonRpcClient<HttpTransport>> {
+        let url = self.resolve_url(config)?;
+        let provider = get_provider(&url)?;
+
+        verify_and_warn_if_incompatible_rpc_version(&provider, url).await?;
+
+        Ok(provider)
+    }
+
+    /// Returns url of the RPC node, taking into account `--network` and `url` from `snfoundry.toml`
+    pub fn resolve_url(&self, config: &CastConfig) -> Result<String> {
         if self.network.is_some() && !config.url.is_empty() {
             bail!(
                 "The argument '--network' cannot be used when `url` is defined in `snfoundry.toml` for the active profile"
@@ -44,11 +54,8 @@ impl RpcArgs {
         };
 
         assert!(!url.is_empty(), "url cannot be empty");
-        let provider = get_provider(&url)?;
 
-        verify_and_warn_if_incompatible_rpc_version(&provider, url).await?;
-
-        Ok(provider)
+        Ok(url)
     }
 
     #[must_use]
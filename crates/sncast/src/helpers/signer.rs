@@ -0,0 +1,356 @@
+use anyhow::{Context, Result, anyhow, ensure};
+use p256::ecdsa::signature::hazmat::PrehashSigner;
+use serde::{Deserialize, Serialize};
+use starknet::core::crypto::EcdsaSignError;
+use starknet::signers::SigningKey;
+use starknet_types_core::felt::Felt;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use thiserror::Error;
+
+pub const DEFAULT_CONSTRUCTOR_CALLDATA_TEMPLATE: &str = "{public_key}";
+
+/// Signing scheme of an account, stored under the `signer` key in the accounts file.
+/// Accounts without it are signed with their `private_key`.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SignerConfig {
+    /// Stark keys of all signers, the signature is a concatenation of `[r, s]` pairs in the order of keys
+    Multisig { private_keys: Vec<Felt> },
+    /// Hex encoded secp256r1 key, the signature is `[r.low, r.high, s.low, s.high]`
+    Secp256r1 { private_key: String },
+    /// Program receiving a `SignatureRequest` as JSON on stdin and printing
+    /// `{"signature": [...]}` to stdout
+    External {
+        command: String,
+        #[serde(default)]
+        args: Vec<String>,
+        #[serde(default)]
+        public_keys: Vec<Felt>,
+    },
+}
+
+#[derive(Serialize, Debug)]
+pub struct SignatureRequest {
+    pub transaction_hash: Felt,
+    pub account_address: Felt,
+    pub chain_id: Felt,
+}
+
+#[derive(Deserialize, Debug)]
+struct ExternalSignerResponse {
+    signature: Vec<Felt>,
+}
+
+#[derive(Error, Debug)]
+pub enum SignerError {
+    #[error(transparent)]
+    Stark(#[from] EcdsaSignError),
+    #[error("Failed to sign with secp256r1 key: {0}")]
+    Secp256r1(String),
+    #[error("External signer failed: {0}")]
+    External(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct ExternalSigner {
+    command: String,
+    args: Vec<String>,
+    public_keys: Vec<Felt>,
+}
+
+#[derive(Debug, Clone)]
+pub enum AccountSigner {
+    Stark(Vec<SigningKey>),
+    Secp256r1(p256::ecdsa::SigningKey),
+    External(ExternalSigner),
+}
+
+impl AccountSigner {
+    pub fn new(private_key: Felt, config: Option<&SignerConfig>) -> Result<Self> {
+        match config {
+            None => Ok(Self::Stark(vec![SigningKey::from_secret_scalar(
+                private_key,
+            )])),
+            Some(SignerConfig::Multisig { private_keys }) => {
+                ensure!(
+                    !private_keys.is_empty(),
+                    "Multisig signer requires at least one private key"
+                );
+                Ok(Self::Stark(
+                    private_keys
+                        .iter()
+                        .copied()
+                        .map(SigningKey::from_secret_scalar)
+                        .collect(),
+                ))
+            }
+            Some(SignerConfig::Secp256r1 { private_key }) => {
+                let bytes = const_hex::decode(private_key)
+                    .context("Failed to decode secp256r1 private key")?;
+                let signing_key = p256::ecdsa::SigningKey::from_slice(&bytes)
+                    .map_err(|err| anyhow!("Invalid secp256r1 private key: {err}"))?;
+                Ok(Self::Secp256r1(signing_key))
+            }
+            Some(SignerConfig::External {
+                command,
+                args,
+                public_keys,
+            }) => Ok(Self::External(ExternalSigner {
+                command: command.clone(),
+                args: args.clone(),
+                public_keys: public_keys.clone(),
+            })),
+        }
+    }
+
+    pub fn sign(&self, request: &SignatureRequest) -> Result<Vec<Felt>, SignerError> {
+        match self {
+            Self::Stark(signing_keys) => {
+                let mut signature = Vec::with_capacity(signing_keys.len() * 2);
+                for signing_key in signing_keys {
+                    let stark_signature = signing_key.sign(&request.transaction_hash)?;
+                    signature.extend([stark_signature.r, stark_signature.s]);
+                }
+                Ok(signature)
+            }
+            Self::Secp256r1(signing_key) => {
+                let signature: p256::ecdsa::Signature = signing_key
+                    .sign_prehash(&request.transaction_hash.to_bytes_be())
+                    .map_err(|err| SignerError::Secp256r1(err.to_string()))?;
+                let signature = signature.normalize_s().unwrap_or(signature);
+                let (r, s) = signature.split_bytes();
+
+                Ok([split_u256(&r), split_u256(&s)].concat())
+            }
+            Self::External(external_signer) => external_signer.sign(request),
+        }
+    }
+
+    /// Public keys of all signers, each serialized the way account contracts expect it
+    #[must_use]
+    pub fn public_keys(&self) -> Vec<Vec<Felt>> {
+        match self {
+            Self::Stark(signing_keys) => signing_keys
+                .iter()
+                .map(|signing_key| vec![signing_key.verifying_key().scalar()])
+                .collect(),
+            Self::Secp256r1(signing_key) => {
+                let point = signing_key.verifying_key().to_encoded_point(false);
+                let x = point
+                    .x()
+                    .expect("Uncompressed point must have x coordinate");
+                let y = point
+                    .y()
+                    .expect("Uncompressed point must have y coordinate");
+
+                vec![[split_u256(x), split_u256(y)].concat()]
+            }
+            Self::External(external_signer) => external_signer
+                .public_keys
+                .iter()
+                .map(|public_key| vec![*public_key])
+                .collect(),
+        }
+    }
+
+    /// External signers may require user interaction, so they are not asked to sign fee estimations
+    #[must_use]
+    pub fn is_interactive(&self) -> bool {
+        matches!(self, Self::External(_))
+    }
+}
+
+impl ExternalSigner {
+    fn sign(&self, request: &SignatureRequest) -> Result<Vec<Felt>, SignerError> {
+        let mut child = Command::new(&self.command)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| {
+                SignerError::External(format!("Failed to run `{}`: {err}", self.command))
+            })?;
+
+        let input =
+            serde_json::to_string(request).map_err(|err| SignerError::External(err.to_string()))?;
+        child
+            .stdin
+            .take()
+            .expect("Failed to open signer stdin")
+            .write_all(input.as_bytes())
+            .map_err(|err| SignerError::External(err.to_string()))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|err| SignerError::External(err.to_string()))?;
+        if !output.status.success() {
+            return Err(SignerError::External(format!(
+                "`{}` exited with {}",
+                self.command, output.status
+            )));
+        }
+
+        let response: ExternalSignerResponse =
+            serde_json::from_slice(&output.stdout).map_err(|err| {
+                SignerError::External(format!("Failed to parse signer output: {err}"))
+            })?;
+
+        Ok(response.signature)
+    }
+}
+
+/// Resolves a custom account constructor calldata template. Supported placeholders are
+/// `{public_key}` (first signer public key), `{public_keys}` (all public keys serialized as an array)
+/// and `{salt}`, other items are parsed as felts
+pub fn resolve_constructor_calldata(
+    template: &[String],
+    signer: &AccountSigner,
+    salt: Felt,
+) -> Result<Vec<Felt>> {
+    let public_keys = signer.public_keys();
+    let mut calldata = vec![];
+
+    for item in template {
+        match item.as_str() {
+            "{public_key}" => calldata.extend(
+                public_keys
+                    .first()
+                    .context("Signer does not provide any public key")?,
+            ),
+            "{public_keys}" => {
+                calldata.push(Felt::from(public_keys.len()));
+                calldata.extend(public_keys.iter().flatten());
+            }
+            "{salt}" => calldata.push(salt),
+            value => calldata.push(
+                Felt::from_dec_str(value)
+                    .or_else(|_| Felt::from_hex(value))
+                    .with_context(|| {
+                        format!("Failed to parse constructor calldata template item = {value}")
+                    })?,
+            ),
+        }
+    }
+
+    Ok(calldata)
+}
+
+fn split_u256(bytes: &[u8]) -> [Felt; 2] {
+    let (high, low) = bytes.split_at(16);
+    [
+        Felt::from_bytes_be_slice(low),
+        Felt::from_bytes_be_slice(high),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountSigner, SignatureRequest, SignerConfig, resolve_constructor_calldata, split_u256,
+    };
+    use starknet::signers::SigningKey;
+    use starknet_types_core::felt::Felt;
+
+    fn signature_request() -> SignatureRequest {
+        SignatureRequest {
+            transaction_hash: Felt::from(0x1234),
+            account_address: Felt::ONE,
+            chain_id: Felt::TWO,
+        }
+    }
+
+    #[test]
+    fn test_multisig_signature_contains_all_signers() {
+        let signer = AccountSigner::new(
+            Felt::ZERO,
+            Some(&SignerConfig::Multisig {
+                private_keys: vec![Felt::from(0x111), Felt::from(0x222), Felt::from(0x333)],
+            }),
+        )
+        .unwrap();
+
+        let signature = signer.sign(&signature_request()).unwrap();
+        let expected = SigningKey::from_secret_scalar(Felt::from(0x222))
+            .sign(&Felt::from(0x1234))
+            .unwrap();
+
+        assert_eq!(signature.len(), 6);
+        assert_eq!(signature[2..4], [expected.r, expected.s]);
+    }
+
+    #[test]
+    fn test_multisig_requires_keys() {
+        let err = AccountSigner::new(
+            Felt::ZERO,
+            Some(&SignerConfig::Multisig {
+                private_keys: vec![],
+            }),
+        )
+        .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("Multisig signer requires at least one private key")
+        );
+    }
+
+    #[test]
+    fn test_secp256r1_signature_format() {
+        let signer = AccountSigner::new(
+            Felt::ZERO,
+            Some(&SignerConfig::Secp256r1 {
+                private_key: "0x1efecf7ee1e25bb87098baf2aaab0406167aae0d5ea9ba0d31404bf01886bd0e"
+                    .to_string(),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(signer.sign(&signature_request()).unwrap().len(), 4);
+        assert_eq!(signer.public_keys()[0].len(), 4);
+    }
+
+    #[test]
+    fn test_resolve_constructor_calldata() {
+        let signer = AccountSigner::new(
+            Felt::ZERO,
+            Some(&SignerConfig::External {
+                command: "signer".to_string(),
+                args: vec![],
+                public_keys: vec![Felt::from(0xa), Felt::from(0xb)],
+            }),
+        )
+        .unwrap();
+        let template = ["2", "{public_keys}", "{public_key}", "{salt}", "0x5"].map(String::from);
+
+        let calldata = resolve_constructor_calldata(&template, &signer, Felt::from(7)).unwrap();
+
+        assert_eq!(
+            calldata,
+            [2, 2, 0xa, 0xb, 0xa, 7, 5].map(Felt::from).to_vec()
+        );
+    }
+
+    #[test]
+    fn test_resolve_constructor_calldata_invalid_item() {
+        let signer = AccountSigner::new(Felt::ONE, None).unwrap();
+
+        let err = resolve_constructor_calldata(&["{owner}".to_string()], &signer, Felt::ZERO)
+            .unwrap_err();
+
+        assert!(
+            err.to_string()
+                .contains("Failed to parse constructor calldata template item = {owner}")
+        );
+    }
+
+    #[test]
+    fn test_split_u256() {
+        let mut bytes = [0_u8; 32];
+        bytes[15] = 1;
+        bytes[31] = 2;
+
+        assert_eq!(split_u256(&bytes), [Felt::TWO, Felt::ONE]);
+    }
+}
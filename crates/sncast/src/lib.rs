@@ -1,4 +1,8 @@
-use crate::helpers::constants::{DEFAULT_STATE_FILE_SUFFIX, WAIT_RETRY_INTERVAL, WAIT_TIMEOUT};
+use crate::helpers::account::SncastAccount;
+use crate::helpers::constants::{
+    DEFAULT_MANIFEST_FILE_SUFFIX, DEFAULT_STATE_FILE_SUFFIX, WAIT_RETRY_INTERVAL, WAIT_TIMEOUT,
+};
+use crate::helpers::signer::{AccountSigner, SignerConfig};
 use crate::response::errors::SNCastProviderError;
 use anyhow::{Context, Error, Result, anyhow, bail};
 use camino::Utf8PathBuf;
@@ -22,13 +26,13 @@ use starknet::core::types::{
 use starknet::core::utils::UdcUniqueness::{NotUnique, Unique};
 use starknet::core::utils::{UdcUniqueSettings, UdcUniqueness};
 use starknet::{
-    accounts::{ExecutionEncoding, SingleOwnerAccount},
+    accounts::ExecutionEncoding,
     providers::{
         Provider, ProviderError,
         ProviderError::StarknetError,
         jsonrpc::{HttpTransport, JsonRpcClient},
     },
-    signers::{LocalWallet, SigningKey},
+    signers::SigningKey,
 };
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
@@ -54,6 +58,7 @@ pub enum AccountType {
     OpenZeppelin,
     Argent,
     Braavos,
+    Custom,
 }
 
 impl FromStr for AccountType {
@@ -64,6 +69,7 @@ impl FromStr for AccountType {
             "open_zeppelin" | "oz" => Ok(AccountType::OpenZeppelin),
             "argent" => Ok(AccountType::Argent),
             "braavos" => Ok(AccountType::Braavos),
+            "custom" => Ok(AccountType::Custom),
             account_type => Err(anyhow!("Invalid account type = {account_type}")),
         }
     }
@@ -122,6 +128,12 @@ pub struct AccountData {
 
     #[serde(default, rename(serialize = "type", deserialize = "type"))]
     pub account_type: Option<AccountType>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constructor_calldata: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<SignerConfig>,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -269,7 +281,7 @@ pub async fn get_account<'a>(
     accounts_file: &Utf8PathBuf,
     provider: &'a JsonRpcClient<HttpTransport>,
     keystore: Option<Utf8PathBuf>,
-) -> Result<SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>> {
+) -> Result<SncastAccount<&'a JsonRpcClient<HttpTransport>>> {
     let chain_id = get_chain_id(provider).await?;
     let account_data = if let Some(keystore) = keystore {
         get_account_data_from_keystore(account, &keystore)?
@@ -308,8 +320,8 @@ async fn build_account(
     account_data: AccountData,
     chain_id: Felt,
     provider: &JsonRpcClient<HttpTransport>,
-) -> Result<SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>> {
-    let signer = LocalWallet::from(SigningKey::from_secret_scalar(account_data.private_key));
+) -> Result<SncastAccount<&JsonRpcClient<HttpTransport>>> {
+    let signer = AccountSigner::new(account_data.private_key, account_data.signer.as_ref())?;
 
     let address = account_data
         .address
@@ -321,8 +333,7 @@ async fn build_account(
     let account_encoding =
         get_account_encoding(account_data.legacy, class_hash, address, provider).await?;
 
-    let mut account =
-        SingleOwnerAccount::new(provider, signer, address, chain_id, account_encoding);
+    let mut account = SncastAccount::new(provider, signer, address, chain_id, account_encoding);
 
     account.set_block_id(BlockId::Tag(Pending));
 
@@ -367,6 +378,21 @@ pub async fn check_class_hash_exists(
     }
 }
 
+/// Checks whether the class is declared, propagating errors other than the class not being found
+pub async fn is_class_declared(
+    provider: &JsonRpcClient<HttpTransport>,
+    class_hash: Felt,
+) -> Result<bool> {
+    match provider
+        .get_class(BlockId::Tag(BlockTag::Latest), class_hash)
+        .await
+    {
+        Ok(_) => Ok(true),
+        Err(StarknetError(ClassHashNotFound)) => Ok(false),
+        Err(err) => Err(handle_rpc_error(err)),
+    }
+}
+
 pub fn get_account_data_from_keystore(
     account: &str,
     keystore_path: &Utf8PathBuf,
@@ -401,6 +427,7 @@ pub fn get_account_data_from_keystore(
         AccountType::Argent => parse_to_felt("/variant/owner"),
         AccountType::OpenZeppelin => parse_to_felt("/variant/public_key"),
         AccountType::Braavos => get_braavos_account_public_key(&account_info)?,
+        AccountType::Custom => bail!("Custom accounts are not supported with keystore"),
     }
     .context("Failed to get public key from account JSON file")?;
 
@@ -413,6 +440,8 @@ pub fn get_account_data_from_keystore(
         class_hash,
         legacy,
         account_type,
+        constructor_calldata: None,
+        signer: None,
     })
 }
 fn get_braavos_account_public_key(account_info: &Value) -> Result<Option<Felt>> {
@@ -736,6 +765,11 @@ pub fn get_default_state_file_name(script_name: &str, chain_id: &str) -> String
     format!("{script_name}_{chain_id}_{DEFAULT_STATE_FILE_SUFFIX}")
 }
 
+#[must_use]
+pub fn get_default_manifest_file_name(script_name: &str, chain_id: &str) -> String {
+    format!("{script_name}_{chain_id}_{DEFAULT_MANIFEST_FILE_SUFFIX}")
+}
+
 #[cfg(test)]
 mod tests {
     use crate::helpers::constants::KEYSTORE_PASSWORD_ENV_VAR;
@@ -1,37 +1,48 @@
 use crate::starknet_commands::{
-    account, account::Account, call::Call, declare::Declare, deploy::Deploy, invoke::Invoke,
-    multicall::Multicall, script::Script, show_config::ShowConfig, tx_status::TxStatus,
+    account, account::Account, call::Call, declare::Declare, deploy::Deploy, events::Events,
+    invoke::Invoke, multicall::Multicall, script::Script, show_config::ShowConfig,
+    tx_status::TxStatus,
 };
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use data_transformer::Calldata;
-use sncast::helpers::account::generate_account_name;
+use sncast::helpers::account::{SncastAccount, generate_account_name};
 use sncast::response::explorer_link::print_block_explorer_link_if_allowed;
 use sncast::response::print::{OutputFormat, print_command_result};
 use std::io;
 use std::io::IsTerminal;
 
 use crate::starknet_commands::deploy::DeployArguments;
+use crate::starknet_commands::script::simulation::{self, ScriptSimulation};
+use crate::starknet_commands::script::state::StateCommands;
 use camino::Utf8PathBuf;
 use clap::{Parser, Subcommand};
 use configuration::load_config;
+use forge_runner::CACHE_DIR;
 use sncast::helpers::config::{combine_cast_configs, get_global_config_path};
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::{DEFAULT_ACCOUNTS_FILE, DEFAULT_MULTICALL_CONTENTS};
 use sncast::helpers::interactive::prompt_to_add_account_as_default;
+use sncast::helpers::nonce_manager::NonceManager;
+use sncast::helpers::rpc::RpcArgs;
 use sncast::helpers::scarb_utils::{
     BuildConfig, assert_manifest_path_exists, build, build_and_load_artifacts,
     get_package_metadata, get_scarb_metadata_with_deps,
 };
 use sncast::response::errors::handle_starknet_command_error;
-use sncast::response::structs::DeclareResponse;
+use sncast::response::structs::{
+    DeclareResponse, DeployResponse, EventResponse, ScriptStateResponse,
+};
+use sncast::state::manifest::ManifestManager;
 use sncast::{
     NumbersFormat, ValidatedWaitParams, WaitForTx, chain_id_to_network_name, get_account,
     get_block_id, get_chain_id, get_class_hash_by_address, get_contract_class,
-    get_default_state_file_name,
+    get_default_manifest_file_name, get_default_state_file_name,
 };
+use starknet::accounts::Account as _;
 use starknet::core::types::ContractClass;
 use starknet::core::utils::get_selector_from_name;
-use starknet::providers::Provider;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
 use starknet_commands::account::list::print_account_list;
 use starknet_commands::verify::Verify;
 use starknet_types_core::felt::Felt;
@@ -150,6 +161,9 @@ enum Commands {
 
     /// Verify a contract
     Verify(Verify),
+
+    /// Watch events emitted by a contract
+    Events(Events),
 }
 
 #[derive(Debug, Clone, clap::Args)]
@@ -238,13 +252,7 @@ async fn run_async_command(
         Commands::Declare(declare) => {
             let provider = declare.rpc.get_provider(&config).await?;
 
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_with_nonce_manager(&config, &provider).await?;
             let manifest_path = assert_manifest_path_exists()?;
             let package_metadata = get_package_metadata(&manifest_path, &declare.package)?;
             let artifacts = build_and_load_artifacts(
@@ -288,37 +296,89 @@ async fn run_async_command(
 
         Commands::Deploy(deploy) => {
             let Deploy {
+                class_hash,
+                contract_name,
+                package,
                 arguments,
+                salt,
+                unique,
                 fee_args,
+                nonce,
                 rpc,
-                ..
             } = deploy;
 
             let provider = rpc.get_provider(&config).await?;
 
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_with_nonce_manager(&config, &provider).await?;
+
+            let (class_hash, contract_class, nonce) = if let Some(contract_name) = contract_name {
+                let manifest_path = assert_manifest_path_exists()?;
+                let package_metadata = get_package_metadata(&manifest_path, &package)?;
+                let artifacts = build_and_load_artifacts(
+                    &package_metadata,
+                    &BuildConfig {
+                        scarb_toml_path: manifest_path,
+                        json: cli.json,
+                        profile: cli.profile.unwrap_or("release".to_string()),
+                    },
+                    false,
+                )
+                .expect("Failed to build contract");
+
+                let local_class = match starknet_commands::deploy::declare_if_not_declared(
+                    &contract_name,
+                    fee_args.clone(),
+                    nonce,
+                    &account,
+                    &artifacts,
+                    config.wait_params,
+                )
+                .await
+                {
+                    Ok(local_class) => local_class,
+                    Err(err) => {
+                        let result: Result<DeployResponse> =
+                            Err(handle_starknet_command_error(err));
+                        print_command_result("deploy", &result, numbers_format, output_format)?;
+                        return Ok(());
+                    }
+                };
+
+                // Declaration used the passed nonce, so the deployment has to use the next one
+                let nonce = match local_class.declare_response {
+                    Some(declare_response) => {
+                        print_command_result(
+                            "declare",
+                            &Ok(declare_response),
+                            numbers_format,
+                            output_format,
+                        )?;
+                        nonce.map(|nonce| nonce + Felt::ONE)
+                    }
+                    None => nonce,
+                };
+
+                (local_class.class_hash, local_class.contract_class, nonce)
+            } else {
+                // safe to unwrap because clap requires either `--class-hash` or `--contract-name`
+                let class_hash = class_hash.unwrap();
+                let contract_class = get_contract_class(class_hash, &provider).await?;
+                (class_hash, contract_class, nonce)
+            };
 
             // safe to unwrap because "constructor" is a standardized name
             let selector = get_selector_from_name("constructor").unwrap();
 
-            let contract_class = get_contract_class(deploy.class_hash, &provider).await?;
-
             let arguments: Arguments = arguments.into();
             let calldata = arguments.try_into_calldata(contract_class, &selector)?;
 
             let result = starknet_commands::deploy::deploy(
-                deploy.class_hash,
+                class_hash,
                 &calldata,
-                deploy.salt,
-                deploy.unique,
+                salt,
+                unique,
                 fee_args,
-                deploy.nonce,
+                nonce,
                 &account,
                 wait_config,
             )
@@ -381,13 +441,7 @@ async fn run_async_command(
 
             let provider = rpc.get_provider(&config).await?;
 
-            let account = get_account(
-                &config.account,
-                &config.accounts_file,
-                &provider,
-                config.keystore,
-            )
-            .await?;
+            let account = get_account_with_nonce_manager(&config, &provider).await?;
 
             let selector = get_selector_from_name(&function)
                 .context("Failed to convert entry point selector to FieldElement")?;
@@ -442,16 +496,15 @@ async fn run_async_command(
                 starknet_commands::multicall::Commands::Run(run) => {
                     let provider = run.rpc.get_provider(&config).await?;
 
-                    let account = get_account(
-                        &config.account,
-                        &config.accounts_file,
-                        &provider,
-                        config.keystore,
+                    let account = get_account_with_nonce_manager(&config, &provider).await?;
+                    let result = starknet_commands::multicall::run::run(
+                        run.clone(),
+                        &account,
+                        wait_config,
+                        cli.json,
+                        cli.profile,
                     )
-                    .await?;
-                    let result =
-                        starknet_commands::multicall::run::run(run.clone(), &account, wait_config)
-                            .await;
+                    .await;
 
                     print_command_result("multicall run", &result, numbers_format, output_format)?;
                     print_block_explorer_link_if_allowed(
@@ -462,6 +515,31 @@ async fn run_async_command(
                         config.block_explorer,
                     );
                 }
+                starknet_commands::multicall::Commands::Validate(validate) => {
+                    let provider = validate.rpc.get_provider(&config).await?;
+
+                    let account = get_account(
+                        &config.account,
+                        &config.accounts_file,
+                        &provider,
+                        config.keystore,
+                    )
+                    .await?;
+                    let result = starknet_commands::multicall::validate::validate(
+                        validate,
+                        &account,
+                        cli.json,
+                        cli.profile,
+                    )
+                    .await;
+
+                    print_command_result(
+                        "multicall validate",
+                        &result,
+                        numbers_format,
+                        output_format,
+                    )?;
+                }
             }
             Ok(())
         }
@@ -583,6 +661,23 @@ async fn run_async_command(
                 Ok(())
             }
 
+            account::Commands::Nonce(nonce) => {
+                let provider = nonce.rpc.get_provider(&config).await?;
+
+                let account = get_account(
+                    &config.account,
+                    &config.accounts_file,
+                    &provider,
+                    config.keystore,
+                )
+                .await?;
+                let result =
+                    starknet_commands::account::nonce::nonce(&nonce, &account, &provider).await;
+
+                print_command_result("account nonce", &result, numbers_format, output_format)?;
+                Ok(())
+            }
+
             account::Commands::List(options) => print_account_list(
                 &config.accounts_file,
                 options.display_private_keys,
@@ -632,14 +727,38 @@ async fn run_async_command(
                 false,
             )
             .expect("Failed to build contract");
+
+            if verify.local {
+                let rpc = RpcArgs {
+                    url: verify.url,
+                    network: verify.network,
+                };
+                let provider = rpc.get_provider(&config).await?;
+
+                let result = starknet_commands::verify::local::verify(
+                    verify.contract_address,
+                    verify.class_hash,
+                    &verify.contract_name,
+                    &artifacts,
+                    &provider,
+                )
+                .await;
+
+                print_command_result("verify", &result, numbers_format, output_format)?;
+                return Ok(());
+            }
+
             let result = starknet_commands::verify::verify(
-                verify.contract_address,
+                verify
+                    .contract_address
+                    .context("`--contract-address` is required")?,
                 verify.contract_name,
                 verify.verifier,
-                verify.network,
+                verify.network.context("`--network` is required")?,
                 verify.confirm_verification,
                 &package_metadata.manifest_path,
                 &artifacts,
+                &config,
             )
             .await;
 
@@ -647,6 +766,30 @@ async fn run_async_command(
             Ok(())
         }
 
+        Commands::Events(events) => match events.command {
+            starknet_commands::events::Commands::Watch(watch) => {
+                let provider = watch.rpc.get_provider(&config).await?;
+
+                let result = starknet_commands::events::watch::watch(
+                    &watch,
+                    &provider,
+                    numbers_format,
+                    output_format,
+                )
+                .await;
+
+                if let Err(err) = result {
+                    print_command_result::<EventResponse>(
+                        "events watch",
+                        &Err(err),
+                        numbers_format,
+                        output_format,
+                    )?;
+                }
+                Ok(())
+            }
+        },
+
         Commands::Script(_) => unreachable!(),
     }
 }
@@ -695,7 +838,8 @@ fn run_script_command(
             let metadata_with_deps = get_scarb_metadata_with_deps(&manifest_path)?;
 
             let chain_id = runtime.block_on(get_chain_id(&provider))?;
-            let state_file_path = if run.no_state_file {
+            // Simulated transactions are not recorded in the state file
+            let state_file_path = if run.no_state_file || run.simulate {
                 None
             } else {
                 Some(package_metadata.root.join(get_default_state_file_name(
@@ -704,8 +848,29 @@ fn run_script_command(
                 )))
             };
 
+            // The manifest is written alongside the state file
+            let manifest_file_path = (!run.no_state_file && !run.simulate).then(|| {
+                package_metadata.root.join(get_default_manifest_file_name(
+                    &run.script_name,
+                    &chain_id_to_network_name(chain_id),
+                ))
+            });
+            let manifest = ManifestManager::from(manifest_file_path, chain_id)?;
+
+            let mut simulation = if run.simulate {
+                Some(ScriptSimulation::new(
+                    &run.rpc.resolve_url(&config)?,
+                    &provider,
+                    &runtime,
+                    &metadata_with_deps.workspace.root.join(CACHE_DIR),
+                )?)
+            } else {
+                None
+            };
+
             let result = starknet_commands::script::run::run(
                 &run.script_name,
+                &run.script_args,
                 &metadata_with_deps,
                 &package_metadata,
                 &mut artifacts,
@@ -713,9 +878,100 @@ fn run_script_command(
                 runtime,
                 &config,
                 state_file_path,
+                manifest,
+                simulation.as_mut(),
             );
 
+            let Some(simulation) = simulation else {
+                print_command_result("script run", &result, numbers_format, output_format)?;
+                return Ok(());
+            };
+
+            simulation::print_simulated_transactions(
+                simulation.transactions(),
+                numbers_format,
+                output_format,
+            )?;
             print_command_result("script run", &result, numbers_format, output_format)?;
+
+            if run.broadcast {
+                if !result.is_ok_and(|response| response.status == "success") {
+                    bail!(
+                        "Script did not succeed during the simulation, no transactions were broadcast"
+                    );
+                }
+
+                let runtime = Runtime::new().expect("Failed to instantiate Runtime");
+                runtime.block_on(async {
+                    let account = get_account(
+                        &config.account,
+                        &config.accounts_file,
+                        &provider,
+                        config.keystore.clone(),
+                    )
+                    .await?;
+
+                    simulation::broadcast(
+                        simulation.transactions(),
+                        &account,
+                        &artifacts,
+                        config.wait_params,
+                        numbers_format,
+                        output_format,
+                    )
+                    .await
+                })?;
+            }
+        }
+        starknet_commands::script::Commands::State(state) => {
+            let args = state.command.args();
+            let manifest_path = assert_manifest_path_exists()?;
+            let package_metadata = get_package_metadata(&manifest_path, &args.package)?;
+
+            let config = get_cast_config(cli)?;
+            let provider = runtime.block_on(args.rpc.get_provider(&config))?;
+            let chain_id = runtime.block_on(get_chain_id(&provider))?;
+
+            let state_file_path = package_metadata.root.join(get_default_state_file_name(
+                &args.script_name,
+                &chain_id_to_network_name(chain_id),
+            ));
+
+            match &state.command {
+                StateCommands::Show(_) => {
+                    let transactions = starknet_commands::script::state::show(&state_file_path)?;
+                    if transactions.is_empty() {
+                        let result = Ok(ScriptStateResponse {
+                            message: format!(
+                                "No transactions recorded in state file {state_file_path}"
+                            ),
+                        });
+                        print_command_result(
+                            "script state show",
+                            &result,
+                            numbers_format,
+                            output_format,
+                        )?;
+                    }
+                    for transaction in transactions {
+                        print_command_result(
+                            "script state show",
+                            &Ok(transaction),
+                            numbers_format,
+                            output_format,
+                        )?;
+                    }
+                }
+                StateCommands::Prune(prune) => {
+                    let result = starknet_commands::script::state::prune(prune, &state_file_path);
+                    print_command_result(
+                        "script state prune",
+                        &result,
+                        numbers_format,
+                        output_format,
+                    )?;
+                }
+            }
         }
     }
 
@@ -748,6 +1004,25 @@ fn config_with_cli(config: &mut CastConfig, cli: &Cli) {
     );
 }
 
+async fn get_account_with_nonce_manager<'a>(
+    config: &CastConfig,
+    provider: &'a JsonRpcClient<HttpTransport>,
+) -> Result<SncastAccount<&'a JsonRpcClient<HttpTransport>>> {
+    let mut account = get_account(
+        &config.account,
+        &config.accounts_file,
+        provider,
+        config.keystore.clone(),
+    )
+    .await?;
+
+    if config.nonce_manager {
+        account.set_nonce_manager(NonceManager::new(account.chain_id(), account.address())?);
+    }
+
+    Ok(account)
+}
+
 fn get_cast_config(cli: &Cli) -> Result<CastConfig> {
     let global_config_path = get_global_config_path().unwrap_or_else(|err| {
         eprintln!("Error getting global config path: {err}");
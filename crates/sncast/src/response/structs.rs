@@ -1,6 +1,7 @@
 use super::explorer_link::OutputLink;
 use crate::helpers::block_explorer;
 use crate::helpers::block_explorer::LinkProvider;
+use crate::state::state_file::ScriptTransactionStatus;
 use camino::Utf8PathBuf;
 use conversions::padded_felt::PaddedFelt;
 use conversions::serde::serialize::CairoSerialize;
@@ -8,6 +9,7 @@ use indoc::formatdoc;
 use serde::{Deserialize, Serialize, Serializer};
 use starknet_types_core::felt::Felt;
 
+#[derive(Clone)]
 pub struct Decimal(pub u64);
 
 impl Serialize for Decimal {
@@ -98,6 +100,18 @@ pub struct AccountDeleteResponse {
 
 impl CommandResponse for AccountDeleteResponse {}
 
+#[derive(Serialize)]
+pub struct AccountNonceResponse {
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub nonce: Felt,
+    #[serde(serialize_with = "crate::response::structs::serialize_as_decimal")]
+    pub next_nonce: Felt,
+    pub pending_transactions: Vec<PaddedFelt>,
+    pub message: Option<String>,
+}
+
+impl CommandResponse for AccountNonceResponse {}
+
 #[derive(Serialize)]
 pub struct MulticallNewResponse {
     pub path: Utf8PathBuf,
@@ -105,6 +119,12 @@ pub struct MulticallNewResponse {
 }
 impl CommandResponse for MulticallNewResponse {}
 
+#[derive(Serialize)]
+pub struct MulticallValidateResponse {
+    pub message: String,
+}
+impl CommandResponse for MulticallValidateResponse {}
+
 #[derive(Serialize)]
 pub struct ShowConfigResponse {
     pub profile: Option<String>,
@@ -128,6 +148,40 @@ pub struct ScriptRunResponse {
 
 impl CommandResponse for ScriptRunResponse {}
 
+#[derive(Serialize)]
+pub struct ScriptStateTransactionResponse {
+    pub id: String,
+    pub name: String,
+    pub status: ScriptTransactionStatus,
+    pub timestamp: Decimal,
+    pub class_hash: Option<PaddedFelt>,
+    pub contract_address: Option<PaddedFelt>,
+    pub transaction_hash: Option<PaddedFelt>,
+    pub error: Option<String>,
+}
+
+impl CommandResponse for ScriptStateTransactionResponse {}
+
+#[derive(Serialize)]
+pub struct ScriptStateResponse {
+    pub message: String,
+}
+
+impl CommandResponse for ScriptStateResponse {}
+
+#[derive(Serialize, Clone)]
+pub struct ScriptSimulatedTransactionResponse {
+    pub transaction: String,
+    pub index: Decimal,
+    pub status: ExecutionStatus,
+    pub class_hash: Option<PaddedFelt>,
+    pub contract_address: Option<PaddedFelt>,
+    pub estimated_fee: Option<String>,
+    pub error: Option<String>,
+}
+
+impl CommandResponse for ScriptSimulatedTransactionResponse {}
+
 #[derive(Serialize)]
 pub struct ScriptInitResponse {
     pub message: String,
@@ -143,7 +197,7 @@ pub enum FinalityStatus {
     AcceptedOnL1,
 }
 
-#[derive(Serialize, CairoSerialize)]
+#[derive(Serialize, CairoSerialize, Clone)]
 pub enum ExecutionStatus {
     Succeeded,
     Reverted,
@@ -157,6 +211,27 @@ pub struct TransactionStatusResponse {
 
 impl CommandResponse for TransactionStatusResponse {}
 
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventStatus {
+    Accepted,
+    Pending,
+    Removed,
+}
+
+#[derive(Serialize)]
+pub struct EventResponse {
+    pub status: EventStatus,
+    pub block_number: Option<Decimal>,
+    pub transaction_hash: PaddedFelt,
+    pub from_address: PaddedFelt,
+    pub name: Option<String>,
+    pub fields: Option<Vec<String>>,
+    pub keys: Option<Vec<Felt>>,
+    pub data: Option<Vec<Felt>>,
+}
+
+impl CommandResponse for EventResponse {}
+
 #[derive(Serialize)]
 pub struct VerifyResponse {
     pub message: String,
@@ -164,6 +239,15 @@ pub struct VerifyResponse {
 
 impl CommandResponse for VerifyResponse {}
 
+#[derive(Serialize)]
+pub struct LocalVerifyResponse {
+    pub message: String,
+    pub class_hash: PaddedFelt,
+    pub compiled_class_hash: PaddedFelt,
+}
+
+impl CommandResponse for LocalVerifyResponse {}
+
 impl OutputLink for InvokeResponse {
     const TITLE: &'static str = "invocation";
 
@@ -1,6 +1,6 @@
 use crate::starknet_commands::account::{
-    AccountType, add_created_profile_to_configuration, prepare_account_json,
-    write_account_to_accounts_file,
+    AccountType, CustomAccountArgs, add_created_profile_to_configuration,
+    add_custom_account_fields, prepare_account_json, write_account_to_accounts_file,
 };
 use anyhow::{Context, Result, anyhow, bail};
 use camino::Utf8PathBuf;
@@ -13,7 +13,9 @@ use sncast::helpers::constants::{
     ARGENT_CLASS_HASH, BRAAVOS_BASE_ACCOUNT_CLASS_HASH, BRAAVOS_CLASS_HASH,
     CREATE_KEYSTORE_PASSWORD_ENV_VAR, OZ_CLASS_HASH,
 };
+use sncast::helpers::custom_account::CustomAccountFactory;
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::signer::{AccountSigner, resolve_constructor_calldata};
 use sncast::response::structs::AccountCreateResponse;
 use sncast::{
     Network, check_class_hash_exists, check_if_legacy_contract, extract_or_generate_salt,
@@ -51,6 +53,9 @@ pub struct Create {
     #[clap(short, long, requires = "account_type")]
     pub class_hash: Option<Felt>,
 
+    #[clap(flatten)]
+    pub custom_account: CustomAccountArgs,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 
@@ -69,15 +74,31 @@ pub async fn create(
 ) -> Result<AccountCreateResponse> {
     let add_profile = create.add_profile.clone();
     let salt = extract_or_generate_salt(create.salt);
-    let class_hash = create.class_hash.unwrap_or(match create.account_type {
-        AccountType::Oz => OZ_CLASS_HASH,
-        AccountType::Argent => ARGENT_CLASS_HASH,
-        AccountType::Braavos => BRAAVOS_CLASS_HASH,
-    });
+    create
+        .custom_account
+        .ensure_used_with(&create.account_type)?;
+    let class_hash = match (create.class_hash, &create.account_type) {
+        (Some(class_hash), _) => class_hash,
+        (None, AccountType::Oz) => OZ_CLASS_HASH,
+        (None, AccountType::Argent) => ARGENT_CLASS_HASH,
+        (None, AccountType::Braavos) => BRAAVOS_CLASS_HASH,
+        (None, AccountType::Custom) => {
+            bail!("Argument `--class-hash` must be passed when using `--type custom`")
+        }
+    };
+    if keystore.is_some() && matches!(create.account_type, AccountType::Custom) {
+        bail!("Custom accounts are not supported with keystore");
+    }
     check_class_hash_exists(provider, class_hash).await?;
 
-    let (account_json, max_fee) =
-        generate_account(provider, salt, class_hash, &create.account_type).await?;
+    let (account_json, max_fee) = generate_account(
+        provider,
+        salt,
+        class_hash,
+        &create.account_type,
+        &create.custom_account,
+    )
+    .await?;
 
     let address: Felt = account_json["address"]
         .as_str()
@@ -168,6 +189,7 @@ async fn generate_account(
     salt: Felt,
     class_hash: Felt,
     account_type: &AccountType,
+    custom_account: &CustomAccountArgs,
 ) -> Result<(serde_json::Value, Felt)> {
     let chain_id = get_chain_id(provider).await?;
     let private_key = SigningKey::from_random();
@@ -196,11 +218,28 @@ async fn generate_account(
             .await?;
             get_address_and_deployment_fee(factory, salt).await?
         }
+        AccountType::Custom => {
+            let signer_config = custom_account.signer_config()?;
+            let signer = AccountSigner::new(private_key.secret_scalar(), signer_config.as_ref())?;
+            let constructor_calldata = resolve_constructor_calldata(
+                &custom_account.constructor_calldata_template(),
+                &signer,
+                salt,
+            )?;
+            let factory = CustomAccountFactory::new(
+                class_hash,
+                chain_id,
+                constructor_calldata,
+                signer,
+                provider,
+            );
+            get_address_and_deployment_fee(factory, salt).await?
+        }
     };
 
     let legacy = check_if_legacy_contract(Some(class_hash), address, provider).await?;
 
-    let account_json = prepare_account_json(
+    let mut account_json = prepare_account_json(
         &private_key,
         address,
         false,
@@ -209,6 +248,13 @@ async fn generate_account(
         Some(class_hash),
         Some(salt),
     );
+    if let AccountType::Custom = account_type {
+        add_custom_account_fields(
+            &mut account_json,
+            custom_account.constructor_calldata_template(),
+            custom_account.signer_config()?.as_ref(),
+        )?;
+    }
 
     Ok((account_json, fee_estimate.overall_fee))
 }
@@ -322,6 +368,7 @@ fn create_to_keystore(
                 }
             )
         }
+        AccountType::Custom => unreachable!("Custom accounts are not supported with keystore"),
     };
 
     write_account_to_file(&account_json, account_path)
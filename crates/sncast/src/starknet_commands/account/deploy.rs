@@ -5,8 +5,13 @@ use conversions::IntoConv;
 use serde_json::Map;
 use sncast::helpers::braavos::BraavosAccountFactory;
 use sncast::helpers::constants::{BRAAVOS_BASE_ACCOUNT_CLASS_HASH, KEYSTORE_PASSWORD_ENV_VAR};
+use sncast::helpers::custom_account::CustomAccountFactory;
 use sncast::helpers::fee::{FeeArgs, FeeSettings};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::signer::{
+    AccountSigner, DEFAULT_CONSTRUCTOR_CALLDATA_TEMPLATE, SignerConfig,
+    resolve_constructor_calldata,
+};
 use sncast::response::structs::InvokeResponse;
 use sncast::{
     AccountType, WaitForTx, apply_optional, chain_id_to_network_name, check_account_file_exists,
@@ -134,6 +139,8 @@ async fn deploy_from_keystore(
             chain_id,
             fee_args,
             wait_config,
+            None,
+            None,
         )
         .await?
     };
@@ -170,6 +177,8 @@ async fn deploy_from_accounts_file(
         chain_id,
         fee_args,
         wait_config,
+        account_data.constructor_calldata,
+        account_data.signer,
     )
     .await?;
 
@@ -188,6 +197,8 @@ async fn get_deployment_result(
     chain_id: Felt,
     fee_args: FeeArgs,
     wait_config: WaitForTx,
+    constructor_calldata_template: Option<Vec<String>>,
+    signer_config: Option<SignerConfig>,
 ) -> Result<InvokeResponse> {
     match account_type {
         AccountType::Argent => {
@@ -223,6 +234,22 @@ async fn get_deployment_result(
             )
             .await?;
 
+            deploy_account(factory, provider, salt, fee_args, wait_config, class_hash).await
+        }
+        AccountType::Custom => {
+            let signer = AccountSigner::new(private_key.secret_scalar(), signer_config.as_ref())?;
+            let constructor_calldata_template = constructor_calldata_template
+                .unwrap_or_else(|| vec![DEFAULT_CONSTRUCTOR_CALLDATA_TEMPLATE.to_string()]);
+            let constructor_calldata =
+                resolve_constructor_calldata(&constructor_calldata_template, &signer, salt)?;
+            let factory = CustomAccountFactory::new(
+                class_hash,
+                chain_id,
+                constructor_calldata,
+                signer,
+                provider,
+            );
+
             deploy_account(factory, provider, salt, fee_args, wait_config, class_hash).await
         }
     }
@@ -268,6 +295,9 @@ where
             )),
             _ => Err(handle_rpc_error(error)),
         },
+        Err(AccountFactoryError::Signing(error)) => {
+            Err(anyhow!("Failed to sign account deployment: {error}"))
+        }
         Err(_) => Err(anyhow!("Unknown AccountFactoryError")),
         Ok(result) => {
             let return_value = InvokeResponse {
@@ -355,5 +385,8 @@ pub(crate) fn compute_account_address(
             &[private_key.verifying_key().scalar()],
             chain_id,
         ),
+        AccountType::Custom => {
+            unreachable!("Address of a custom account depends on its constructor calldata")
+        }
     }
 }
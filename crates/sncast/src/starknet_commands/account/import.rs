@@ -1,7 +1,7 @@
 use super::deploy::compute_account_address;
 use crate::starknet_commands::account::{
-    AccountType, add_created_profile_to_configuration, prepare_account_json,
-    write_account_to_accounts_file,
+    AccountType, CustomAccountArgs, add_created_profile_to_configuration,
+    add_custom_account_fields, prepare_account_json, write_account_to_accounts_file,
 };
 use anyhow::{Context, Result, bail, ensure};
 use camino::Utf8PathBuf;
@@ -12,11 +12,13 @@ use sncast::check_if_legacy_contract;
 use sncast::helpers::account::generate_account_name;
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::rpc::RpcArgs;
+use sncast::helpers::signer::{AccountSigner, resolve_constructor_calldata};
 use sncast::response::structs::AccountImportResponse;
 use sncast::{
     AccountType as SNCastAccountType, check_class_hash_exists, get_chain_id, handle_rpc_error,
 };
 use starknet::core::types::{BlockId, BlockTag, StarknetError};
+use starknet::core::utils::get_contract_address;
 use starknet::providers::jsonrpc::{HttpTransport, JsonRpcClient};
 use starknet::providers::{Provider, ProviderError};
 use starknet::signers::SigningKey;
@@ -57,6 +59,9 @@ pub struct Import {
     #[clap(long, conflicts_with = "network")]
     pub add_profile: Option<String>,
 
+    #[clap(flatten)]
+    pub custom_account: CustomAccountArgs,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
 
@@ -71,6 +76,11 @@ pub async fn import(
     provider: &JsonRpcClient<HttpTransport>,
     import: &Import,
 ) -> Result<AccountImportResponse> {
+    import
+        .custom_account
+        .ensure_used_with(&import.account_type)?;
+    let signer_config = import.custom_account.signer_config()?;
+
     let private_key = if let Some(passed_private_key) = &import.private_key {
         passed_private_key
     } else if let Some(passed_private_key_file_path) = &import.private_key_file_path {
@@ -124,12 +134,22 @@ pub async fn import(
     if let Some(salt) = import.salt {
         // TODO(#2571)
         let sncast_account_type = match import.account_type {
-            AccountType::Argent => SNCastAccountType::Argent,
-            AccountType::Braavos => SNCastAccountType::Braavos,
-            AccountType::Oz => SNCastAccountType::OpenZeppelin,
+            AccountType::Argent => Some(SNCastAccountType::Argent),
+            AccountType::Braavos => Some(SNCastAccountType::Braavos),
+            AccountType::Oz => Some(SNCastAccountType::OpenZeppelin),
+            AccountType::Custom => None,
+        };
+        let computed_address = if let Some(sncast_account_type) = sncast_account_type {
+            compute_account_address(salt, private_key, class_hash, sncast_account_type, chain_id)
+        } else {
+            let signer = AccountSigner::new(private_key.secret_scalar(), signer_config.as_ref())?;
+            let constructor_calldata = resolve_constructor_calldata(
+                &import.custom_account.constructor_calldata_template(),
+                &signer,
+                salt,
+            )?;
+            get_contract_address(salt, class_hash, &constructor_calldata, Felt::ZERO)
         };
-        let computed_address =
-            compute_account_address(salt, private_key, class_hash, sncast_account_type, chain_id);
         ensure!(
             computed_address == import.address,
             "Computed address {:#x} does not match the provided address {:#x}. Please ensure that the provided salt, class hash, and account type are correct.",
@@ -140,7 +160,7 @@ pub async fn import(
 
     let legacy = check_if_legacy_contract(Some(class_hash), import.address, provider).await?;
 
-    let account_json = prepare_account_json(
+    let mut account_json = prepare_account_json(
         private_key,
         import.address,
         deployed,
@@ -149,6 +169,13 @@ pub async fn import(
         Some(class_hash),
         import.salt,
     );
+    if let AccountType::Custom = import.account_type {
+        add_custom_account_fields(
+            &mut account_json,
+            import.custom_account.constructor_calldata_template(),
+            signer_config.as_ref(),
+        )?;
+    }
 
     write_account_to_accounts_file(&account_name, accounts_file, chain_id, account_json.clone())?;
 
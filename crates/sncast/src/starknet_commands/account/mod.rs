@@ -3,6 +3,7 @@ use crate::starknet_commands::account::delete::Delete;
 use crate::starknet_commands::account::deploy::Deploy;
 use crate::starknet_commands::account::import::Import;
 use crate::starknet_commands::account::list::List;
+use crate::starknet_commands::account::nonce::Nonce;
 use anyhow::{Context, Result, anyhow, bail};
 use camino::Utf8PathBuf;
 use clap::{Args, Subcommand, ValueEnum};
@@ -10,7 +11,11 @@ use configuration::{
     CONFIG_FILENAME, find_config_file, load_config, search_config_upwards_relative_to,
 };
 use serde_json::json;
-use sncast::{chain_id_to_network_name, decode_chain_id, helpers::configuration::CastConfig};
+use sncast::helpers::signer::{DEFAULT_CONSTRUCTOR_CALLDATA_TEMPLATE, SignerConfig};
+use sncast::{
+    chain_id_to_network_name, decode_chain_id, helpers::configuration::CastConfig,
+    read_and_parse_json_file,
+};
 use starknet::signers::SigningKey;
 use starknet_types_core::felt::Felt;
 use std::{fmt, fs::OpenOptions, io::Write};
@@ -21,6 +26,7 @@ pub mod delete;
 pub mod deploy;
 pub mod import;
 pub mod list;
+pub mod nonce;
 
 #[derive(Args)]
 #[command(about = "Creates and deploys an account to the Starknet")]
@@ -36,6 +42,7 @@ pub enum Commands {
     Deploy(Deploy),
     Delete(Delete),
     List(List),
+    Nonce(Nonce),
 }
 
 #[expect(clippy::doc_markdown)]
@@ -47,6 +54,8 @@ pub enum AccountType {
     Argent,
     /// Braavos account implementation
     Braavos,
+    /// Custom account implementation, requires `--class-hash`
+    Custom,
 }
 
 impl fmt::Display for AccountType {
@@ -55,10 +64,64 @@ impl fmt::Display for AccountType {
             AccountType::Oz => write!(f, "open_zeppelin"),
             AccountType::Argent => write!(f, "argent"),
             AccountType::Braavos => write!(f, "braavos"),
+            AccountType::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct CustomAccountArgs {
+    /// Constructor calldata template of a custom account. Supports `{public_key}`, `{public_keys}`
+    /// and `{salt}` placeholders, defaults to `{public_key}`
+    #[clap(long, value_delimiter = ' ', num_args = 1..)]
+    pub constructor_calldata_template: Option<Vec<String>>,
+
+    /// Path to the JSON file with a signer configuration of a custom account
+    #[clap(long)]
+    pub signer_config: Option<Utf8PathBuf>,
+}
+
+impl CustomAccountArgs {
+    #[must_use]
+    pub fn constructor_calldata_template(&self) -> Vec<String> {
+        self.constructor_calldata_template
+            .clone()
+            .unwrap_or_else(|| vec![DEFAULT_CONSTRUCTOR_CALLDATA_TEMPLATE.to_string()])
+    }
+
+    pub fn signer_config(&self) -> Result<Option<SignerConfig>> {
+        self.signer_config
+            .as_ref()
+            .map(read_and_parse_json_file)
+            .transpose()
+            .context("Failed to load signer config")
+    }
+
+    pub fn ensure_used_with(&self, account_type: &AccountType) -> Result<()> {
+        if !matches!(account_type, AccountType::Custom)
+            && (self.constructor_calldata_template.is_some() || self.signer_config.is_some())
+        {
+            bail!(
+                "Arguments `--constructor-calldata-template` and `--signer-config` can only be used with `--type custom`"
+            );
         }
+        Ok(())
     }
 }
 
+pub fn add_custom_account_fields(
+    account_json: &mut serde_json::Value,
+    constructor_calldata_template: Vec<String>,
+    signer_config: Option<&SignerConfig>,
+) -> Result<()> {
+    account_json["constructor_calldata"] = json!(constructor_calldata_template);
+    if let Some(signer_config) = signer_config {
+        account_json["signer"] =
+            serde_json::to_value(signer_config).context("Failed to serialize signer config")?;
+    }
+    Ok(())
+}
+
 pub fn prepare_account_json(
     private_key: &SigningKey,
     address: Felt,
@@ -0,0 +1,64 @@
+use anyhow::Result;
+use clap::Args;
+use conversions::IntoConv;
+use sncast::handle_rpc_error;
+use sncast::helpers::account::SncastAccount;
+use sncast::helpers::nonce_manager::NonceManager;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::AccountNonceResponse;
+use starknet::accounts::Account;
+use starknet::core::types::{BlockId, BlockTag};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_types_core::felt::Felt;
+
+#[derive(Args, Debug)]
+#[command(about = "Show or reset nonces of an account tracked by the local nonce manager")]
+pub struct Nonce {
+    /// Forget all transactions tracked by the nonce manager, e.g. when some of them were rejected
+    #[clap(long)]
+    pub reset: bool,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn nonce(
+    nonce: &Nonce,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<AccountNonceResponse> {
+    let nonce_manager = NonceManager::new(account.chain_id(), account.address())?;
+    let chain_nonce = provider
+        .get_nonce(BlockId::Tag(BlockTag::Pending), account.address())
+        .await
+        .map_err(handle_rpc_error)?;
+
+    let message = if nonce.reset {
+        Some(if nonce_manager.reset()? {
+            "Nonces tracked by the nonce manager have been reset".to_string()
+        } else {
+            "No nonces are tracked by the nonce manager".to_string()
+        })
+    } else {
+        None
+    };
+
+    let pending = nonce_manager.pending(chain_nonce)?;
+    let next_nonce = pending
+        .iter()
+        .map(|transaction| transaction.nonce + Felt::ONE)
+        .max()
+        .unwrap_or(chain_nonce);
+
+    Ok(AccountNonceResponse {
+        nonce: chain_nonce,
+        next_nonce,
+        pending_transactions: pending
+            .into_iter()
+            .filter_map(|transaction| transaction.transaction_hash)
+            .map(IntoConv::into_)
+            .collect(),
+        message,
+    })
+}
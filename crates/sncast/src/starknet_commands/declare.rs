@@ -3,6 +3,7 @@ use clap::Args;
 use conversions::IntoConv;
 use conversions::byte_array::ByteArray;
 use scarb_api::StarknetContractArtifacts;
+use sncast::helpers::account::SncastAccount;
 use sncast::helpers::fee::{FeeArgs, FeeSettings};
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
@@ -15,10 +16,9 @@ use starknet::accounts::{ConnectedAccount, DeclarationV3};
 use starknet::core::types::{DeclareTransactionResult, StarknetError};
 use starknet::providers::ProviderError;
 use starknet::{
-    accounts::{Account, SingleOwnerAccount},
+    accounts::Account,
     core::types::contract::{CompiledClass, SierraClass},
     providers::jsonrpc::{HttpTransport, JsonRpcClient},
-    signers::LocalWallet,
 };
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
@@ -48,7 +48,7 @@ pub struct Declare {
 
 pub async fn declare(
     declare: Declare,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
     artifacts: &HashMap<String, StarknetContractArtifacts>,
     wait_config: WaitForTx,
     skip_on_already_declared: bool,
@@ -97,9 +97,18 @@ pub async fn declare(
         max_gas_unit_price.map(std::num::NonZero::get),
         DeclarationV3::gas_price,
     );
-    let declaration = apply_optional(declaration, declare.nonce, DeclarationV3::nonce);
+    let reserved_nonce = account.reserve_nonce(declare.nonce).await?;
+    let declaration = apply_optional(
+        declaration,
+        declare.nonce.or(reserved_nonce),
+        DeclarationV3::nonce,
+    );
 
     let declared = declaration.send().await;
+    account.complete_nonce_reservation(
+        reserved_nonce,
+        declared.as_ref().ok().map(|result| result.transaction_hash),
+    )?;
 
     match declared {
         Ok(DeclareTransactionResult {
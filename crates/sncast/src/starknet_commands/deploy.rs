@@ -1,27 +1,46 @@
-use anyhow::{Result, anyhow};
+use crate::starknet_commands::declare::{Declare, declare};
+use anyhow::{Context, Result, anyhow};
 use clap::Args;
 use conversions::IntoConv;
+use conversions::byte_array::ByteArray;
+use scarb_api::StarknetContractArtifacts;
+use sncast::helpers::account::SncastAccount;
 use sncast::helpers::fee::{FeeArgs, FeeSettings};
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
-use sncast::response::structs::DeployResponse;
-use sncast::{WaitForTx, handle_wait_for_tx};
+use sncast::response::structs::{DeclareResponse, DeclareTransactionResponse, DeployResponse};
+use sncast::{ErrorData, ValidatedWaitParams, WaitForTx, handle_wait_for_tx, is_class_declared};
 use sncast::{extract_or_generate_salt, udc_uniqueness};
 use starknet::accounts::AccountError::Provider;
-use starknet::accounts::{Account, ConnectedAccount, SingleOwnerAccount};
+use starknet::accounts::{Account, ConnectedAccount};
 use starknet::contract::ContractFactory;
+use starknet::core::types::ContractClass;
+use starknet::core::types::contract::SierraClass;
 use starknet::core::utils::get_udc_deployed_address;
 use starknet::providers::JsonRpcClient;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::signers::LocalWallet;
 use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
 
 #[derive(Args)]
 #[command(about = "Deploy a contract on Starknet")]
 pub struct Deploy {
     /// Class hash of contract to deploy
-    #[clap(short = 'g', long)]
-    pub class_hash: Felt,
+    #[clap(
+        short = 'g',
+        long,
+        required_unless_present = "contract_name",
+        conflicts_with = "contract_name"
+    )]
+    pub class_hash: Option<Felt>,
+
+    /// Name of the contract from the Scarb project to deploy; the contract is declared first if its class is not declared yet
+    #[clap(long)]
+    pub contract_name: Option<String>,
+
+    /// Specifies scarb package to be used; can only be used with `--contract-name`
+    #[clap(long, requires = "contract_name")]
+    pub package: Option<String>,
 
     #[clap(flatten)]
     pub arguments: DeployArguments,
@@ -57,6 +76,83 @@ pub struct DeployArguments {
     pub arguments: Option<String>,
 }
 
+pub struct LocalClass {
+    pub class_hash: Felt,
+    pub contract_class: ContractClass,
+    pub declare_response: Option<DeclareTransactionResponse>,
+}
+
+/// Loads the class hash and the class of the contract from the Scarb project artifacts
+pub fn load_local_class(
+    contract_name: &str,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+) -> Result<(Felt, ContractClass), StarknetCommandError> {
+    let contract_artifacts =
+        artifacts
+            .get(contract_name)
+            .ok_or(StarknetCommandError::ContractArtifactsNotFound(ErrorData {
+                data: ByteArray::from(contract_name),
+            }))?;
+
+    let sierra_class: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
+        .context("Failed to parse sierra artifact")?;
+    let class_hash = sierra_class.class_hash().map_err(anyhow::Error::from)?;
+    let contract_class =
+        ContractClass::Sierra(sierra_class.flatten().map_err(anyhow::Error::from)?);
+
+    Ok((class_hash, contract_class))
+}
+
+/// Loads the class of the contract from the Scarb project artifacts and declares it,
+/// waiting for the declaration to be accepted, unless the class is already declared
+pub async fn declare_if_not_declared(
+    contract_name: &str,
+    fee_args: FeeArgs,
+    nonce: Option<Felt>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+    wait_params: ValidatedWaitParams,
+) -> Result<LocalClass, StarknetCommandError> {
+    let (class_hash, contract_class) = load_local_class(contract_name, artifacts)?;
+
+    if is_class_declared(account.provider(), class_hash).await? {
+        return Ok(LocalClass {
+            class_hash,
+            contract_class,
+            declare_response: None,
+        });
+    }
+
+    let declare_command = Declare {
+        contract: contract_name.to_string(),
+        fee_args,
+        nonce,
+        package: None,
+        rpc: RpcArgs::default(),
+    };
+    let declare_response = match declare(
+        declare_command,
+        account,
+        artifacts,
+        WaitForTx {
+            wait: true,
+            wait_params,
+        },
+        true,
+    )
+    .await?
+    {
+        DeclareResponse::Success(response) => Some(response),
+        DeclareResponse::AlreadyDeclared(_) => None,
+    };
+
+    Ok(LocalClass {
+        class_hash,
+        contract_class,
+        declare_response,
+    })
+}
+
 #[expect(clippy::ptr_arg, clippy::too_many_arguments)]
 pub async fn deploy(
     class_hash: Felt,
@@ -65,7 +161,7 @@ pub async fn deploy(
     unique: bool,
     fee_args: FeeArgs,
     nonce: Option<Felt>,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
     wait_config: WaitForTx,
 ) -> Result<DeployResponse, StarknetCommandError> {
     let fee_settings = fee_args
@@ -89,11 +185,16 @@ pub async fn deploy(
         None => execution,
         Some(max_gas_unit_price) => execution.gas_price(max_gas_unit_price.into()),
     };
-    let execution = match nonce {
+    let reserved_nonce = account.reserve_nonce(nonce).await?;
+    let execution = match nonce.or(reserved_nonce) {
         None => execution,
         Some(nonce) => execution.nonce(nonce),
     };
     let result = execution.send().await;
+    account.complete_nonce_reservation(
+        reserved_nonce,
+        result.as_ref().ok().map(|result| result.transaction_hash),
+    )?;
 
     match result {
         Ok(result) => handle_wait_for_tx(
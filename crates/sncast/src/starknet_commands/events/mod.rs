@@ -0,0 +1,17 @@
+use clap::{Args, Subcommand};
+
+pub mod watch;
+
+use watch::Watch;
+
+#[derive(Args)]
+#[command(about = "Inspect events emitted by contracts", long_about = None)]
+pub struct Events {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Commands {
+    Watch(Watch),
+}
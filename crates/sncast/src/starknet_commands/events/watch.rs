@@ -0,0 +1,264 @@
+use anyhow::Result;
+use clap::Args;
+use conversions::IntoConv;
+use data_transformer::{AbiDecoder, DecodedEvent};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::print::{OutputFormat, print_command_result};
+use sncast::response::structs::{Decimal, EventResponse, EventStatus};
+use sncast::{NumbersFormat, get_class_hash_by_address, get_contract_class, handle_rpc_error};
+use starknet::core::types::{BlockId, BlockTag, EmittedEvent, EventFilter};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_types_core::felt::Felt;
+use std::time::Duration;
+
+#[derive(Args, Debug)]
+#[command(about = "Watch events emitted by a contract, following new blocks")]
+pub struct Watch {
+    /// Address of the contract emitting events
+    #[clap(long)]
+    pub address: Felt,
+
+    /// Name of the event to display, either a full path or just the name of the event type;
+    /// can be passed multiple times. If not passed, all events are displayed
+    #[clap(long = "event")]
+    pub events: Vec<String>,
+
+    /// Number of the block to start watching from; defaults to the latest block
+    #[clap(long)]
+    pub from_block: Option<u64>,
+
+    /// Number of the block to stop watching at; if not passed, new blocks are followed until interrupted
+    #[clap(long)]
+    pub to_block: Option<u64>,
+
+    /// If passed, events from the pending block are displayed as well
+    #[clap(long)]
+    pub pending: bool,
+
+    /// Maximum number of events fetched in a single request
+    #[clap(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    pub chunk_size: u64,
+
+    /// Time in seconds between consecutive polls for new blocks
+    #[clap(long, default_value_t = 5)]
+    pub poll_interval: u64,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+struct WatchedEvent {
+    event: EmittedEvent,
+    /// Index of the event among events emitted by the contract in the same transaction
+    index: usize,
+    decoded: Option<DecodedEvent>,
+}
+
+impl WatchedEvent {
+    // Pending events have no block hash and number, so only the transaction, position and contents are compared
+    fn is_same_as(&self, other: &Self) -> bool {
+        self.event.transaction_hash == other.event.transaction_hash
+            && self.index == other.index
+            && self.event.keys == other.event.keys
+            && self.event.data == other.event.data
+    }
+
+    fn to_response(&self, status: EventStatus) -> EventResponse {
+        let (name, fields, keys, data) = match &self.decoded {
+            Some(decoded) => (
+                Some(decoded.name.clone()),
+                Some(
+                    decoded
+                        .fields
+                        .iter()
+                        .map(|(name, value)| format!("{name}: {value}"))
+                        .collect(),
+                ),
+                None,
+                None,
+            ),
+            None => (
+                None,
+                None,
+                Some(self.event.keys.clone()),
+                Some(self.event.data.clone()),
+            ),
+        };
+
+        EventResponse {
+            status,
+            block_number: self.event.block_number.map(Decimal),
+            transaction_hash: self.event.transaction_hash.into_(),
+            from_address: self.event.from_address.into_(),
+            name,
+            fields,
+            keys,
+            data,
+        }
+    }
+}
+
+pub async fn watch(
+    watch: &Watch,
+    provider: &JsonRpcClient<HttpTransport>,
+    numbers_format: NumbersFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let class_hash = get_class_hash_by_address(provider, watch.address).await?;
+    let contract_class = get_contract_class(class_hash, provider).await?;
+    let decoder = AbiDecoder::new(&contract_class)?;
+
+    let print_event = |event: &WatchedEvent, status: EventStatus| {
+        print_command_result(
+            "events watch",
+            &Ok(event.to_response(status)),
+            numbers_format,
+            output_format,
+        )
+    };
+
+    let mut next_block = match watch.from_block {
+        Some(from_block) => from_block,
+        None => provider.block_number().await.map_err(handle_rpc_error)?,
+    };
+    let mut shown_pending: Vec<WatchedEvent> = vec![];
+
+    loop {
+        let latest_block = provider.block_number().await.map_err(handle_rpc_error)?;
+        let last_block = watch
+            .to_block
+            .map_or(latest_block, |to_block| to_block.min(latest_block));
+
+        if next_block <= last_block {
+            let accepted = fetch_events(
+                provider,
+                watch,
+                &decoder,
+                BlockId::Number(next_block),
+                BlockId::Number(last_block),
+            )
+            .await?;
+            next_block = last_block + 1;
+
+            for event in accepted {
+                // Events already displayed from the pending block are not displayed again
+                if let Some(position) = shown_pending
+                    .iter()
+                    .position(|shown| shown.is_same_as(&event))
+                {
+                    shown_pending.swap_remove(position);
+                    continue;
+                }
+                print_event(&event, EventStatus::Accepted)?;
+            }
+        }
+
+        if watch.to_block.is_some_and(|to_block| next_block > to_block) {
+            return Ok(());
+        }
+
+        if watch.pending {
+            let pending = fetch_events(
+                provider,
+                watch,
+                &decoder,
+                BlockId::Tag(BlockTag::Pending),
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await?;
+
+            let (still_pending, missing): (Vec<_>, Vec<_>) = std::mem::take(&mut shown_pending)
+                .into_iter()
+                .partition(|shown| pending.iter().any(|event| event.is_same_as(shown)));
+            shown_pending = still_pending;
+
+            if !missing.is_empty() {
+                // Pending block may have been accepted since the accepted blocks were fetched,
+                // events missing from both were dropped when the pending block was replaced
+                let newly_accepted = fetch_events(
+                    provider,
+                    watch,
+                    &decoder,
+                    BlockId::Number(next_block),
+                    BlockId::Tag(BlockTag::Latest),
+                )
+                .await?;
+
+                for event in missing {
+                    if newly_accepted
+                        .iter()
+                        .any(|accepted| accepted.is_same_as(&event))
+                    {
+                        // Not displayed again when fetched from the accepted block in the next poll
+                        shown_pending.push(event);
+                    } else {
+                        print_event(&event, EventStatus::Removed)?;
+                    }
+                }
+            }
+
+            for event in pending {
+                if !shown_pending.iter().any(|shown| shown.is_same_as(&event)) {
+                    print_event(&event, EventStatus::Pending)?;
+                    shown_pending.push(event);
+                }
+            }
+        }
+
+        tokio::time::sleep(Duration::from_secs(watch.poll_interval)).await;
+    }
+}
+
+async fn fetch_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    watch: &Watch,
+    decoder: &AbiDecoder,
+    from_block: BlockId,
+    to_block: BlockId,
+) -> Result<Vec<WatchedEvent>> {
+    let filter = EventFilter {
+        from_block: Some(from_block),
+        to_block: Some(to_block),
+        address: Some(watch.address),
+        keys: None,
+    };
+
+    let mut events = vec![];
+    let mut continuation_token = None;
+    let mut last_transaction: Option<(Felt, usize)> = None;
+
+    loop {
+        let page = provider
+            .get_events(filter.clone(), continuation_token, watch.chunk_size)
+            .await
+            .map_err(handle_rpc_error)?;
+
+        events.extend(page.events.into_iter().filter_map(|event| {
+            let index = match last_transaction {
+                Some((transaction_hash, index)) if transaction_hash == event.transaction_hash => {
+                    index + 1
+                }
+                _ => 0,
+            };
+            last_transaction = Some((event.transaction_hash, index));
+
+            let decoded = decoder.decode_event(&event.keys, &event.data).ok();
+            let is_watched = watch.events.is_empty()
+                || decoded
+                    .as_ref()
+                    .is_some_and(|decoded| watch.events.iter().any(|name| decoded.is_named(name)));
+
+            is_watched.then_some(WatchedEvent {
+                event,
+                index,
+                decoded,
+            })
+        }));
+
+        continuation_token = page.continuation_token;
+        if continuation_token.is_none() {
+            return Ok(events);
+        }
+    }
+}
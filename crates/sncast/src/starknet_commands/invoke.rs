@@ -2,17 +2,17 @@ use crate::Arguments;
 use anyhow::{Result, anyhow};
 use clap::Args;
 use conversions::IntoConv;
+use sncast::helpers::account::SncastAccount;
 use sncast::helpers::fee::{FeeArgs, FeeSettings};
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::StarknetCommandError;
 use sncast::response::structs::InvokeResponse;
 use sncast::{WaitForTx, apply_optional, handle_wait_for_tx};
 use starknet::accounts::AccountError::Provider;
-use starknet::accounts::{Account, ConnectedAccount, ExecutionV3, SingleOwnerAccount};
+use starknet::accounts::{Account, ConnectedAccount, ExecutionV3};
 use starknet::core::types::{Call, InvokeTransactionResult};
 use starknet::providers::JsonRpcClient;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::signers::LocalWallet;
 use starknet_types_core::felt::Felt;
 
 #[derive(Args, Clone, Debug)]
@@ -46,7 +46,7 @@ pub async fn invoke(
     nonce: Option<Felt>,
     fee_args: FeeArgs,
     function_selector: Felt,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
     wait_config: WaitForTx,
 ) -> Result<InvokeResponse, StarknetCommandError> {
     let call = Call {
@@ -59,7 +59,7 @@ pub async fn invoke(
 }
 
 pub async fn execute_calls(
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
     calls: Vec<Call>,
     fee_args: FeeArgs,
     nonce: Option<Felt>,
@@ -85,8 +85,13 @@ pub async fn execute_calls(
         max_gas_unit_price.map(std::num::NonZero::get),
         ExecutionV3::gas_price,
     );
-    let execution = apply_optional(execution, nonce, ExecutionV3::nonce);
+    let reserved_nonce = account.reserve_nonce(nonce).await?;
+    let execution = apply_optional(execution, nonce.or(reserved_nonce), ExecutionV3::nonce);
     let result = execution.send().await;
+    account.complete_nonce_reservation(
+        reserved_nonce,
+        result.as_ref().ok().map(|result| result.transaction_hash),
+    )?;
 
     match result {
         Ok(InvokeTransactionResult { transaction_hash }) => handle_wait_for_tx(
@@ -2,6 +2,7 @@ pub mod account;
 pub mod call;
 pub mod declare;
 pub mod deploy;
+pub mod events;
 pub mod invoke;
 pub mod multicall;
 pub mod script;
@@ -0,0 +1,508 @@
+use crate::starknet_commands::deploy::{declare_if_not_declared, load_local_class};
+use anyhow::{Context, Result, bail};
+use camino::Utf8PathBuf;
+use data_transformer::Calldata;
+use regex::Regex;
+use scarb_api::StarknetContractArtifacts;
+use serde::Deserialize;
+use sncast::helpers::account::SncastAccount;
+use sncast::helpers::constants::UDC_ADDRESS;
+use sncast::helpers::fee::FeeArgs;
+use sncast::helpers::scarb_utils::{
+    BuildConfig, assert_manifest_path_exists, build_and_load_artifacts, get_package_metadata,
+};
+use sncast::response::errors::handle_starknet_command_error;
+use sncast::{
+    ValidatedWaitParams, extract_or_generate_salt, get_class_hash_by_address, get_contract_class,
+    udc_uniqueness,
+};
+use starknet::accounts::{Account, ConnectedAccount};
+use starknet::core::types::contract::AbiEntry;
+use starknet::core::types::{Call, ContractClass};
+use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
+use starknet::providers::JsonRpcClient;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+// String and short string literals are matched as well, so references inside them are skipped
+static REFERENCE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#""(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*'|@[\w-]+(\.\w+)?"#)
+        .expect("Invalid reference regex")
+});
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum Input {
+    String(String),
+    Number(i64),
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeclareCall {
+    contract_name: String,
+    id: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DeployCall {
+    class_hash: String,
+    #[serde(default)]
+    inputs: Vec<Input>,
+    arguments: Option<String>,
+    #[serde(default)]
+    unique: bool,
+    salt: Option<Felt>,
+    id: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct InvokeCall {
+    contract_address: String,
+    function: String,
+    #[serde(default)]
+    inputs: Vec<Input>,
+    arguments: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum CallSpec {
+    Declare(DeclareCall),
+    Deploy(DeployCall),
+    Invoke(InvokeCall),
+}
+
+/// Determines what happens with `declare` entries and how thoroughly the calls are checked
+pub enum PrepareMode {
+    /// Classes are declared if not declared yet, ABIs are fetched only to serialize `arguments`
+    Execute {
+        fee_args: FeeArgs,
+        wait_params: ValidatedWaitParams,
+    },
+    /// Nothing is sent, every call is checked against the ABI of its target
+    Validate,
+}
+
+pub fn parse_calls(path: &Utf8PathBuf) -> Result<Vec<CallSpec>> {
+    let contents = std::fs::read_to_string(path)?;
+    let items_map: HashMap<String, Vec<toml::Value>> =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {path}"))?;
+
+    items_map
+        .get("call")
+        .unwrap_or(&vec![])
+        .iter()
+        .map(|call| {
+            let Some(call_type) = call.get("call_type") else {
+                bail!("`Field call_type` is missing in a call specification");
+            };
+            let call = toml::to_string(&call)?;
+
+            match call_type.as_str() {
+                Some("declare") => Ok(CallSpec::Declare(
+                    toml::from_str(&call).context("Failed to parse toml `declare` call")?,
+                )),
+                Some("deploy") => Ok(CallSpec::Deploy(
+                    toml::from_str(&call).context("Failed to parse toml `deploy` call")?,
+                )),
+                Some("invoke") => Ok(CallSpec::Invoke(
+                    toml::from_str(&call).context("Failed to parse toml `invoke` call")?,
+                )),
+                Some(unsupported) => bail!("Unsupported call type found = {}", unsupported),
+                None => bail!("Field `call_type` is missing in a call specification"),
+            }
+        })
+        .collect()
+}
+
+/// Builds the Scarb project if any of the calls declares a contract
+pub fn load_artifacts_if_needed(
+    calls: &[CallSpec],
+    package: &Option<String>,
+    json: bool,
+    profile: Option<String>,
+) -> Result<Option<HashMap<String, StarknetContractArtifacts>>> {
+    if !calls
+        .iter()
+        .any(|call| matches!(call, CallSpec::Declare(_)))
+    {
+        return Ok(None);
+    }
+
+    let manifest_path = assert_manifest_path_exists()?;
+    let package_metadata = get_package_metadata(&manifest_path, package)?;
+    let artifacts = build_and_load_artifacts(
+        &package_metadata,
+        &BuildConfig {
+            scarb_toml_path: manifest_path,
+            json,
+            profile: profile.unwrap_or("release".to_string()),
+        },
+        false,
+    )?;
+
+    Ok(Some(artifacts))
+}
+
+/// Values produced by a call that can be referenced by its `id` in the following calls
+struct CallOutputs {
+    class_hash: Felt,
+    contract_address: Option<Felt>,
+    salt: Option<Felt>,
+}
+
+impl CallOutputs {
+    fn get(&self, id: &str, field: Option<&str>) -> Result<Felt> {
+        match field {
+            None => Ok(self.contract_address.unwrap_or(self.class_hash)),
+            Some("class_hash") => Ok(self.class_hash),
+            Some("contract_address") => self
+                .contract_address
+                .with_context(|| format!("Call `{id}` does not deploy a contract")),
+            Some("salt") => self
+                .salt
+                .with_context(|| format!("Call `{id}` does not deploy a contract")),
+            Some(field) => bail!(
+                "Unknown field `{field}` referenced in call `{id}`, available fields are `class_hash`, `contract_address` and `salt`"
+            ),
+        }
+    }
+}
+
+pub struct CallsBuilder<'a> {
+    account: &'a SncastAccount<&'a JsonRpcClient<HttpTransport>>,
+    artifacts: Option<&'a HashMap<String, StarknetContractArtifacts>>,
+    mode: PrepareMode,
+    outputs: HashMap<String, CallOutputs>,
+    deployed: HashMap<Felt, Felt>,
+    classes: HashMap<Felt, ContractClass>,
+}
+
+impl<'a> CallsBuilder<'a> {
+    pub fn new(
+        account: &'a SncastAccount<&'a JsonRpcClient<HttpTransport>>,
+        artifacts: Option<&'a HashMap<String, StarknetContractArtifacts>>,
+        mode: PrepareMode,
+    ) -> Self {
+        Self {
+            account,
+            artifacts,
+            mode,
+            outputs: HashMap::new(),
+            deployed: HashMap::new(),
+            classes: HashMap::new(),
+        }
+    }
+
+    /// Resolves references and serializes arguments of all the calls. In [`PrepareMode::Execute`]
+    /// the classes from `declare` entries are declared before returning
+    pub async fn build(mut self, calls: Vec<CallSpec>) -> Result<Vec<Call>> {
+        let mut parsed_calls = vec![];
+
+        for call in calls {
+            match call {
+                CallSpec::Declare(declare_call) => self.declare(declare_call).await?,
+                CallSpec::Deploy(deploy_call) => parsed_calls.push(self.deploy(deploy_call).await?),
+                CallSpec::Invoke(invoke_call) => parsed_calls.push(self.invoke(invoke_call).await?),
+            }
+        }
+
+        Ok(parsed_calls)
+    }
+
+    async fn declare(&mut self, declare_call: DeclareCall) -> Result<()> {
+        let artifacts = self
+            .artifacts
+            .context("Contract artifacts are required to declare contracts in a multicall")?;
+
+        let (class_hash, contract_class) = match &self.mode {
+            PrepareMode::Execute {
+                fee_args,
+                wait_params,
+            } => {
+                let local_class = declare_if_not_declared(
+                    &declare_call.contract_name,
+                    fee_args.clone(),
+                    None,
+                    self.account,
+                    artifacts,
+                    *wait_params,
+                )
+                .await
+                .map_err(handle_starknet_command_error)?;
+                (local_class.class_hash, local_class.contract_class)
+            }
+            PrepareMode::Validate => load_local_class(&declare_call.contract_name, artifacts)
+                .map_err(handle_starknet_command_error)?,
+        };
+
+        self.classes.insert(class_hash, contract_class);
+        self.insert_outputs(
+            declare_call.id,
+            CallOutputs {
+                class_hash,
+                contract_address: None,
+                salt: None,
+            },
+        )
+    }
+
+    async fn deploy(&mut self, deploy_call: DeployCall) -> Result<Call> {
+        let class_hash = self
+            .resolve(&deploy_call.class_hash)?
+            .map_or_else(|| deploy_call.class_hash.parse(), Ok)
+            .context("Failed to parse class hash to Felt")?;
+
+        // safe to unwrap because "constructor" is a standardized name
+        let selector = get_selector_from_name("constructor").unwrap();
+        let constructor_calldata = match deploy_call.arguments {
+            Some(arguments) => {
+                ensure_no_inputs(&deploy_call.inputs)?;
+                let contract_class = self.contract_class(class_hash).await?;
+                self.serialize_arguments(&arguments, contract_class, &selector)?
+            }
+            None => {
+                if matches!(self.mode, PrepareMode::Validate) {
+                    self.contract_class(class_hash).await?;
+                }
+                self.parse_inputs(&deploy_call.inputs)?
+            }
+        };
+
+        let salt = extract_or_generate_salt(deploy_call.salt);
+        let contract_address = get_udc_deployed_address(
+            salt,
+            class_hash,
+            &udc_uniqueness(deploy_call.unique, self.account.address()),
+            &constructor_calldata,
+        );
+
+        let mut calldata = vec![
+            class_hash,
+            salt,
+            Felt::from(u8::from(deploy_call.unique)),
+            constructor_calldata.len().into(),
+        ];
+        calldata.extend(&constructor_calldata);
+
+        self.deployed.insert(contract_address, class_hash);
+        self.insert_outputs(
+            deploy_call.id,
+            CallOutputs {
+                class_hash,
+                contract_address: Some(contract_address),
+                salt: Some(salt),
+            },
+        )?;
+
+        Ok(Call {
+            to: UDC_ADDRESS,
+            selector: get_selector_from_name("deployContract")?,
+            calldata,
+        })
+    }
+
+    async fn invoke(&mut self, invoke_call: InvokeCall) -> Result<Call> {
+        let contract_address = self
+            .resolve(&invoke_call.contract_address)?
+            .map_or_else(|| invoke_call.contract_address.parse(), Ok)
+            .context("Failed to parse contract address to Felt")?;
+        let selector = get_selector_from_name(&invoke_call.function)?;
+
+        let calldata = match invoke_call.arguments {
+            Some(arguments) => {
+                ensure_no_inputs(&invoke_call.inputs)?;
+                let contract_class = self.contract_class_at(contract_address).await?;
+                self.serialize_arguments(&arguments, contract_class, &selector)?
+            }
+            None => {
+                if matches!(self.mode, PrepareMode::Validate) {
+                    let contract_class = self.contract_class_at(contract_address).await?;
+                    ensure_function_exists(&contract_class, &invoke_call.function)?;
+                }
+                self.parse_inputs(&invoke_call.inputs)?
+            }
+        };
+
+        Ok(Call {
+            to: contract_address,
+            selector,
+            calldata,
+        })
+    }
+
+    fn insert_outputs(&mut self, id: Option<String>, outputs: CallOutputs) -> Result<()> {
+        let Some(id) = id else {
+            return Ok(());
+        };
+        if self.outputs.insert(id.clone(), outputs).is_some() {
+            bail!("Duplicated call id `{id}`");
+        }
+        Ok(())
+    }
+
+    /// Resolves `id`, `id.field`, `@id` or `@id.field` to a value produced by a previous call.
+    /// Returns `None` if the value does not refer to any call
+    fn resolve(&self, value: &str) -> Result<Option<Felt>> {
+        let is_explicit = value.starts_with('@');
+        let reference = value.strip_prefix('@').unwrap_or(value);
+        let (id, field) = match reference.split_once('.') {
+            Some((id, field)) => (id, Some(field)),
+            None => (reference, None),
+        };
+
+        match self.outputs.get(id) {
+            Some(outputs) => outputs.get(id, field).map(Some),
+            None if is_explicit => {
+                bail!("Reference `{value}` does not match any of the previous calls")
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn parse_inputs(&self, inputs: &[Input]) -> Result<Vec<Felt>> {
+        inputs
+            .iter()
+            .map(|input| match input {
+                Input::String(value) => match self.resolve(value)? {
+                    Some(resolved) => Ok(resolved),
+                    None => value
+                        .parse()
+                        .with_context(|| format!("Failed to parse input '{value}' to Felt")),
+                },
+                Input::Number(number) => Ok((*number).into()),
+            })
+            .collect()
+    }
+
+    fn serialize_arguments(
+        &self,
+        arguments: &str,
+        contract_class: ContractClass,
+        selector: &Felt,
+    ) -> Result<Vec<Felt>> {
+        let resolved = substitute_references(arguments, |reference| self.resolve(reference))?;
+
+        Calldata::new(resolved).serialized(contract_class, selector)
+    }
+
+    async fn contract_class(&mut self, class_hash: Felt) -> Result<ContractClass> {
+        if let Some(contract_class) = self.classes.get(&class_hash) {
+            return Ok(contract_class.clone());
+        }
+
+        let contract_class = get_contract_class(class_hash, self.account.provider()).await?;
+        self.classes.insert(class_hash, contract_class.clone());
+        Ok(contract_class)
+    }
+
+    async fn contract_class_at(&mut self, contract_address: Felt) -> Result<ContractClass> {
+        let class_hash = match self.deployed.get(&contract_address) {
+            Some(class_hash) => *class_hash,
+            None => get_class_hash_by_address(self.account.provider(), contract_address).await?,
+        };
+        self.contract_class(class_hash).await
+    }
+}
+
+/// Replaces references to outputs of previous calls in Cairo expressions with their values,
+/// leaving string literals untouched
+fn substitute_references(
+    arguments: &str,
+    resolve: impl Fn(&str) -> Result<Option<Felt>>,
+) -> Result<String> {
+    let mut resolved = String::with_capacity(arguments.len());
+    let mut last_end = 0;
+
+    for reference in REFERENCE_REGEX.find_iter(arguments) {
+        if reference.as_str().starts_with(['"', '\'']) {
+            continue;
+        }
+        // `resolve` fails for unknown references prefixed with `@`
+        let value = resolve(reference.as_str())?.unwrap_or_default();
+        resolved.push_str(&arguments[last_end..reference.start()]);
+        resolved.push_str(&format!("{value:#x}"));
+        last_end = reference.end();
+    }
+    resolved.push_str(&arguments[last_end..]);
+
+    Ok(resolved)
+}
+
+fn ensure_no_inputs(inputs: &[Input]) -> Result<()> {
+    if !inputs.is_empty() {
+        bail!("Only one of `inputs` and `arguments` can be specified in a call");
+    }
+    Ok(())
+}
+
+fn ensure_function_exists(contract_class: &ContractClass, function: &str) -> Result<()> {
+    // ABI of Cairo Zero contracts is not checked
+    let ContractClass::Sierra(sierra_class) = contract_class else {
+        return Ok(());
+    };
+
+    let abi: Vec<AbiEntry> = serde_json::from_str(&sierra_class.abi)
+        .context("Couldn't deserialize ABI received from chain")?;
+    let exists = abi.iter().any(|entry| match entry {
+        AbiEntry::Function(abi_function) => abi_function.name == function,
+        AbiEntry::Interface(interface) => interface.items.iter().any(|item| match item {
+            AbiEntry::Function(abi_function) => abi_function.name == function,
+            _ => false,
+        }),
+        _ => false,
+    });
+
+    if !exists {
+        bail!(r#"Function "{function}" not found in ABI of the contract"#);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::substitute_references;
+    use anyhow::{Result, bail};
+    use starknet_types_core::felt::Felt;
+
+    fn resolve(reference: &str) -> Result<Option<Felt>> {
+        match reference {
+            "@map_contract" => Ok(Some(Felt::from(0x123))),
+            "@map_contract.salt" => Ok(Some(Felt::from(0x456))),
+            _ => bail!("Reference `{reference}` does not match any of the previous calls"),
+        }
+    }
+
+    #[test]
+    fn substitutes_references() {
+        let resolved =
+            substitute_references("@map_contract, @map_contract.salt, 0x1_u256", resolve).unwrap();
+
+        assert_eq!(resolved, "0x123, 0x456, 0x1_u256");
+    }
+
+    #[test]
+    fn skips_references_in_string_literals() {
+        let resolved = substitute_references(
+            r#"@map_contract, "user@example", 'user@example', "escaped \" @unknown""#,
+            resolve,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved,
+            r#"0x123, "user@example", 'user@example', "escaped \" @unknown""#
+        );
+    }
+
+    #[test]
+    fn fails_for_unknown_reference() {
+        let error = substitute_references("\"user\", @unknown", resolve).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Reference `@unknown` does not match any of the previous calls"
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use clap::{Args, Subcommand};
 
+mod calls;
 pub mod new;
 pub mod run;
+pub mod validate;
 
 use new::New;
 use run::Run;
+use validate::Validate;
 
 #[derive(Args)]
 #[command(about = "Execute multiple calls at once", long_about = None)]
@@ -17,4 +20,5 @@ pub struct Multicall {
 pub enum Commands {
     Run(Run),
     New(New),
+    Validate(Validate),
 }
@@ -1,22 +1,18 @@
 use crate::starknet_commands::invoke::execute_calls;
-use anyhow::{Context, Result};
+use crate::starknet_commands::multicall::calls::{
+    CallsBuilder, PrepareMode, load_artifacts_if_needed, parse_calls,
+};
+use anyhow::{Result, bail};
 use camino::Utf8PathBuf;
 use clap::Args;
-use serde::Deserialize;
-use sncast::helpers::constants::UDC_ADDRESS;
+use sncast::WaitForTx;
+use sncast::helpers::account::SncastAccount;
 use sncast::helpers::fee::FeeArgs;
 use sncast::helpers::rpc::RpcArgs;
 use sncast::response::errors::handle_starknet_command_error;
 use sncast::response::structs::InvokeResponse;
-use sncast::{WaitForTx, extract_or_generate_salt, udc_uniqueness};
-use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::Call;
-use starknet::core::utils::{get_selector_from_name, get_udc_deployed_address};
 use starknet::providers::JsonRpcClient;
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::signers::LocalWallet;
-use starknet_types_core::felt::Felt;
-use std::collections::HashMap;
 
 #[derive(Args, Debug, Clone)]
 #[command(about = "Execute a multicall from a .toml file", long_about = None)]
@@ -25,6 +21,10 @@ pub struct Run {
     #[clap(short = 'p', long = "path")]
     pub path: Utf8PathBuf,
 
+    /// Specifies scarb package to be used for `declare` calls
+    #[clap(long)]
+    pub package: Option<String>,
+
     #[clap(flatten)]
     pub fee_args: FeeArgs,
 
@@ -32,123 +32,31 @@ pub struct Run {
     pub rpc: RpcArgs,
 }
 
-#[derive(Deserialize, Debug)]
-#[serde(untagged)]
-enum Input {
-    String(String),
-    Number(i64),
-}
-
-#[derive(Deserialize, Debug)]
-struct DeployCall {
-    class_hash: Felt,
-    inputs: Vec<Input>,
-    unique: bool,
-    salt: Option<Felt>,
-    id: String,
-}
-
-#[derive(Deserialize, Debug)]
-struct InvokeCall {
-    contract_address: String,
-    function: String,
-    inputs: Vec<Input>,
-}
-
 pub async fn run(
     run: Run,
-    account: &SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
     wait_config: WaitForTx,
+    json: bool,
+    profile: Option<String>,
 ) -> Result<InvokeResponse> {
     let fee_args = run.fee_args.clone();
 
-    let contents = std::fs::read_to_string(&run.path)?;
-    let items_map: HashMap<String, Vec<toml::Value>> =
-        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", run.path))?;
-
-    let mut contracts = HashMap::new();
-    let mut parsed_calls: Vec<Call> = vec![];
-
-    for call in items_map.get("call").unwrap_or(&vec![]) {
-        let call_type = call.get("call_type");
-        if call_type.is_none() {
-            anyhow::bail!("`Field call_type` is missing in a call specification");
-        }
+    let calls = parse_calls(&run.path)?;
+    let artifacts = load_artifacts_if_needed(&calls, &run.package, json, profile)?;
 
-        match call_type.unwrap().as_str() {
-            Some("deploy") => {
-                let deploy_call: DeployCall = toml::from_str(toml::to_string(&call)?.as_str())
-                    .context("Failed to parse toml `deploy` call")?;
+    let mode = PrepareMode::Execute {
+        fee_args: fee_args.clone(),
+        wait_params: wait_config.wait_params,
+    };
+    let parsed_calls = CallsBuilder::new(account, artifacts.as_ref(), mode)
+        .build(calls)
+        .await?;
 
-                let salt = extract_or_generate_salt(deploy_call.salt);
-                let mut calldata = vec![
-                    deploy_call.class_hash,
-                    salt,
-                    Felt::from(u8::from(deploy_call.unique)),
-                    deploy_call.inputs.len().into(),
-                ];
-
-                let parsed_inputs = parse_inputs(&deploy_call.inputs, &contracts)?;
-                calldata.extend(&parsed_inputs);
-
-                parsed_calls.push(Call {
-                    to: UDC_ADDRESS,
-                    selector: get_selector_from_name("deployContract")?,
-                    calldata,
-                });
-
-                let contract_address = get_udc_deployed_address(
-                    salt,
-                    deploy_call.class_hash,
-                    &udc_uniqueness(deploy_call.unique, account.address()),
-                    &parsed_inputs,
-                );
-                contracts.insert(deploy_call.id, contract_address.to_string());
-            }
-            Some("invoke") => {
-                let invoke_call: InvokeCall = toml::from_str(toml::to_string(&call)?.as_str())
-                    .context("Failed to parse toml `invoke` call")?;
-                let mut contract_address = &invoke_call.contract_address;
-                if let Some(addr) = contracts.get(&invoke_call.contract_address) {
-                    contract_address = addr;
-                }
-
-                let calldata = parse_inputs(&invoke_call.inputs, &contracts)?;
-
-                parsed_calls.push(Call {
-                    to: contract_address
-                        .parse()
-                        .context("Failed to parse contract address to Felt")?,
-                    selector: get_selector_from_name(&invoke_call.function)?,
-                    calldata,
-                });
-            }
-            Some(unsupported) => {
-                anyhow::bail!("Unsupported call type found = {}", unsupported);
-            }
-            None => anyhow::bail!("Field `call_type` is missing in a call specification"),
-        }
+    if parsed_calls.is_empty() {
+        bail!("No `deploy` or `invoke` calls found in {}", run.path);
     }
 
     execute_calls(account, parsed_calls, fee_args, None, wait_config)
         .await
         .map_err(handle_starknet_command_error)
 }
-
-fn parse_inputs(inputs: &Vec<Input>, contracts: &HashMap<String, String>) -> Result<Vec<Felt>> {
-    let mut parsed_inputs = Vec::new();
-    for input in inputs {
-        let felt_value = match input {
-            Input::String(s) => {
-                let resolved = contracts.get(s).unwrap_or(s);
-                resolved
-                    .parse()
-                    .context(format!("Failed to parse input '{resolved}' to Felt"))?
-            }
-            Input::Number(n) => (*n).into(),
-        };
-        parsed_inputs.push(felt_value);
-    }
-
-    Ok(parsed_inputs)
-}
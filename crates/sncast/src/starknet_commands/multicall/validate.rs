@@ -0,0 +1,48 @@
+use crate::starknet_commands::multicall::calls::{
+    CallsBuilder, PrepareMode, load_artifacts_if_needed, parse_calls,
+};
+use anyhow::Result;
+use camino::Utf8PathBuf;
+use clap::Args;
+use sncast::helpers::account::SncastAccount;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::MulticallValidateResponse;
+use starknet::providers::JsonRpcClient;
+use starknet::providers::jsonrpc::HttpTransport;
+
+#[derive(Args, Debug, Clone)]
+#[command(about = "Check a multicall .toml file against ABIs of the called contracts without executing it", long_about = None)]
+pub struct Validate {
+    /// Path to the toml file with declared operations
+    #[clap(short = 'p', long = "path")]
+    pub path: Utf8PathBuf,
+
+    /// Specifies scarb package to be used for `declare` calls
+    #[clap(long)]
+    pub package: Option<String>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+pub async fn validate(
+    validate: &Validate,
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
+    json: bool,
+    profile: Option<String>,
+) -> Result<MulticallValidateResponse> {
+    let calls = parse_calls(&validate.path)?;
+    let artifacts = load_artifacts_if_needed(&calls, &validate.package, json, profile)?;
+
+    let parsed_calls = CallsBuilder::new(account, artifacts.as_ref(), PrepareMode::Validate)
+        .build(calls)
+        .await?;
+
+    Ok(MulticallValidateResponse {
+        message: format!(
+            "Multicall file {} is valid, {} calls are ready to be executed",
+            validate.path,
+            parsed_calls.len()
+        ),
+    })
+}
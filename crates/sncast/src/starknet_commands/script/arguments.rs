@@ -0,0 +1,337 @@
+use anyhow::{Context, Result, bail, ensure};
+use cairo_lang_runner::Arg;
+use cairo_lang_sierra::ids::ConcreteTypeId;
+use cairo_lang_sierra::program::{ConcreteTypeLongId, Function, GenericArg, Program};
+use cairo_lang_sierra_to_casm::invocations::enm::get_variant_selector;
+use clap::Parser;
+use data_transformer::transform_for_function;
+use starknet::core::types::contract::{AbiFunction, AbiNamedMember, StateMutability};
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+
+/// Implicit parameters of `main` which are provided by the runner
+const BUILTINS: [&str; 10] = [
+    "Pedersen",
+    "RangeCheck",
+    "RangeCheck96",
+    "Bitwise",
+    "EcOp",
+    "Poseidon",
+    "AddMod",
+    "MulMod",
+    "SegmentArena",
+    "System",
+];
+const GAS_BUILTIN: &str = "GasBuiltin";
+
+const INTEGERS: [&str; 10] = [
+    "u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128",
+];
+const SUPPORTED_USER_TYPES: [&str; 3] = [
+    "core::bool",
+    "core::integer::u256",
+    "core::byte_array::ByteArray",
+];
+
+/// Arguments passed to the script after `--`
+#[derive(Parser, Debug)]
+#[command(no_binary_name = true)]
+struct ScriptArguments {
+    /// Comma-separated series of Cairo expressions passed as arguments to `main`
+    #[arg(long)]
+    arguments: Option<String>,
+}
+
+/// Parses arguments passed after `--` against the signature of `main`
+/// and returns them laid out in memory as expected by the runner
+pub fn build_user_args(
+    program: &Program,
+    main: &Function,
+    script_args: &[String],
+) -> Result<Vec<Vec<Arg>>> {
+    let arguments = ScriptArguments::try_parse_from(script_args)?.arguments;
+    let types = SierraTypes::new(program);
+
+    let mut parameters = vec![];
+    for ty in &main.signature.param_types {
+        let generic_id = types.long_id(ty)?.generic_id.0.as_str();
+        if generic_id != GAS_BUILTIN && !BUILTINS.contains(&generic_id) {
+            parameters.push(AbiNamedMember {
+                name: format!("arg{}", parameters.len()),
+                r#type: types.abi_type_name(ty)?,
+            });
+        }
+    }
+
+    let calldata = match arguments {
+        Some(arguments) => {
+            let function = AbiFunction {
+                name: "main".to_string(),
+                inputs: parameters,
+                outputs: vec![],
+                state_mutability: StateMutability::External,
+            };
+            transform_for_function(&arguments, &function, &[])?
+        }
+        None if parameters.is_empty() => vec![],
+        None => bail!(
+            "Script main function expects {} argument(s), pass them with `-- --arguments`",
+            parameters.len()
+        ),
+    };
+
+    let mut calldata = calldata.into_iter();
+    let mut user_args = vec![];
+    for ty in &main.signature.param_types {
+        let generic_id = types.long_id(ty)?.generic_id.0.as_str();
+        if generic_id == GAS_BUILTIN {
+            // Setting gas to be far from u64 boundaries
+            user_args.push(vec![Arg::Value(Felt::from(i64::MAX))]);
+        } else if !BUILTINS.contains(&generic_id) {
+            let mut memory = vec![];
+            types.write_value(ty, &mut calldata, &mut memory)?;
+            user_args.push(memory);
+        }
+    }
+
+    Ok(user_args)
+}
+
+struct SierraTypes<'a> {
+    declarations: HashMap<&'a ConcreteTypeId, &'a ConcreteTypeLongId>,
+}
+
+impl<'a> SierraTypes<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            declarations: program
+                .type_declarations
+                .iter()
+                .map(|declaration| (&declaration.id, &declaration.long_id))
+                .collect(),
+        }
+    }
+
+    fn long_id(&self, ty: &ConcreteTypeId) -> Result<&'a ConcreteTypeLongId> {
+        self.declarations
+            .get(ty)
+            .copied()
+            .with_context(|| format!("Type {ty} not found in Sierra program"))
+    }
+
+    /// Returns the type name as it would appear in a contract ABI
+    fn abi_type_name(&self, ty: &ConcreteTypeId) -> Result<String> {
+        let long_id = self.long_id(ty)?;
+        let generic_id = long_id.generic_id.0.as_str();
+
+        let name = match generic_id {
+            "felt252" => "core::felt252".to_string(),
+            "ContractAddress" => "core::starknet::contract_address::ContractAddress".to_string(),
+            "ClassHash" => "core::starknet::class_hash::ClassHash".to_string(),
+            "bytes31" => "core::bytes_31::bytes31".to_string(),
+            "Array" => {
+                let [element_type] = type_args(long_id)[..] else {
+                    bail!("Invalid Array type {ty}")
+                };
+                format!(
+                    "core::array::Array::<{}>",
+                    self.abi_type_name(element_type)?
+                )
+            }
+            "Struct" | "Enum" => {
+                let name = match long_id.generic_args.first() {
+                    Some(GenericArg::UserType(user_type)) => user_type.to_string(),
+                    _ => bail!("Invalid user type {ty}"),
+                };
+                ensure!(
+                    SUPPORTED_USER_TYPES.contains(&name.as_str()),
+                    "Type {name} is not supported as an argument of script main function"
+                );
+                name
+            }
+            integer if INTEGERS.contains(&integer) => format!("core::integer::{integer}"),
+            _ => bail!("Type {ty} is not supported as an argument of script main function"),
+        };
+
+        Ok(name)
+    }
+
+    /// Converts serialized `calldata` to the memory representation of `ty`
+    fn write_value(
+        &self,
+        ty: &ConcreteTypeId,
+        calldata: &mut impl Iterator<Item = Felt>,
+        memory: &mut Vec<Arg>,
+    ) -> Result<()> {
+        let long_id = self.long_id(ty)?;
+
+        match long_id.generic_id.0.as_str() {
+            "Array" => {
+                let [element_type] = type_args(long_id)[..] else {
+                    bail!("Invalid Array type {ty}")
+                };
+                let length = next_usize(calldata)?;
+
+                let mut elements = vec![];
+                for _ in 0..length {
+                    self.write_value(element_type, calldata, &mut elements)?;
+                }
+                memory.push(Arg::Array(elements));
+            }
+            "Struct" => {
+                for member_type in type_args(long_id) {
+                    self.write_value(member_type, calldata, memory)?;
+                }
+            }
+            "Enum" => {
+                let variant_types = type_args(long_id);
+                let index = next_usize(calldata)?;
+                let variant_type = variant_types
+                    .get(index)
+                    .with_context(|| format!("Invalid variant index {index} of enum {ty}"))?;
+
+                // Variants are padded from the left to the size of the largest variant
+                let padding = self.size(ty)? - 1 - self.size(variant_type)?;
+                let selector = get_variant_selector(variant_types.len(), index)?;
+
+                memory.push(Arg::Value(Felt::from(selector)));
+                memory.extend((0..padding).map(|_| Arg::Value(Felt::ZERO)));
+                self.write_value(variant_type, calldata, memory)?;
+            }
+            _ => memory.push(Arg::Value(next_felt(calldata)?)),
+        }
+
+        Ok(())
+    }
+
+    fn size(&self, ty: &ConcreteTypeId) -> Result<usize> {
+        let long_id = self.long_id(ty)?;
+
+        let size = match long_id.generic_id.0.as_str() {
+            "Array" => 2,
+            "Struct" => type_args(long_id)
+                .into_iter()
+                .map(|member_type| self.size(member_type))
+                .sum::<Result<usize>>()?,
+            "Enum" => {
+                1 + type_args(long_id)
+                    .into_iter()
+                    .map(|variant_type| self.size(variant_type))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .max()
+                    .unwrap_or_default()
+            }
+            _ => 1,
+        };
+
+        Ok(size)
+    }
+}
+
+fn type_args(long_id: &ConcreteTypeLongId) -> Vec<&ConcreteTypeId> {
+    long_id
+        .generic_args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArg::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect()
+}
+
+fn next_felt(calldata: &mut impl Iterator<Item = Felt>) -> Result<Felt> {
+    calldata
+        .next()
+        .context("Not enough arguments passed to script main function")
+}
+
+fn next_usize(calldata: &mut impl Iterator<Item = Felt>) -> Result<usize> {
+    let value = next_felt(calldata)?;
+    usize::try_from(value).with_context(|| format!("Failed to convert {value} to usize"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cairo_lang_sierra::ProgramParser;
+    use indoc::indoc;
+
+    fn program() -> Program {
+        ProgramParser::new()
+            .parse(indoc! {"
+                type RangeCheck = RangeCheck;
+                type GasBuiltin = GasBuiltin;
+                type felt252 = felt252;
+                type u8 = u8;
+                type Array<u8> = Array<u8>;
+                type Unit = Struct<ut@Tuple>;
+                type core::bool = Enum<ut@core::bool, Unit, Unit>;
+                type core::option::Option::<u8> = Enum<ut@core::option::Option::<u8>, u8, Unit>;
+
+                return([0]);
+
+                script::main@0([0]: RangeCheck, [1]: GasBuiltin, [2]: felt252, [3]: Array<u8>, [4]: core::bool) -> (RangeCheck, GasBuiltin);
+                script::with_option@0([0]: GasBuiltin, [1]: core::option::Option::<u8>) -> (GasBuiltin);
+            "})
+            .unwrap()
+    }
+
+    fn script_args(arguments: &str) -> Vec<String> {
+        vec!["--arguments".to_string(), arguments.to_string()]
+    }
+
+    #[test]
+    fn test_build_user_args() {
+        let program = program();
+
+        let user_args = build_user_args(
+            &program,
+            &program.funcs[0],
+            &script_args("0x123, array![1, 2], true"),
+        )
+        .unwrap();
+
+        // `Arg` does not implement `PartialEq`
+        assert_eq!(
+            format!("{user_args:?}"),
+            format!(
+                "{:?}",
+                vec![
+                    vec![Arg::Value(Felt::from(i64::MAX))],
+                    vec![Arg::Value(Felt::from(0x123))],
+                    vec![Arg::Array(vec![
+                        Arg::Value(Felt::ONE),
+                        Arg::Value(Felt::TWO)
+                    ])],
+                    vec![Arg::Value(Felt::ONE)],
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_missing_arguments() {
+        let program = program();
+
+        let error = build_user_args(&program, &program.funcs[0], &[]).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Script main function expects 3 argument(s), pass them with `-- --arguments`"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let program = program();
+
+        let error =
+            build_user_args(&program, &program.funcs[1], &script_args("Option::None")).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Type core::option::Option::<u8> is not supported as an argument of script main function"
+        );
+    }
+}
@@ -1,9 +1,14 @@
 use crate::starknet_commands::script::init::Init;
 use crate::starknet_commands::script::run::Run;
+use crate::starknet_commands::script::state::State;
 use clap::{Args, Subcommand};
 
+pub mod arguments;
 pub mod init;
+pub mod queries;
 pub mod run;
+pub mod simulation;
+pub mod state;
 
 #[derive(Args)]
 pub struct Script {
@@ -15,4 +20,5 @@ pub struct Script {
 pub enum Commands {
     Init(Init),
     Run(Run),
+    State(State),
 }
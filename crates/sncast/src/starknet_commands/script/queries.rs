@@ -0,0 +1,173 @@
+use anyhow::anyhow;
+use conversions::serde::deserialize::CairoDeserialize;
+use conversions::serde::serialize::CairoSerialize;
+use sncast::helpers::account::SncastAccount;
+use sncast::response::errors::StarknetCommandError;
+use starknet::accounts::Account;
+use starknet::accounts::AccountError::Provider;
+use starknet::core::types::{
+    BlockId, BlockTag, Call, Event, MaybePendingBlockWithTxHashes, TransactionReceipt,
+};
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider as _};
+use starknet_types_core::felt::Felt;
+
+#[derive(Debug, Clone, Copy, CairoDeserialize)]
+pub enum ScriptBlockTag {
+    Latest,
+    Pending,
+}
+
+/// `BlockId` as passed by `sncast_std`
+#[derive(Debug, Clone, Copy, CairoDeserialize)]
+pub enum ScriptBlockId {
+    Tag(ScriptBlockTag),
+    Hash(Felt),
+    Number(u64),
+}
+
+impl From<ScriptBlockId> for BlockId {
+    fn from(value: ScriptBlockId) -> Self {
+        match value {
+            ScriptBlockId::Tag(ScriptBlockTag::Latest) => BlockId::Tag(BlockTag::Latest),
+            ScriptBlockId::Tag(ScriptBlockTag::Pending) => BlockId::Tag(BlockTag::Pending),
+            ScriptBlockId::Hash(hash) => BlockId::Hash(hash),
+            ScriptBlockId::Number(number) => BlockId::Number(number),
+        }
+    }
+}
+
+/// `Call` as passed by `sncast_std`
+#[derive(Debug, Clone, CairoDeserialize)]
+pub struct ScriptCall {
+    pub contract_address: Felt,
+    pub entry_point_selector: Felt,
+    pub calldata: Vec<Felt>,
+}
+
+impl From<ScriptCall> for Call {
+    fn from(value: ScriptCall) -> Self {
+        Call {
+            to: value.contract_address,
+            selector: value.entry_point_selector,
+            calldata: value.calldata,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CairoSerialize)]
+pub struct BlockResponse {
+    pub block_hash: Option<Felt>,
+    pub parent_hash: Felt,
+    pub block_number: Option<u64>,
+    pub timestamp: u64,
+    pub sequencer_address: Felt,
+    pub transaction_hashes: Vec<Felt>,
+}
+
+#[derive(Debug, Clone, PartialEq, CairoSerialize)]
+pub struct EventResponse {
+    pub from_address: Felt,
+    pub keys: Vec<Felt>,
+    pub data: Vec<Felt>,
+}
+
+impl From<Event> for EventResponse {
+    fn from(value: Event) -> Self {
+        Self {
+            from_address: value.from_address,
+            keys: value.keys,
+            data: value.data,
+        }
+    }
+}
+
+#[derive(Debug, Clone, CairoSerialize)]
+pub struct EstimateFeeResponse {
+    pub overall_fee: Felt,
+}
+
+pub async fn get_storage_at(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract_address: Felt,
+    key: Felt,
+) -> Result<Felt, StarknetCommandError> {
+    provider
+        .get_storage_at(contract_address, key, BlockId::Tag(BlockTag::Pending))
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))
+}
+
+pub async fn get_class_hash_at(
+    provider: &JsonRpcClient<HttpTransport>,
+    contract_address: Felt,
+) -> Result<Felt, StarknetCommandError> {
+    provider
+        .get_class_hash_at(BlockId::Tag(BlockTag::Pending), contract_address)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))
+}
+
+pub async fn get_block(
+    provider: &JsonRpcClient<HttpTransport>,
+    block_id: BlockId,
+) -> Result<BlockResponse, StarknetCommandError> {
+    let block = provider
+        .get_block_with_tx_hashes(block_id)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    Ok(match block {
+        MaybePendingBlockWithTxHashes::Block(block) => BlockResponse {
+            block_hash: Some(block.block_hash),
+            parent_hash: block.parent_hash,
+            block_number: Some(block.block_number),
+            timestamp: block.timestamp,
+            sequencer_address: block.sequencer_address,
+            transaction_hashes: block.transactions,
+        },
+        MaybePendingBlockWithTxHashes::PendingBlock(block) => BlockResponse {
+            block_hash: None,
+            parent_hash: block.parent_hash,
+            block_number: None,
+            timestamp: block.timestamp,
+            sequencer_address: block.sequencer_address,
+            transaction_hashes: block.transactions,
+        },
+    })
+}
+
+/// Returns events emitted by the transaction, taken from its receipt
+pub async fn get_events(
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+) -> Result<Vec<EventResponse>, StarknetCommandError> {
+    let receipt = provider
+        .get_transaction_receipt(transaction_hash)
+        .await
+        .map_err(|error| StarknetCommandError::ProviderError(error.into()))?;
+
+    let events = match receipt.receipt {
+        TransactionReceipt::Invoke(receipt) => receipt.events,
+        TransactionReceipt::L1Handler(receipt) => receipt.events,
+        TransactionReceipt::Declare(receipt) => receipt.events,
+        TransactionReceipt::Deploy(receipt) => receipt.events,
+        TransactionReceipt::DeployAccount(receipt) => receipt.events,
+    };
+
+    Ok(events.into_iter().map(EventResponse::from).collect())
+}
+
+/// Estimates the fee of an invoke transaction executing `calls`
+pub async fn estimate_fee(
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
+    calls: Vec<Call>,
+) -> Result<EstimateFeeResponse, StarknetCommandError> {
+    match account.execute_v3(calls).estimate_fee().await {
+        Ok(estimate) => Ok(EstimateFeeResponse {
+            overall_fee: estimate.overall_fee,
+        }),
+        Err(Provider(error)) => Err(StarknetCommandError::ProviderError(error.into())),
+        Err(error) => Err(anyhow!(format!("Unexpected error occurred: {error}")).into()),
+    }
+}
@@ -1,4 +1,7 @@
 use crate::starknet_commands::declare::Declare;
+use crate::starknet_commands::script::arguments::build_user_args;
+use crate::starknet_commands::script::queries::{self, ScriptBlockId, ScriptCall};
+use crate::starknet_commands::script::simulation::ScriptSimulation;
 use crate::starknet_commands::{call, declare, deploy, invoke, tx_status};
 use crate::{WaitForTx, get_account};
 use anyhow::{Context, Result, anyhow};
@@ -11,7 +14,7 @@ use cairo_lang_casm::hints::Hint;
 use cairo_lang_runnable_utils::builder::{EntryCodeConfig, RunnableBuilder, create_code_footer};
 use cairo_lang_runner::casm_run::hint_to_hint_params;
 use cairo_lang_runner::short_string::as_cairo_short_string;
-use cairo_lang_runner::{Arg, RunResultValue, SierraCasmRunner};
+use cairo_lang_runner::{RunResultValue, SierraCasmRunner};
 use cairo_lang_sierra::program::VersionedProgram;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -20,8 +23,10 @@ use cairo_vm::types::relocatable::Relocatable;
 use cairo_vm::vm::errors::hint_errors::HintError;
 use cairo_vm::vm::vm_core::VirtualMachine;
 use camino::Utf8PathBuf;
+use cheatnet::runtime_extensions::forge_runtime_extension::file_operations;
 use clap::Args;
 use conversions::byte_array::ByteArray;
+use conversions::felt::TryInferFormat;
 use conversions::serde::deserialize::BufferReader;
 use forge_runner::running::{has_segment_arena, syscall_handler_offset};
 use runtime::starknet::context::{SerializableBlockInfo, build_context};
@@ -36,20 +41,22 @@ use semver::{Comparator, Op, Version, VersionReq};
 use shared::print::print_as_warning;
 use shared::utils::build_readable_text;
 use sncast::get_nonce;
+use sncast::helpers::account::SncastAccount;
 use sncast::helpers::configuration::CastConfig;
 use sncast::helpers::constants::SCRIPT_LIB_ARTIFACT_NAME;
 use sncast::helpers::fee::{FeeArgs, ScriptFeeSettings};
 use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::StarknetCommandError;
 use sncast::response::structs::ScriptRunResponse;
 use sncast::state::hashing::{
-    generate_declare_tx_id, generate_deploy_tx_id, generate_invoke_tx_id,
+    generate_declare_tx_id, generate_deploy_tx_id, generate_invoke_tx_id, generate_multicall_tx_id,
 };
-use sncast::state::state_file::StateManager;
-use starknet::accounts::{Account, SingleOwnerAccount};
-use starknet::core::types::{BlockId, BlockTag::Pending};
-use starknet::providers::JsonRpcClient;
+use sncast::state::manifest::ManifestManager;
+use sncast::state::state_file::{ScriptTransactionOutput, StateManager};
+use starknet::accounts::Account;
+use starknet::core::types::{BlockId, BlockTag::Pending, Call};
 use starknet::providers::jsonrpc::HttpTransport;
-use starknet::signers::LocalWallet;
+use starknet::providers::{JsonRpcClient, Provider};
 use starknet_types_core::felt::Felt;
 use std::collections::HashMap;
 use std::fs;
@@ -71,23 +78,37 @@ pub struct Run {
     #[clap(long)]
     pub no_state_file: bool,
 
+    /// Execute the script against a state forked from the network, without sending any transactions
+    #[clap(long)]
+    pub simulate: bool,
+
+    /// Send transactions recorded during the simulation to the network if the script succeeded
+    #[clap(long, requires = "simulate")]
+    pub broadcast: bool,
+
     #[clap(flatten)]
     pub rpc: RpcArgs,
+
+    /// Arguments passed to the script, e.g. `-- --arguments '0x1, array![2, 3]'`
+    #[clap(last = true)]
+    pub script_args: Vec<String>,
 }
 
 pub struct CastScriptExtension<'a> {
     pub provider: &'a JsonRpcClient<HttpTransport>,
-    pub account: Option<&'a SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>>,
+    pub account: Option<&'a SncastAccount<&'a JsonRpcClient<HttpTransport>>>,
     pub tokio_runtime: Runtime,
     pub config: &'a CastConfig,
     pub artifacts: &'a HashMap<String, StarknetContractArtifacts>,
     pub state: StateManager,
+    pub manifest: ManifestManager,
+    pub simulation: Option<&'a mut ScriptSimulation>,
+    /// Paths of files read by the script are relative to the script package root
+    pub package_root: Utf8PathBuf,
 }
 
 impl CastScriptExtension<'_> {
-    pub fn account(
-        &self,
-    ) -> Result<&SingleOwnerAccount<&JsonRpcClient<HttpTransport>, LocalWallet>> {
+    pub fn account(&self) -> Result<&SncastAccount<&JsonRpcClient<HttpTransport>>> {
         self.account.ok_or_else(|| anyhow!("Account not defined. Please ensure the correct account is passed to `script run` command"))
     }
 }
@@ -102,19 +123,26 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
         mut input_reader: BufferReader,
         _extended_runtime: &mut Self::Runtime,
     ) -> Result<CheatcodeHandlingResult, EnhancedHintError> {
+        let account_address = self.account().map(Account::address);
+
         let res = match selector {
             "call" => {
                 let contract_address = input_reader.read()?;
                 let function_selector = input_reader.read()?;
-                let calldata_felts = input_reader.read()?;
-
-                let call_result = self.tokio_runtime.block_on(call::call(
-                    contract_address,
-                    function_selector,
-                    calldata_felts,
-                    self.provider,
-                    &BlockId::Tag(Pending),
-                ));
+                let calldata_felts: Vec<_> = input_reader.read()?;
+
+                let call_result = match self.simulation.as_deref_mut() {
+                    Some(simulation) => {
+                        simulation.call(contract_address, function_selector, &calldata_felts)
+                    }
+                    None => self.tokio_runtime.block_on(call::call(
+                        contract_address,
+                        function_selector,
+                        calldata_felts,
+                        self.provider,
+                        &BlockId::Tag(Pending),
+                    )),
+                };
                 Ok(CheatcodeHandlingResult::from_serializable(call_result))
             }
             "declare" => {
@@ -122,6 +150,18 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 let fee_args: FeeArgs = input_reader.read::<ScriptFeeSettings>()?.into();
                 let nonce = input_reader.read()?;
 
+                if let Some(simulation) = self.simulation.as_deref_mut() {
+                    let account_address = account_address?;
+                    let declare_result = simulation.declare(
+                        &contract,
+                        self.artifacts,
+                        fee_args,
+                        nonce,
+                        account_address,
+                    );
+                    return Ok(CheatcodeHandlingResult::from_serializable(declare_result));
+                }
+
                 let declare = Declare {
                     contract: contract.clone(),
                     fee_args,
@@ -135,6 +175,19 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 if let Some(success_output) =
                     self.state.get_output_if_success(declare_tx_id.as_str())
                 {
+                    if let ScriptTransactionOutput::DeclareResponse(response) = &success_output {
+                        self.manifest.record_declaration(
+                            &contract,
+                            response,
+                            |transaction_hash| {
+                                get_block_number(
+                                    &self.tokio_runtime,
+                                    self.provider,
+                                    transaction_hash,
+                                )
+                            },
+                        )?;
+                    }
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
@@ -154,6 +207,12 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     selector,
                     &declare_result,
                 )?;
+                if let Ok(response) = &declare_result {
+                    self.manifest
+                        .record_declaration(&contract, response, |transaction_hash| {
+                            get_block_number(&self.tokio_runtime, self.provider, transaction_hash)
+                        })?;
+                }
                 Ok(CheatcodeHandlingResult::from_serializable(declare_result))
             }
             "deploy" => {
@@ -164,12 +223,40 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 let fee_args: FeeArgs = input_reader.read::<ScriptFeeSettings>()?.into();
                 let nonce = input_reader.read()?;
 
+                if let Some(simulation) = self.simulation.as_deref_mut() {
+                    let account_address = account_address?;
+                    let deploy_result = simulation.deploy(
+                        class_hash,
+                        &constructor_calldata,
+                        salt,
+                        unique,
+                        fee_args,
+                        nonce,
+                        account_address,
+                    );
+                    return Ok(CheatcodeHandlingResult::from_serializable(deploy_result));
+                }
+
                 let deploy_tx_id =
                     generate_deploy_tx_id(class_hash, &constructor_calldata, salt, unique);
 
                 if let Some(success_output) =
                     self.state.get_output_if_success(deploy_tx_id.as_str())
                 {
+                    if let ScriptTransactionOutput::DeployResponse(response) = &success_output {
+                        self.manifest.record_deployment(
+                            class_hash,
+                            &constructor_calldata,
+                            response,
+                            |transaction_hash| {
+                                get_block_number(
+                                    &self.tokio_runtime,
+                                    self.provider,
+                                    transaction_hash,
+                                )
+                            },
+                        )?;
+                    }
                     return Ok(CheatcodeHandlingResult::from_serializable(success_output));
                 }
 
@@ -192,6 +279,16 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                     selector,
                     &deploy_result,
                 )?;
+                if let Ok(response) = &deploy_result {
+                    self.manifest.record_deployment(
+                        class_hash,
+                        &constructor_calldata,
+                        response,
+                        |transaction_hash| {
+                            get_block_number(&self.tokio_runtime, self.provider, transaction_hash)
+                        },
+                    )?;
+                }
 
                 Ok(CheatcodeHandlingResult::from_serializable(deploy_result))
             }
@@ -202,6 +299,19 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 let fee_args = input_reader.read::<ScriptFeeSettings>()?.into();
                 let nonce = input_reader.read()?;
 
+                if let Some(simulation) = self.simulation.as_deref_mut() {
+                    let account_address = account_address?;
+                    let invoke_result = simulation.invoke(
+                        contract_address,
+                        function_selector,
+                        calldata,
+                        fee_args,
+                        nonce,
+                        account_address,
+                    );
+                    return Ok(CheatcodeHandlingResult::from_serializable(invoke_result));
+                }
+
                 let invoke_tx_id =
                     generate_invoke_tx_id(contract_address, function_selector, &calldata);
 
@@ -236,23 +346,166 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
                 let block_id = as_cairo_short_string(&input_reader.read()?)
                     .expect("Failed to convert entry point name to short string");
 
-                let nonce = self.tokio_runtime.block_on(get_nonce(
-                    self.provider,
-                    &block_id,
-                    self.account()?.address(),
-                ))?;
+                let nonce = match self.simulation.as_deref() {
+                    Some(simulation) => simulation.get_nonce(account_address?)?,
+                    None => self.tokio_runtime.block_on(get_nonce(
+                        self.provider,
+                        &block_id,
+                        account_address?,
+                    ))?,
+                };
 
                 Ok(CheatcodeHandlingResult::from_serializable(nonce))
             }
             "tx_status" => {
                 let transaction_hash = input_reader.read()?;
 
-                let tx_status_result = self
-                    .tokio_runtime
-                    .block_on(tx_status::tx_status(self.provider, transaction_hash));
+                let tx_status_result = match self.simulation.as_deref() {
+                    Some(simulation) => simulation.tx_status(transaction_hash),
+                    None => self
+                        .tokio_runtime
+                        .block_on(tx_status::tx_status(self.provider, transaction_hash)),
+                };
 
                 Ok(CheatcodeHandlingResult::from_serializable(tx_status_result))
             }
+            "get_storage_at" => {
+                let contract_address = input_reader.read()?;
+                let key = input_reader.read()?;
+
+                let storage_result = match self.simulation.as_deref() {
+                    Some(simulation) => simulation
+                        .get_storage_at(contract_address, key)
+                        .map_err(StarknetCommandError::from),
+                    None => self.tokio_runtime.block_on(queries::get_storage_at(
+                        self.provider,
+                        contract_address,
+                        key,
+                    )),
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(storage_result))
+            }
+            "get_class_hash_at" => {
+                let contract_address = input_reader.read()?;
+
+                let class_hash_result = match self.simulation.as_deref() {
+                    Some(simulation) => simulation.get_class_hash_at(contract_address),
+                    None => self
+                        .tokio_runtime
+                        .block_on(queries::get_class_hash_at(self.provider, contract_address)),
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    class_hash_result,
+                ))
+            }
+            "get_block" => {
+                let block_id = input_reader.read::<ScriptBlockId>()?;
+
+                let block_result = self
+                    .tokio_runtime
+                    .block_on(queries::get_block(self.provider, block_id.into()));
+
+                Ok(CheatcodeHandlingResult::from_serializable(block_result))
+            }
+            "get_events" => {
+                let transaction_hash = input_reader.read()?;
+
+                let events_result = match self.simulation.as_deref() {
+                    Some(simulation) => simulation.get_events(transaction_hash),
+                    None => self
+                        .tokio_runtime
+                        .block_on(queries::get_events(self.provider, transaction_hash)),
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(events_result))
+            }
+            "estimate_fee" => {
+                let calls: Vec<Call> = input_reader
+                    .read::<Vec<ScriptCall>>()?
+                    .into_iter()
+                    .map(Call::from)
+                    .collect();
+
+                let estimate_fee_result = match self.simulation.as_deref_mut() {
+                    Some(simulation) => simulation.estimate_fee(&calls, account_address?),
+                    None => self
+                        .tokio_runtime
+                        .block_on(queries::estimate_fee(self.account()?, calls)),
+                };
+
+                Ok(CheatcodeHandlingResult::from_serializable(
+                    estimate_fee_result,
+                ))
+            }
+            "multicall" => {
+                let calls: Vec<Call> = input_reader
+                    .read::<Vec<ScriptCall>>()?
+                    .into_iter()
+                    .map(Call::from)
+                    .collect();
+                let fee_args = input_reader.read::<ScriptFeeSettings>()?.into();
+                let nonce = input_reader.read()?;
+
+                if let Some(simulation) = self.simulation.as_deref_mut() {
+                    let multicall_result =
+                        simulation.multicall(calls, fee_args, nonce, account_address?);
+                    return Ok(CheatcodeHandlingResult::from_serializable(multicall_result));
+                }
+
+                let multicall_tx_id = generate_multicall_tx_id(&calls);
+
+                if let Some(success_output) =
+                    self.state.get_output_if_success(multicall_tx_id.as_str())
+                {
+                    return Ok(CheatcodeHandlingResult::from_serializable(success_output));
+                }
+
+                let multicall_result = self.tokio_runtime.block_on(invoke::execute_calls(
+                    self.account()?,
+                    calls,
+                    fee_args,
+                    nonce,
+                    WaitForTx {
+                        wait: true,
+                        wait_params: self.config.wait_params,
+                    },
+                ));
+
+                self.state.maybe_insert_tx_entry(
+                    multicall_tx_id.as_str(),
+                    selector,
+                    &multicall_result,
+                )?;
+
+                Ok(CheatcodeHandlingResult::from_serializable(multicall_result))
+            }
+            "var" => {
+                let name: String = input_reader.read::<ByteArray>()?.to_string();
+
+                let env_var = std::env::var(&name)
+                    .with_context(|| format!("Failed to read from env var = {name}"))?;
+
+                let parsed_env_var = Felt::infer_format_and_parse(&env_var)
+                    .map_err(|_| anyhow!("Failed to parse value = {env_var} to felt"))?;
+
+                Ok(CheatcodeHandlingResult::Handled(parsed_env_var))
+            }
+            "read_txt" => {
+                let file_path = input_reader.read::<ByteArray>()?.to_string();
+                let parsed_content =
+                    file_operations::read_txt(self.package_root.join(file_path).into_string())?;
+
+                Ok(CheatcodeHandlingResult::Handled(parsed_content))
+            }
+            "read_json" => {
+                let file_path = input_reader.read::<ByteArray>()?.to_string();
+                let parsed_content =
+                    file_operations::read_json(self.package_root.join(file_path).into_string())?;
+
+                Ok(CheatcodeHandlingResult::Handled(parsed_content))
+            }
             _ => Ok(CheatcodeHandlingResult::Forwarded),
         };
 
@@ -274,6 +527,7 @@ impl<'a> ExtensionLogic for CastScriptExtension<'a> {
 #[expect(clippy::too_many_arguments)]
 pub fn run(
     module_name: &str,
+    script_args: &[String],
     metadata: &Metadata,
     package_metadata: &PackageMetadata,
     artifacts: &mut HashMap<String, StarknetContractArtifacts>,
@@ -281,6 +535,8 @@ pub fn run(
     tokio_runtime: Runtime,
     config: &CastConfig,
     state_file_path: Option<Utf8PathBuf>,
+    manifest: ManifestManager,
+    simulation: Option<&mut ScriptSimulation>,
 ) -> Result<ScriptRunResponse> {
     warn_if_sncast_std_not_compatible(metadata)?;
     let artifacts = inject_lib_artifact(metadata, package_metadata, artifacts)?;
@@ -303,15 +559,18 @@ pub fn run(
     )
     .with_context(|| "Failed to set up runner")?;
 
+    let name_suffix = module_name.to_string() + "::main";
+    let func = runner.find_function(name_suffix.as_str())
+        .context("Failed to find main function in script - please make sure `sierra-replace-ids` is not set to `false` for `dev` profile in script's Scarb.toml")?;
+
+    let user_args = build_user_args(&sierra_program, func, script_args)
+        .context("Failed to parse script arguments")?;
+
     // `builder` field in `SierraCasmRunner` is private, hence the need to create a new `RunnableBuilder`
     // https://github.com/starkware-libs/cairo/blob/66f5c7223f7a6c27c5f800816dba05df9b60674e/crates/cairo-lang-runner/src/lib.rs#L184
     let builder = RunnableBuilder::new(sierra_program, Some(MetadataComputationConfig::default()))
         .with_context(|| "Failed to create builder")?;
 
-    let name_suffix = module_name.to_string() + "::main";
-    let func = runner.find_function(name_suffix.as_str())
-        .context("Failed to find main function in script - please make sure `sierra-replace-ids` is not set to `false` for `dev` profile in script's Scarb.toml")?;
-
     let entry_code_config = EntryCodeConfig::testing();
     let casm_program_wrapper_info = builder.create_wrapper_info(func, entry_code_config)?;
     let entry_code = casm_program_wrapper_info.header;
@@ -368,16 +627,16 @@ pub fn run(
         artifacts: &artifacts,
         account: account.as_ref(),
         state,
+        manifest,
+        simulation,
+        package_root: package_metadata.root.clone(),
     };
 
     let mut cast_runtime = ExtendedRuntime {
         extension: cast_extension,
         extended_runtime: StarknetRuntime {
             hint_handler: syscall_handler,
-            // If for some reason we need to calculate `user_args`, here
-            // is the function to do it: https://github.com/starkware-libs/cairo/blob/66f5c7223f7a6c27c5f800816dba05df9b60674e/crates/cairo-lang-runner/src/lib.rs#L464
-            // See TODO(#2966)
-            user_args: vec![vec![Arg::Value(Felt::from(i64::MAX))]],
+            user_args,
         },
     };
 
@@ -402,6 +661,18 @@ pub fn run(
     }
 }
 
+/// Block number is only informative, so it is not set if the receipt could not be fetched
+fn get_block_number(
+    tokio_runtime: &Runtime,
+    provider: &JsonRpcClient<HttpTransport>,
+    transaction_hash: Felt,
+) -> Option<u64> {
+    tokio_runtime
+        .block_on(provider.get_transaction_receipt(transaction_hash))
+        .ok()
+        .and_then(|receipt| receipt.block.block_number())
+}
+
 fn sncast_std_version_requirement() -> VersionReq {
     let version = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
     let comparator = Comparator {
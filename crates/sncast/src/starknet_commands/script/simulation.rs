@@ -0,0 +1,842 @@
+use crate::starknet_commands::script::queries::{EstimateFeeResponse, EventResponse};
+use crate::starknet_commands::{declare, deploy, invoke};
+use anyhow::{Context, Result, anyhow, bail};
+use blockifier::execution::call_info::CallInfo;
+use blockifier::execution::contract_class::{CompiledClassV1, RunnableCompiledClass};
+use blockifier::execution::entry_point::{
+    CallEntryPoint, CallType, ConstructorContext, EntryPointExecutionContext,
+};
+use blockifier::state::cached_state::CachedState;
+use blockifier::state::state_api::{State, StateReader};
+use cairo_vm::vm::runners::cairo_runner::ExecutionResources;
+use camino::Utf8Path;
+use cheatnet::forking::state::ForkStateReader;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::execution::cheated_syscalls::execute_deployment;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::execution::entry_point::execute_call_entry_point;
+use cheatnet::runtime_extensions::call_to_blockifier_runtime_extension::rpc::{
+    AddressOrClassHash, CallFailure, UsedResources,
+};
+use cheatnet::runtime_extensions::common::create_execute_calldata;
+use cheatnet::state::{BlockInfoReader, CheatnetState, ExtendedStateReader};
+use conversions::byte_array::ByteArray;
+use conversions::IntoConv;
+use forge_runner::gas::calculate_used_gas;
+use runtime::starknet::context::build_context;
+use runtime::starknet::state::DictStateReader;
+use scarb_api::StarknetContractArtifacts;
+use shared::print::print_as_warning;
+use shared::utils::build_readable_text;
+use sncast::helpers::account::SncastAccount;
+use sncast::helpers::constants::UDC_ADDRESS;
+use sncast::helpers::fee::FeeArgs;
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::errors::{
+    SNCastProviderError, SNCastStarknetError, StarknetCommandError,
+    handle_starknet_command_error,
+};
+use sncast::response::print::{OutputFormat, print_command_result};
+use sncast::response::structs::{
+    AlreadyDeclaredResponse, CallResponse, CommandResponse, Decimal, DeclareResponse,
+    DeclareTransactionResponse, DeployResponse, ExecutionStatus, FinalityStatus, InvokeResponse,
+    ScriptSimulatedTransactionResponse, TransactionStatusResponse,
+};
+use sncast::{
+    ErrorData, NumbersFormat, ValidatedWaitParams, WaitForTx, extract_or_generate_salt,
+    handle_rpc_error, udc_uniqueness,
+};
+use starknet::core::types::contract::{CompiledClass, SierraClass};
+use starknet::core::types::{BlockId, Call, MaybePendingBlockWithTxHashes};
+use starknet::core::utils::get_udc_deployed_address;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet::providers::{JsonRpcClient, Provider};
+use starknet_api::block::{BlockInfo, BlockNumber};
+use starknet_api::contract_class::{EntryPointType, SierraVersion};
+use starknet_api::core::{ChainId, ClassHash, CompiledClassHash, ContractAddress};
+use starknet_api::state::StorageKey;
+use starknet_api::transaction::fields::Calldata;
+use starknet_types_core::felt::Felt;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use url::Url;
+
+#[derive(Debug, Clone)]
+pub enum SimulatedCall {
+    Declare {
+        contract: String,
+    },
+    Deploy {
+        class_hash: Felt,
+        constructor_calldata: Vec<Felt>,
+        salt: Felt,
+        unique: bool,
+    },
+    Invoke {
+        contract_address: Felt,
+        function_selector: Felt,
+        calldata: Vec<Felt>,
+    },
+    Multicall {
+        calls: Vec<Call>,
+    },
+}
+
+impl SimulatedCall {
+    fn name(&self) -> &'static str {
+        match self {
+            SimulatedCall::Declare { .. } => "declare",
+            SimulatedCall::Deploy { .. } => "deploy",
+            SimulatedCall::Invoke { .. } => "invoke",
+            SimulatedCall::Multicall { .. } => "multicall",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulatedTransaction {
+    pub call: SimulatedCall,
+    pub fee_args: FeeArgs,
+    pub class_hash: Option<Felt>,
+    pub contract_address: Option<Felt>,
+    /// Fee in FRI or the error the transaction failed with
+    pub result: Result<Felt, String>,
+    pub events: Vec<EventResponse>,
+}
+
+impl SimulatedTransaction {
+    fn response(&self, index: usize) -> ScriptSimulatedTransactionResponse {
+        let (status, estimated_fee, error) = match &self.result {
+            Ok(fee) => (ExecutionStatus::Succeeded, Some(format!("{fee:#}")), None),
+            Err(error) => (ExecutionStatus::Reverted, None, Some(error.clone())),
+        };
+
+        ScriptSimulatedTransactionResponse {
+            transaction: self.call.name().to_string(),
+            index: Decimal(index as u64),
+            status,
+            class_hash: self.class_hash.map(IntoConv::into_),
+            contract_address: self.contract_address.map(IntoConv::into_),
+            estimated_fee,
+            error,
+        }
+    }
+}
+
+struct GasPrices {
+    l1_gas: Felt,
+    l1_data_gas: Felt,
+}
+
+/// Executes transactions sent by a script against a state forked from the network,
+/// recording them so they can be broadcast once the whole script succeeds
+pub struct ScriptSimulation {
+    state: CachedState<ExtendedStateReader>,
+    cheatnet_state: CheatnetState,
+    block_info: BlockInfo,
+    chain_id: ChainId,
+    gas_prices: GasPrices,
+    transactions: Vec<SimulatedTransaction>,
+}
+
+impl ScriptSimulation {
+    pub fn new(
+        url: &str,
+        provider: &JsonRpcClient<HttpTransport>,
+        tokio_runtime: &Runtime,
+        cache_dir: &Utf8Path,
+    ) -> Result<Self> {
+        let block_number = tokio_runtime
+            .block_on(provider.block_number())
+            .map_err(handle_rpc_error)?;
+        let MaybePendingBlockWithTxHashes::Block(block) = tokio_runtime
+            .block_on(provider.get_block_with_tx_hashes(BlockId::Number(block_number)))
+            .map_err(handle_rpc_error)?
+        else {
+            bail!("Failed to fetch block {block_number} to fork from")
+        };
+
+        let url = Url::parse(url).with_context(|| format!("Failed to parse RPC url {url}"))?;
+        let mut fork_state_reader =
+            ForkStateReader::new(url, BlockNumber(block_number), cache_dir)?;
+        let chain_id = fork_state_reader.chain_id()?;
+        let block_info = fork_state_reader.get_block_info()?;
+
+        Ok(Self {
+            state: CachedState::new(ExtendedStateReader {
+                dict_state_reader: DictStateReader::default(),
+                fork_state_reader: Some(fork_state_reader),
+            }),
+            cheatnet_state: CheatnetState {
+                block_info: block_info.clone(),
+                ..Default::default()
+            },
+            block_info,
+            chain_id,
+            gas_prices: GasPrices {
+                l1_gas: block.l1_gas_price.price_in_fri,
+                l1_data_gas: block.l1_data_gas_price.price_in_fri,
+            },
+            transactions: vec![],
+        })
+    }
+
+    #[must_use]
+    pub fn transactions(&self) -> &[SimulatedTransaction] {
+        &self.transactions
+    }
+
+    pub fn declare(
+        &mut self,
+        contract: &str,
+        artifacts: &HashMap<String, StarknetContractArtifacts>,
+        fee_args: FeeArgs,
+        nonce: Option<Felt>,
+        account_address: Felt,
+    ) -> Result<DeclareResponse, StarknetCommandError> {
+        let contract_artifacts =
+            artifacts
+                .get(contract)
+                .ok_or(StarknetCommandError::ContractArtifactsNotFound(ErrorData {
+                    data: ByteArray::from(contract),
+                }))?;
+
+        let sierra_class: SierraClass = serde_json::from_str(&contract_artifacts.sierra)
+            .context("Failed to parse sierra artifact")?;
+        let casm_class: CompiledClass = serde_json::from_str(&contract_artifacts.casm)
+            .context("Failed to parse casm artifact")?;
+        let class_hash = sierra_class.class_hash().map_err(anyhow::Error::from)?;
+        let casm_class_hash = casm_class.class_hash().map_err(anyhow::Error::from)?;
+
+        if self.state.get_compiled_class(class_hash.into_()).is_ok() {
+            return Ok(DeclareResponse::AlreadyDeclared(AlreadyDeclaredResponse {
+                class_hash: class_hash.into_(),
+            }));
+        }
+
+        let compiled_class =
+            CompiledClassV1::try_from_json_string(&contract_artifacts.casm, SierraVersion::LATEST)
+                .context("Failed to read contract class from casm artifact")?;
+
+        let call = SimulatedCall::Declare {
+            contract: contract.to_string(),
+        };
+        let transaction_hash = self.execute(
+            call,
+            fee_args,
+            nonce,
+            account_address,
+            (Some(class_hash), None),
+            |state, _, _| {
+                state
+                    .set_contract_class(
+                        class_hash.into_(),
+                        RunnableCompiledClass::V1(compiled_class),
+                    )
+                    .map_err(|error| error.to_string())?;
+                state
+                    .set_compiled_class_hash(class_hash.into_(), CompiledClassHash(casm_class_hash))
+                    .map_err(|error| error.to_string())?;
+                Ok(vec![])
+            },
+        )?;
+
+        Ok(DeclareResponse::Success(DeclareTransactionResponse {
+            class_hash: class_hash.into_(),
+            transaction_hash: transaction_hash.into_(),
+        }))
+    }
+
+    #[expect(clippy::too_many_arguments)]
+    pub fn deploy(
+        &mut self,
+        class_hash: Felt,
+        constructor_calldata: &[Felt],
+        salt: Option<Felt>,
+        unique: bool,
+        fee_args: FeeArgs,
+        nonce: Option<Felt>,
+        account_address: Felt,
+    ) -> Result<DeployResponse, StarknetCommandError> {
+        let salt = extract_or_generate_salt(salt);
+        let contract_address = get_udc_deployed_address(
+            salt,
+            class_hash,
+            &udc_uniqueness(unique, account_address),
+            constructor_calldata,
+        );
+
+        let call = SimulatedCall::Deploy {
+            class_hash,
+            constructor_calldata: constructor_calldata.to_vec(),
+            salt,
+            unique,
+        };
+        let transaction_hash = self.execute(
+            call,
+            fee_args,
+            nonce,
+            account_address,
+            (Some(class_hash), Some(contract_address)),
+            |state, cheatnet_state, context| {
+                let contract_address: ContractAddress = contract_address.into_();
+                let constructor_context = ConstructorContext {
+                    class_hash: class_hash.into_(),
+                    code_address: Some(contract_address),
+                    storage_address: contract_address,
+                    caller_address: UDC_ADDRESS.into_(),
+                };
+
+                execute_deployment(
+                    state,
+                    cheatnet_state,
+                    context,
+                    &constructor_context,
+                    Calldata(Arc::new(constructor_calldata.to_vec())),
+                    i64::MAX as u64,
+                )
+                .map(|call_info| vec![call_info])
+                .map_err(|error| {
+                    failure_message(&CallFailure::from_execution_error(
+                        &error,
+                        &AddressOrClassHash::ContractAddress(contract_address),
+                    ))
+                })
+            },
+        )?;
+
+        Ok(DeployResponse {
+            contract_address: contract_address.into_(),
+            transaction_hash: transaction_hash.into_(),
+        })
+    }
+
+    pub fn invoke(
+        &mut self,
+        contract_address: Felt,
+        function_selector: Felt,
+        calldata: Vec<Felt>,
+        fee_args: FeeArgs,
+        nonce: Option<Felt>,
+        account_address: Felt,
+    ) -> Result<InvokeResponse, StarknetCommandError> {
+        let mut entry_point = entry_point(
+            contract_address,
+            function_selector,
+            &calldata,
+            account_address,
+        );
+
+        let call = SimulatedCall::Invoke {
+            contract_address,
+            function_selector,
+            calldata,
+        };
+        let transaction_hash = self.execute(
+            call,
+            fee_args,
+            nonce,
+            account_address,
+            (None, Some(contract_address)),
+            |state, cheatnet_state, context| {
+                execute_call(&mut entry_point, state, cheatnet_state, context)
+                    .map(|call_info| vec![call_info])
+            },
+        )?;
+
+        Ok(InvokeResponse {
+            transaction_hash: transaction_hash.into_(),
+        })
+    }
+
+    pub fn multicall(
+        &mut self,
+        calls: Vec<Call>,
+        fee_args: FeeArgs,
+        nonce: Option<Felt>,
+        account_address: Felt,
+    ) -> Result<InvokeResponse, StarknetCommandError> {
+        let mut entry_points = multicall_entry_points(&calls, account_address);
+
+        let call = SimulatedCall::Multicall { calls };
+        let transaction_hash = self.execute(
+            call,
+            fee_args,
+            nonce,
+            account_address,
+            (None, None),
+            |state, cheatnet_state, context| {
+                entry_points
+                    .iter_mut()
+                    .map(|entry_point| execute_call(entry_point, state, cheatnet_state, context))
+                    .collect()
+            },
+        )?;
+
+        Ok(InvokeResponse {
+            transaction_hash: transaction_hash.into_(),
+        })
+    }
+
+    /// Estimates the fee of an invoke transaction executing `calls`, changes made by it are discarded
+    pub fn estimate_fee(
+        &mut self,
+        calls: &[Call],
+        account_address: Felt,
+    ) -> Result<EstimateFeeResponse, StarknetCommandError> {
+        let mut entry_points = multicall_entry_points(calls, account_address);
+        let mut context = build_context(&self.block_info, Some(self.chain_id.clone()));
+        let mut state = CachedState::create_transactional(&mut self.state);
+
+        let result = execute_transaction(
+            &mut state,
+            &mut self.cheatnet_state,
+            &mut context,
+            &self.gas_prices,
+            account_address,
+            |state, cheatnet_state, context| {
+                entry_points
+                    .iter_mut()
+                    .map(|entry_point| execute_call(entry_point, state, cheatnet_state, context))
+                    .collect()
+            },
+        );
+        state.abort();
+
+        result
+            .map(|(overall_fee, _)| EstimateFeeResponse { overall_fee })
+            .map_err(|error| anyhow!("Transaction simulation failed: {error}").into())
+    }
+
+    pub fn get_storage_at(&self, contract_address: Felt, key: Felt) -> Result<Felt> {
+        let key = StorageKey::try_from(key).context("Invalid storage key")?;
+        Ok(self.state.get_storage_at(contract_address.into_(), key)?)
+    }
+
+    pub fn get_class_hash_at(&self, contract_address: Felt) -> Result<Felt, StarknetCommandError> {
+        let class_hash = self
+            .state
+            .get_class_hash_at(contract_address.into_())
+            .map_err(anyhow::Error::from)?;
+
+        if class_hash == ClassHash::default() {
+            return Err(StarknetCommandError::ProviderError(
+                SNCastProviderError::StarknetError(SNCastStarknetError::ContractNotFound),
+            ));
+        }
+        Ok(class_hash.0)
+    }
+
+    /// Events emitted by a simulated transaction, identified by its consecutive number
+    pub fn get_events(
+        &self,
+        transaction_hash: Felt,
+    ) -> Result<Vec<EventResponse>, StarknetCommandError> {
+        Ok(self.transaction(transaction_hash)?.events.clone())
+    }
+
+    /// Calls a contract on the simulated state, changes made by the call are discarded
+    pub fn call(
+        &mut self,
+        contract_address: Felt,
+        function_selector: Felt,
+        calldata: &[Felt],
+    ) -> Result<CallResponse, StarknetCommandError> {
+        let mut entry_point = entry_point(
+            contract_address,
+            function_selector,
+            calldata,
+            ContractAddress::default().into_(),
+        );
+        let mut context = build_context(&self.block_info, Some(self.chain_id.clone()));
+        let mut state = CachedState::create_transactional(&mut self.state);
+
+        let result = execute_call_entry_point(
+            &mut entry_point,
+            &mut state,
+            &mut self.cheatnet_state,
+            &mut context,
+        );
+        state.abort();
+
+        result
+            .map(|call_info| CallResponse {
+                response: call_info.execution.retdata.0,
+            })
+            .map_err(|error| {
+                anyhow!(failure_message(&CallFailure::from_execution_error(
+                    &error,
+                    &AddressOrClassHash::ContractAddress(contract_address.into_()),
+                )))
+                .into()
+            })
+    }
+
+    pub fn get_nonce(&self, account_address: Felt) -> Result<Felt> {
+        Ok(self.state.get_nonce_at(account_address.into_())?.0)
+    }
+
+    /// Simulated transactions are identified by their consecutive numbers instead of hashes
+    pub fn tx_status(
+        &self,
+        transaction_hash: Felt,
+    ) -> Result<TransactionStatusResponse, StarknetCommandError> {
+        let transaction = self.transaction(transaction_hash)?;
+
+        Ok(TransactionStatusResponse {
+            finality_status: FinalityStatus::AcceptedOnL2,
+            execution_status: Some(if transaction.result.is_ok() {
+                ExecutionStatus::Succeeded
+            } else {
+                ExecutionStatus::Reverted
+            }),
+        })
+    }
+
+    fn transaction(
+        &self,
+        transaction_hash: Felt,
+    ) -> Result<&SimulatedTransaction, StarknetCommandError> {
+        usize::try_from(transaction_hash.to_biguint())
+            .ok()
+            .and_then(|index| index.checked_sub(1))
+            .and_then(|index| self.transactions.get(index))
+            .ok_or(StarknetCommandError::ProviderError(
+                SNCastProviderError::StarknetError(SNCastStarknetError::TransactionHashNotFound),
+            ))
+    }
+
+    fn execute(
+        &mut self,
+        call: SimulatedCall,
+        fee_args: FeeArgs,
+        nonce: Option<Felt>,
+        account_address: Felt,
+        (class_hash, contract_address): (Option<Felt>, Option<Felt>),
+        execution: impl FnOnce(
+            &mut dyn State,
+            &mut CheatnetState,
+            &mut EntryPointExecutionContext,
+        ) -> Result<Vec<CallInfo>, String>,
+    ) -> Result<Felt, StarknetCommandError> {
+        let account_nonce = self.get_nonce(account_address)?;
+        if nonce.is_some_and(|nonce| nonce != account_nonce) {
+            return Err(StarknetCommandError::ProviderError(
+                SNCastProviderError::StarknetError(SNCastStarknetError::InvalidTransactionNonce),
+            ));
+        }
+
+        let mut context = build_context(&self.block_info, Some(self.chain_id.clone()));
+        let mut state = CachedState::create_transactional(&mut self.state);
+
+        let result = execute_transaction(
+            &mut state,
+            &mut self.cheatnet_state,
+            &mut context,
+            &self.gas_prices,
+            account_address,
+            execution,
+        )
+        .and_then(|(fee, call_infos)| {
+            check_fee_limit(fee, &fee_args)?;
+            Ok((fee, call_infos))
+        });
+
+        if result.is_ok() {
+            state.commit();
+        } else {
+            state.abort();
+        }
+
+        let (result, events) = match result {
+            Ok((fee, call_infos)) => (Ok(fee), emitted_events(&call_infos)),
+            Err(error) => (Err(error), vec![]),
+        };
+        let error = result.as_ref().err().cloned();
+        self.transactions.push(SimulatedTransaction {
+            call,
+            fee_args,
+            class_hash,
+            contract_address,
+            result,
+            events,
+        });
+
+        match error {
+            None => Ok(Felt::from(self.transactions.len())),
+            Some(error) => Err(anyhow!("Transaction simulation failed: {error}").into()),
+        }
+    }
+}
+
+fn entry_point(
+    contract_address: Felt,
+    function_selector: Felt,
+    calldata: &[Felt],
+    caller_address: Felt,
+) -> CallEntryPoint {
+    CallEntryPoint {
+        class_hash: None,
+        code_address: Some(contract_address.into_()),
+        entry_point_type: EntryPointType::External,
+        entry_point_selector: function_selector.into_(),
+        calldata: create_execute_calldata(calldata),
+        storage_address: contract_address.into_(),
+        caller_address: caller_address.into_(),
+        call_type: CallType::Call,
+        initial_gas: i64::MAX as u64,
+    }
+}
+
+/// Executes a transaction of `account_address` on `state`,
+/// returns its fee in FRI and infos of the executed calls
+fn execute_transaction<S: StateReader>(
+    state: &mut CachedState<S>,
+    cheatnet_state: &mut CheatnetState,
+    context: &mut EntryPointExecutionContext,
+    gas_prices: &GasPrices,
+    account_address: Felt,
+    execution: impl FnOnce(
+        &mut dyn State,
+        &mut CheatnetState,
+        &mut EntryPointExecutionContext,
+    ) -> Result<Vec<CallInfo>, String>,
+) -> Result<(Felt, Vec<CallInfo>), String> {
+    let call_infos = execution(state, cheatnet_state, context)?;
+
+    state
+        .increment_nonce(account_address.into_())
+        .map_err(|error| error.to_string())?;
+    let gas = calculate_used_gas(&context.tx_context, state, used_resources(&call_infos))
+        .map_err(|error| error.to_string())?;
+
+    let fee = Felt::from(gas.l1_gas.0) * gas_prices.l1_gas
+        + Felt::from(gas.l1_data_gas.0) * gas_prices.l1_data_gas;
+    Ok((fee, call_infos))
+}
+
+/// Fails if the estimated fee exceeds the limit set by `--max-fee`, as the transaction
+/// would be rejected by the network. Limits set with `--max-gas` and `--max-gas-unit-price`
+/// bound L2 gas, which the simulation does not estimate, so they are not checked.
+fn check_fee_limit(fee: Felt, fee_args: &FeeArgs) -> Result<(), String> {
+    let Some(max_fee) = fee_args.max_fee else {
+        return Ok(());
+    };
+    let max_fee = Felt::from(max_fee);
+
+    if fee > max_fee {
+        return Err(format!(
+            "Estimated fee {fee} exceeds the max fee {max_fee} set for the transaction"
+        ));
+    }
+    Ok(())
+}
+
+fn execute_call(
+    entry_point: &mut CallEntryPoint,
+    state: &mut dyn State,
+    cheatnet_state: &mut CheatnetState,
+    context: &mut EntryPointExecutionContext,
+) -> Result<CallInfo, String> {
+    let contract_address = entry_point.storage_address;
+    execute_call_entry_point(entry_point, state, cheatnet_state, context).map_err(|error| {
+        failure_message(&CallFailure::from_execution_error(
+            &error,
+            &AddressOrClassHash::ContractAddress(contract_address),
+        ))
+    })
+}
+
+fn multicall_entry_points(calls: &[Call], account_address: Felt) -> Vec<CallEntryPoint> {
+    calls
+        .iter()
+        .map(|call| entry_point(call.to, call.selector, &call.calldata, account_address))
+        .collect()
+}
+
+fn used_resources(call_infos: &[CallInfo]) -> UsedResources {
+    let mut execution_resources = ExecutionResources::default();
+    for call_info in call_infos {
+        execution_resources += &call_info.resources;
+    }
+
+    UsedResources {
+        execution_resources,
+        l2_to_l1_payload_lengths: call_infos
+            .iter()
+            .flat_map(CallInfo::iter)
+            .flat_map(|call| &call.execution.l2_to_l1_messages)
+            .map(|message| message.message.payload.0.len())
+            .collect(),
+        events: call_infos
+            .iter()
+            .flat_map(CallInfo::iter)
+            .flat_map(|call| &call.execution.events)
+            .map(|event| event.event.clone())
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Events emitted by the calls, in the order of emission
+fn emitted_events(call_infos: &[CallInfo]) -> Vec<EventResponse> {
+    let mut events: Vec<_> = call_infos
+        .iter()
+        .flat_map(CallInfo::iter)
+        .flat_map(|call| {
+            call.execution
+                .events
+                .iter()
+                .map(move |event| (event.order, call.call.storage_address, &event.event))
+        })
+        .collect();
+    events.sort_by_key(|(order, _, _)| *order);
+
+    events
+        .into_iter()
+        .map(|(_, from_address, event)| EventResponse {
+            from_address: from_address.into_(),
+            keys: event.keys.iter().map(|key| key.0).collect(),
+            data: event.data.0.clone(),
+        })
+        .collect()
+}
+
+fn failure_message(failure: &CallFailure) -> String {
+    match failure {
+        CallFailure::Panic { panic_data } => {
+            build_readable_text(panic_data).unwrap_or_else(|| format!("{panic_data:?}"))
+        }
+        CallFailure::Error { msg } => msg.to_string(),
+    }
+}
+
+/// Sends transactions that succeeded during the simulation to the network, in the same order
+pub async fn broadcast(
+    transactions: &[SimulatedTransaction],
+    account: &SncastAccount<&JsonRpcClient<HttpTransport>>,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+    wait_params: ValidatedWaitParams,
+    numbers_format: NumbersFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let wait_config = WaitForTx {
+        wait: true,
+        wait_params,
+    };
+
+    for transaction in transactions.iter().filter(|tx| tx.result.is_ok()) {
+        let fee_args = transaction.fee_args.clone();
+
+        match &transaction.call {
+            SimulatedCall::Declare { contract } => {
+                let result = declare::declare(
+                    declare::Declare {
+                        contract: contract.clone(),
+                        fee_args,
+                        nonce: None,
+                        package: None,
+                        rpc: RpcArgs::default(),
+                    },
+                    account,
+                    artifacts,
+                    wait_config,
+                    true,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+                print_broadcast_result("declare", &result, numbers_format, output_format)?;
+            }
+            SimulatedCall::Deploy {
+                class_hash,
+                constructor_calldata,
+                salt,
+                unique,
+            } => {
+                let result = deploy::deploy(
+                    *class_hash,
+                    constructor_calldata,
+                    Some(*salt),
+                    *unique,
+                    fee_args,
+                    None,
+                    account,
+                    wait_config,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+                print_broadcast_result("deploy", &result, numbers_format, output_format)?;
+            }
+            SimulatedCall::Invoke {
+                contract_address,
+                function_selector,
+                calldata,
+            } => {
+                let result = invoke::invoke(
+                    *contract_address,
+                    calldata.clone(),
+                    None,
+                    fee_args,
+                    *function_selector,
+                    account,
+                    wait_config,
+                )
+                .await
+                .map_err(handle_starknet_command_error);
+                print_broadcast_result("invoke", &result, numbers_format, output_format)?;
+            }
+            SimulatedCall::Multicall { calls } => {
+                let result =
+                    invoke::execute_calls(account, calls.clone(), fee_args, None, wait_config)
+                        .await
+                        .map_err(handle_starknet_command_error);
+                print_broadcast_result("multicall", &result, numbers_format, output_format)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_broadcast_result<T: CommandResponse>(
+    command: &str,
+    result: &Result<T>,
+    numbers_format: NumbersFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    print_command_result(command, result, numbers_format, output_format)?;
+    if result.is_err() {
+        bail!("Failed to broadcast `{command}` transaction, remaining transactions were not sent");
+    }
+    Ok(())
+}
+
+pub fn print_simulated_transactions(
+    transactions: &[SimulatedTransaction],
+    numbers_format: NumbersFormat,
+    output_format: OutputFormat,
+) -> Result<()> {
+    for (index, transaction) in transactions.iter().enumerate() {
+        print_command_result(
+            "script run",
+            &Ok(transaction.response(index + 1)),
+            numbers_format,
+            output_format,
+        )?;
+    }
+
+    if !transactions.is_empty() {
+        print_as_warning(&anyhow!(
+            "Simulation does not run `__validate__` and `__execute__` of the account and estimates fees without L2 gas, transactions may still fail or cost more on the network"
+        ));
+    }
+    if transactions.iter().any(|transaction| {
+        transaction.fee_args.max_fee.is_none()
+            && (transaction.fee_args.max_gas.is_some()
+                || transaction.fee_args.max_gas_unit_price.is_some())
+    }) {
+        print_as_warning(&anyhow!(
+            "Limits set with `max_gas` and `max_gas_unit_price` are not checked in the simulation, only `max_fee` is"
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anyhow::{Result, ensure};
+use camino::Utf8PathBuf;
+use clap::{Args, Subcommand};
+use sncast::helpers::rpc::RpcArgs;
+use sncast::response::structs::{
+    Decimal, DeclareResponse, ScriptStateResponse, ScriptStateTransactionResponse,
+};
+use sncast::state::state_file::{
+    ScriptTransactionEntry, ScriptTransactionOutput, prune_state_file, read_txs_from_state_file,
+};
+
+#[derive(Args, Debug)]
+#[command(about = "Inspect and clean up state files of scripts")]
+pub struct State {
+    #[clap(subcommand)]
+    pub command: StateCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StateCommands {
+    Show(Show),
+    Prune(Prune),
+}
+
+#[derive(Args, Debug)]
+pub struct ScriptStateArgs {
+    /// Module name of the script
+    pub script_name: String,
+
+    /// Specifies scarb package to be used
+    #[clap(long)]
+    pub package: Option<String>,
+
+    #[clap(flatten)]
+    pub rpc: RpcArgs,
+}
+
+#[derive(Args, Debug)]
+#[command(about = "Show transactions recorded in the state file of a script")]
+pub struct Show {
+    #[clap(flatten)]
+    pub args: ScriptStateArgs,
+}
+
+#[derive(Args, Debug)]
+#[command(
+    about = "Remove transactions which did not succeed from the state file of a script, so they are sent again on the next run"
+)]
+pub struct Prune {
+    /// Remove all transactions, including the successful ones
+    #[clap(long)]
+    pub all: bool,
+
+    #[clap(flatten)]
+    pub args: ScriptStateArgs,
+}
+
+impl StateCommands {
+    #[must_use]
+    pub fn args(&self) -> &ScriptStateArgs {
+        match self {
+            StateCommands::Show(show) => &show.args,
+            StateCommands::Prune(prune) => &prune.args,
+        }
+    }
+}
+
+/// Returns transactions recorded in the state file, ordered by the time of execution
+pub fn show(state_file_path: &Utf8PathBuf) -> Result<Vec<ScriptStateTransactionResponse>> {
+    ensure!(
+        state_file_path.exists(),
+        "State file {state_file_path} does not exist"
+    );
+
+    let mut entries: Vec<_> = read_txs_from_state_file(state_file_path)?
+        .map(|entries| entries.transactions.into_iter().collect())
+        .unwrap_or_default();
+    entries.sort_by(|(id_a, entry_a), (id_b, entry_b)| {
+        (entry_a.timestamp, id_a).cmp(&(entry_b.timestamp, id_b))
+    });
+
+    Ok(entries
+        .into_iter()
+        .map(|(id, entry)| transaction_response(id, entry))
+        .collect())
+}
+
+pub fn prune(prune: &Prune, state_file_path: &Utf8PathBuf) -> Result<ScriptStateResponse> {
+    ensure!(
+        state_file_path.exists(),
+        "State file {state_file_path} does not exist"
+    );
+
+    let removed = prune_state_file(state_file_path, prune.all)?;
+
+    Ok(ScriptStateResponse {
+        message: format!("Removed {removed} transaction(s) from state file {state_file_path}"),
+    })
+}
+
+fn transaction_response(
+    id: String,
+    entry: ScriptTransactionEntry,
+) -> ScriptStateTransactionResponse {
+    let mut response = ScriptStateTransactionResponse {
+        id,
+        name: entry.name,
+        status: entry.status,
+        timestamp: Decimal(entry.timestamp),
+        class_hash: None,
+        contract_address: None,
+        transaction_hash: None,
+        error: None,
+    };
+
+    match entry.output {
+        ScriptTransactionOutput::InvokeResponse(output) => {
+            response.transaction_hash = Some(output.transaction_hash);
+        }
+        ScriptTransactionOutput::DeclareResponse(DeclareResponse::Success(output)) => {
+            response.class_hash = Some(output.class_hash);
+            response.transaction_hash = Some(output.transaction_hash);
+        }
+        ScriptTransactionOutput::DeclareResponse(DeclareResponse::AlreadyDeclared(output)) => {
+            response.class_hash = Some(output.class_hash);
+        }
+        ScriptTransactionOutput::DeployResponse(output) => {
+            response.contract_address = Some(output.contract_address);
+            response.transaction_hash = Some(output.transaction_hash);
+        }
+        ScriptTransactionOutput::ErrorResponse(output) => {
+            response.error = Some(output.message);
+        }
+    }
+
+    response
+}
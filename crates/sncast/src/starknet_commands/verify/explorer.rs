@@ -1,8 +1,11 @@
-use anyhow::Result;
-use camino::Utf8PathBuf;
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8Path;
+use reqwest::{RequestBuilder, StatusCode};
 use serde::Serialize;
-use sncast::{Network, response::structs::VerifyResponse};
-use starknet_types_core::felt::Felt;
+use serde::de::DeserializeOwned;
+use sncast::Network;
+use std::ffi::OsStr;
+use walkdir::WalkDir;
 
 #[derive(Serialize, Debug)]
 pub struct VerificationPayload {
@@ -11,10 +14,97 @@ pub struct VerificationPayload {
     pub source_code: serde_json::Value,
 }
 
+/// Outcome of submitting the source code to a verification provider
+#[derive(Debug, PartialEq)]
+pub enum VerificationSubmission {
+    /// The provider does not report the progress of the verification
+    Finished(String),
+    /// The provider started a verification job which has to be polled for its status
+    Pending { job_id: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum VerificationStatus {
+    InProgress,
+    Success(String),
+    Failure(String),
+}
+
 #[async_trait::async_trait]
 pub trait VerificationInterface {
-    fn new(network: Network, workspace_dir: Utf8PathBuf) -> Self;
-    async fn verify(&self, contract_address: Felt, contract_name: String)
-    -> Result<VerifyResponse>;
-    fn gen_explorer_url(&self) -> Result<String>;
+    /// `api_url` overrides the default API base url of the provider
+    fn new(network: Network, api_url: Option<String>) -> Self
+    where
+        Self: Sized;
+    async fn submit(&self, payload: &VerificationPayload) -> Result<VerificationSubmission>;
+    async fn status(&self, job_id: &str) -> Result<VerificationStatus>;
+    fn gen_explorer_url(&self) -> String;
+}
+
+/// Submits the payload to the verification endpoint of a verifier API and parses its JSON response
+pub async fn submit_to_verifier<T: DeserializeOwned>(
+    url: &str,
+    payload: &VerificationPayload,
+) -> Result<T> {
+    let request = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(serde_json::to_string(payload)?);
+
+    send_verifier_request(request, "Failed to verify contract").await
+}
+
+/// Fetches the status of a verification job from a verifier API and parses its JSON response
+pub async fn fetch_verification_status<T: DeserializeOwned>(url: &str) -> Result<T> {
+    let request = reqwest::Client::new().get(url);
+
+    send_verifier_request(request, "Failed to get verification status").await
+}
+
+/// Sends the request, failing with the response body if the API did not respond with `200 OK`
+async fn send_verifier_request<T: DeserializeOwned>(
+    request: RequestBuilder,
+    error_context: &'static str,
+) -> Result<T> {
+    let api_res = request
+        .send()
+        .await
+        .context("Failed to send request to verifier API")?;
+
+    if api_res.status() != StatusCode::OK {
+        let message = api_res.text().await.context(error_context)?;
+        return Err(anyhow!(message));
+    }
+
+    let body = api_res
+        .text()
+        .await
+        .context("Failed to read verifier API response")?;
+    serde_json::from_str(&body)
+        .with_context(|| format!("Failed to parse verifier API response: {body}"))
+}
+
+/// Reads all Cairo and TOML files in the workspace recursively,
+/// keyed by their paths relative to the workspace dir
+pub fn read_source_code(workspace_dir: &Utf8Path) -> Result<serde_json::Value> {
+    let mut file_data = serde_json::Map::new();
+
+    for entry in WalkDir::new(workspace_dir).follow_links(true) {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_file() {
+            if let Some(extension) = path.extension() {
+                if extension == OsStr::new("cairo") || extension == OsStr::new("toml") {
+                    let relative_path = path.strip_prefix(workspace_dir)?;
+                    let file_content = std::fs::read_to_string(path)?;
+                    file_data.insert(
+                        relative_path.to_string_lossy().into_owned(),
+                        serde_json::Value::String(file_content),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(serde_json::Value::Object(file_data))
 }
@@ -0,0 +1,284 @@
+use anyhow::{Context, Result, anyhow, bail};
+use conversions::IntoConv;
+use scarb_api::StarknetContractArtifacts;
+use serde_json::Value;
+use sncast::response::structs::LocalVerifyResponse;
+use sncast::{get_class_hash_by_address, get_contract_class};
+use starknet::core::types::contract::{CompiledClass, SierraClass};
+use starknet::core::types::{ContractClass, FlattenedSierraClass, SierraEntryPoint};
+use starknet::core::utils::get_selector_from_name;
+use starknet::providers::JsonRpcClient;
+use starknet::providers::jsonrpc::HttpTransport;
+use starknet_types_core::felt::Felt;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Builds the class from local artifacts and compares its class hash with the class hash on chain.
+/// The compiled class hash is only computed from the local build, as the network does not expose it.
+pub async fn verify(
+    contract_address: Option<Felt>,
+    class_hash: Option<Felt>,
+    contract_name: &str,
+    artifacts: &HashMap<String, StarknetContractArtifacts>,
+    provider: &JsonRpcClient<HttpTransport>,
+) -> Result<LocalVerifyResponse> {
+    let artifact = artifacts
+        .get(contract_name)
+        .ok_or_else(|| anyhow!("Contract named '{contract_name}' was not found"))?;
+
+    let sierra_class: SierraClass =
+        serde_json::from_str(&artifact.sierra).context("Failed to parse sierra artifact")?;
+    let compiled_class: CompiledClass =
+        serde_json::from_str(&artifact.casm).context("Failed to parse casm artifact")?;
+
+    let local_class_hash = sierra_class.class_hash().map_err(anyhow::Error::from)?;
+    let compiled_class_hash = compiled_class.class_hash().map_err(anyhow::Error::from)?;
+
+    let on_chain_class_hash = match (class_hash, contract_address) {
+        (Some(class_hash), _) => class_hash,
+        (None, Some(contract_address)) => {
+            get_class_hash_by_address(provider, contract_address).await?
+        }
+        (None, None) => bail!("Either `--contract-address` or `--class-hash` must be provided"),
+    };
+
+    let on_chain_class = match get_contract_class(on_chain_class_hash, provider).await? {
+        ContractClass::Sierra(class) => class,
+        ContractClass::Legacy(_) => bail!(
+            "Class with hash {on_chain_class_hash:#x} is a Cairo 0 class and cannot be compared with the local build"
+        ),
+    };
+
+    if local_class_hash == on_chain_class_hash {
+        return Ok(LocalVerifyResponse {
+            message: format!(
+                "Class hash {on_chain_class_hash:#x} on chain matches the local build of {contract_name}, compiled class hash is computed locally and not compared"
+            ),
+            class_hash: local_class_hash.into_(),
+            compiled_class_hash: compiled_class_hash.into_(),
+        });
+    }
+
+    let local_class = sierra_class.flatten().map_err(anyhow::Error::from)?;
+    let differences = class_differences(&local_class, &on_chain_class)?;
+
+    bail!(
+        "Class hash {local_class_hash:#x} of the local build of {contract_name} does not match class hash {on_chain_class_hash:#x} on chain\n{}",
+        differences.join("\n")
+    )
+}
+
+/// Returns human-readable differences between ABIs and entry points of the classes
+fn class_differences(
+    local: &FlattenedSierraClass,
+    on_chain: &FlattenedSierraClass,
+) -> Result<Vec<String>> {
+    let local_abi = abi_items(&local.abi)?;
+    let on_chain_abi = abi_items(&on_chain.abi)?;
+
+    let mut differences = vec![];
+
+    for (key, item) in &local_abi {
+        match on_chain_abi.get(key) {
+            None => differences.push(format!("ABI: {key} only in the local build")),
+            Some(on_chain_item) if on_chain_item != item => {
+                differences.push(format!("ABI: {key} differs"));
+            }
+            Some(_) => {}
+        }
+    }
+    for key in on_chain_abi.keys() {
+        if !local_abi.contains_key(key) {
+            differences.push(format!("ABI: {key} only on chain"));
+        }
+    }
+
+    let names = selector_names(local_abi.values().chain(on_chain_abi.values()));
+    for (entry_point_type, local_entry_points, on_chain_entry_points) in [
+        (
+            "constructor",
+            &local.entry_points_by_type.constructor,
+            &on_chain.entry_points_by_type.constructor,
+        ),
+        (
+            "external",
+            &local.entry_points_by_type.external,
+            &on_chain.entry_points_by_type.external,
+        ),
+        (
+            "l1_handler",
+            &local.entry_points_by_type.l1_handler,
+            &on_chain.entry_points_by_type.l1_handler,
+        ),
+    ] {
+        let local_selectors = selectors(local_entry_points);
+        let on_chain_selectors = selectors(on_chain_entry_points);
+
+        for selector in local_selectors.difference(&on_chain_selectors) {
+            differences.push(format!(
+                "Entry point: {entry_point_type} {} only in the local build",
+                entry_point_name(*selector, &names)
+            ));
+        }
+        for selector in on_chain_selectors.difference(&local_selectors) {
+            differences.push(format!(
+                "Entry point: {entry_point_type} {} only on chain",
+                entry_point_name(*selector, &names)
+            ));
+        }
+    }
+
+    if local.contract_class_version != on_chain.contract_class_version {
+        differences.push(format!(
+            "Contract class version: {} in the local build, {} on chain",
+            local.contract_class_version, on_chain.contract_class_version
+        ));
+    }
+    if differences.is_empty() && local.sierra_program != on_chain.sierra_program {
+        differences.push(
+            "Sierra program differs, the class may have been compiled with a different compiler version"
+                .to_string(),
+        );
+    }
+
+    Ok(differences)
+}
+
+/// Returns ABI items by their type and name, with functions of interfaces flattened
+fn abi_items(abi: &str) -> Result<BTreeMap<String, Value>> {
+    let abi: Vec<Value> = serde_json::from_str(abi).context("Failed to parse ABI")?;
+
+    let mut items = BTreeMap::new();
+    for item in abi {
+        let item_type = item["type"].as_str().unwrap_or_default().to_string();
+        let name = item["name"].as_str().unwrap_or_default().to_string();
+
+        match item["items"].as_array() {
+            Some(interface_items) if item_type == "interface" => {
+                for interface_item in interface_items {
+                    let key = format!(
+                        "{} {name}::{}",
+                        interface_item["type"].as_str().unwrap_or_default(),
+                        interface_item["name"].as_str().unwrap_or_default()
+                    );
+                    items.insert(key, interface_item.clone());
+                }
+            }
+            _ => {
+                items.insert(format!("{item_type} {name}"), item);
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+fn selector_names<'a>(items: impl Iterator<Item = &'a Value>) -> HashMap<Felt, String> {
+    items
+        .filter(|item| {
+            matches!(
+                item["type"].as_str(),
+                Some("function" | "constructor" | "l1_handler")
+            )
+        })
+        .filter_map(|item| item["name"].as_str())
+        .filter_map(|name| {
+            get_selector_from_name(name)
+                .ok()
+                .map(|selector| (selector, name.to_string()))
+        })
+        .collect()
+}
+
+fn selectors(entry_points: &[SierraEntryPoint]) -> BTreeSet<Felt> {
+    entry_points
+        .iter()
+        .map(|entry_point| entry_point.selector)
+        .collect()
+}
+
+fn entry_point_name(selector: Felt, names: &HashMap<Felt, String>) -> String {
+    match names.get(&selector) {
+        Some(name) => format!("{name} ({selector:#x})"),
+        None => format!("{selector:#x}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::core::types::EntryPointsByType;
+
+    fn class(abi: &str, external: &[&str]) -> FlattenedSierraClass {
+        FlattenedSierraClass {
+            sierra_program: vec![Felt::ONE],
+            contract_class_version: "0.1.0".to_string(),
+            entry_points_by_type: EntryPointsByType {
+                constructor: vec![],
+                external: external
+                    .iter()
+                    .enumerate()
+                    .map(|(function_idx, name)| SierraEntryPoint {
+                        selector: get_selector_from_name(name).unwrap(),
+                        function_idx: function_idx as u64,
+                    })
+                    .collect(),
+                l1_handler: vec![],
+            },
+            abi: abi.to_string(),
+        }
+    }
+
+    const ABI: &str = r#"[
+        {"type": "impl", "name": "MapImpl", "interface_name": "IMap"},
+        {"type": "interface", "name": "IMap", "items": [
+            {"type": "function", "name": "put", "inputs": [{"name": "key", "type": "core::felt252"}], "outputs": [], "state_mutability": "external"},
+            {"type": "function", "name": "get", "inputs": [{"name": "key", "type": "core::felt252"}], "outputs": [{"type": "core::felt252"}], "state_mutability": "view"}
+        ]}
+    ]"#;
+
+    #[test]
+    fn test_no_differences() {
+        let class = class(ABI, &["put", "get"]);
+
+        assert!(class_differences(&class, &class).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_abi_and_entry_point_differences() {
+        let local = class(ABI, &["put", "get"]);
+        let on_chain_abi = ABI.replace(r#""name": "get""#, r#""name": "read""#);
+        let on_chain = class(&on_chain_abi, &["put", "read"]);
+
+        let differences = class_differences(&local, &on_chain).unwrap();
+
+        assert_eq!(
+            differences,
+            vec![
+                "ABI: function IMap::get only in the local build".to_string(),
+                "ABI: function IMap::read only on chain".to_string(),
+                format!(
+                    "Entry point: external get ({:#x}) only in the local build",
+                    get_selector_from_name("get").unwrap()
+                ),
+                format!(
+                    "Entry point: external read ({:#x}) only on chain",
+                    get_selector_from_name("read").unwrap()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sierra_program_differences() {
+        let local = class(ABI, &["put", "get"]);
+        let mut on_chain = local.clone();
+        on_chain.sierra_program.push(Felt::TWO);
+
+        assert_eq!(
+            class_differences(&local, &on_chain).unwrap(),
+            vec![
+                "Sierra program differs, the class may have been compiled with a different compiler version"
+            ]
+        );
+    }
+}
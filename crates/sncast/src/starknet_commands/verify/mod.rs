@@ -3,22 +3,36 @@ use camino::Utf8PathBuf;
 use clap::{Args, ValueEnum};
 use promptly::prompt;
 use scarb_api::StarknetContractArtifacts;
-use sncast::{Network, response::structs::VerifyResponse};
+use sncast::helpers::configuration::CastConfig;
+use sncast::{Network, ValidatedWaitParams, response::structs::VerifyResponse};
 use starknet_types_core::felt::Felt;
+use std::time::Duration;
 use std::{collections::HashMap, fmt};
 
 pub mod explorer;
+pub mod local;
+pub mod starkscan;
+pub mod voyager;
 pub mod walnut;
 
-use explorer::VerificationInterface;
+use explorer::{
+    VerificationInterface, VerificationPayload, VerificationStatus, VerificationSubmission,
+    read_source_code,
+};
+use starkscan::StarkscanVerificationInterface;
+use voyager::VoyagerVerificationInterface;
 use walnut::WalnutVerificationInterface;
 
 #[derive(Args)]
 #[command(about = "Verify a contract through a block explorer")]
 pub struct Verify {
     /// Address of a contract to be verified
-    #[clap(short = 'd', long)]
-    pub contract_address: Felt,
+    #[clap(short = 'd', long, required_unless_present = "class_hash")]
+    pub contract_address: Option<Felt>,
+
+    /// Class hash to compare the local build with, instead of the class of a deployed contract
+    #[clap(long, conflicts_with = "contract_address", requires = "local")]
+    pub class_hash: Option<Felt>,
 
     /// Name of the contract that is being verified
     #[clap(short, long)]
@@ -29,8 +43,16 @@ pub struct Verify {
     pub verifier: Verifier,
 
     /// The network on which block explorer will do the verification
-    #[clap(short, long, value_enum)]
-    pub network: Network,
+    #[clap(short, long, value_enum, required_unless_present = "local")]
+    pub network: Option<Network>,
+
+    /// Build the contract and compare its class with the one on chain, without sending the source code anywhere
+    #[clap(long, conflicts_with_all = ["verifier", "confirm_verification"])]
+    pub local: bool,
+
+    /// RPC provider url address used with `--local`; overrides url from snfoundry.toml
+    #[clap(short, long, requires = "local", conflicts_with = "network")]
+    pub url: Option<String>,
 
     /// Assume "yes" as answer to confirmation prompt and run non-interactively
     #[clap(long, default_value = "false")]
@@ -44,16 +66,21 @@ pub struct Verify {
 #[derive(ValueEnum, Clone, Debug)]
 pub enum Verifier {
     Walnut,
+    Voyager,
+    Starkscan,
 }
 
 impl fmt::Display for Verifier {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Verifier::Walnut => write!(f, "walnut"),
+            Verifier::Voyager => write!(f, "voyager"),
+            Verifier::Starkscan => write!(f, "starkscan"),
         }
     }
 }
 
+#[expect(clippy::too_many_arguments)]
 pub async fn verify(
     contract_address: Felt,
     contract_name: String,
@@ -62,6 +89,7 @@ pub async fn verify(
     confirm_verification: bool,
     manifest_path: &Utf8PathBuf,
     artifacts: &HashMap<String, StarknetContractArtifacts>,
+    config: &CastConfig,
 ) -> Result<VerifyResponse> {
     // Let's ask confirmation
     if !confirm_verification {
@@ -85,10 +113,56 @@ pub async fn verify(
         .parent()
         .ok_or(anyhow!("Failed to obtain workspace dir"))?;
 
+    let payload = VerificationPayload {
+        contract_name,
+        contract_address: contract_address.to_string(),
+        source_code: read_source_code(workspace_dir)?,
+    };
+
+    let urls = &config.verification;
     match verifier {
         Verifier::Walnut => {
-            let walnut = WalnutVerificationInterface::new(network, workspace_dir.to_path_buf());
-            walnut.verify(contract_address, contract_name).await
+            let walnut = WalnutVerificationInterface::new(network, urls.walnut_api_url.clone());
+            run_verification(&walnut, &payload, config.wait_params).await
+        }
+        Verifier::Voyager => {
+            let voyager = VoyagerVerificationInterface::new(network, urls.voyager_api_url.clone());
+            run_verification(&voyager, &payload, config.wait_params).await
+        }
+        Verifier::Starkscan => {
+            let starkscan =
+                StarkscanVerificationInterface::new(network, urls.starkscan_api_url.clone());
+            run_verification(&starkscan, &payload, config.wait_params).await
         }
     }
 }
+
+/// Submits the payload and polls the verification job until it finishes
+async fn run_verification(
+    verifier: &(impl VerificationInterface + Sync),
+    payload: &VerificationPayload,
+    wait_params: ValidatedWaitParams,
+) -> Result<VerifyResponse> {
+    let job_id = match verifier.submit(payload).await? {
+        VerificationSubmission::Finished(message) => return Ok(VerifyResponse { message }),
+        VerificationSubmission::Pending { job_id } => job_id,
+    };
+
+    for _ in 0..wait_params.get_retries() {
+        match verifier.status(&job_id).await? {
+            VerificationStatus::Success(message) => return Ok(VerifyResponse { message }),
+            VerificationStatus::Failure(message) => {
+                bail!("Verification job {job_id} failed: {message}")
+            }
+            VerificationStatus::InProgress => {
+                tokio::time::sleep(Duration::from_secs(wait_params.get_retry_interval().into()))
+                    .await;
+            }
+        }
+    }
+
+    bail!(
+        "Verification job {job_id} did not finish within {} seconds",
+        wait_params.get_timeout()
+    )
+}
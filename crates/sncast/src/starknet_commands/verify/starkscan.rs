@@ -0,0 +1,77 @@
+use anyhow::Result;
+use serde::Deserialize;
+use sncast::Network;
+
+use super::explorer::{
+    VerificationInterface, VerificationPayload, VerificationStatus, VerificationSubmission,
+    fetch_verification_status, submit_to_verifier,
+};
+
+pub struct StarkscanVerificationInterface {
+    network: Network,
+    api_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct StarkscanJob {
+    job_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+enum StarkscanJobState {
+    Pending,
+    Success,
+    Failed,
+}
+
+#[derive(Deserialize, Debug)]
+struct StarkscanJobStatus {
+    status: StarkscanJobState,
+    message: Option<String>,
+}
+
+impl From<StarkscanJobStatus> for VerificationStatus {
+    fn from(value: StarkscanJobStatus) -> Self {
+        match value.status {
+            StarkscanJobState::Pending => VerificationStatus::InProgress,
+            StarkscanJobState::Success => VerificationStatus::Success(
+                value
+                    .message
+                    .unwrap_or_else(|| "Contract successfully verified".to_string()),
+            ),
+            StarkscanJobState::Failed => VerificationStatus::Failure(
+                value
+                    .message
+                    .unwrap_or_else(|| "Contract verification failed".to_string()),
+            ),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl VerificationInterface for StarkscanVerificationInterface {
+    fn new(network: Network, api_url: Option<String>) -> Self {
+        StarkscanVerificationInterface { network, api_url }
+    }
+
+    async fn submit(&self, payload: &VerificationPayload) -> Result<VerificationSubmission> {
+        let url = format!("{}/verify_contract", self.gen_explorer_url());
+        let job: StarkscanJob = submit_to_verifier(&url, payload).await?;
+        Ok(VerificationSubmission::Pending { job_id: job.job_id })
+    }
+
+    async fn status(&self, job_id: &str) -> Result<VerificationStatus> {
+        let url = format!("{}/verify_contract/{job_id}", self.gen_explorer_url());
+        let status: StarkscanJobStatus = fetch_verification_status(&url).await?;
+        Ok(status.into())
+    }
+
+    fn gen_explorer_url(&self) -> String {
+        match (&self.api_url, &self.network) {
+            (Some(api_url), _) => api_url.clone(),
+            (None, Network::Mainnet) => "https://api.starkscan.co/api/v0".to_string(),
+            (None, Network::Sepolia) => "https://api-sepolia.starkscan.co/api/v0".to_string(),
+        }
+    }
+}
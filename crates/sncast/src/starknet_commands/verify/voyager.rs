@@ -0,0 +1,93 @@
+use anyhow::Result;
+use serde::Deserialize;
+use sncast::Network;
+
+use super::explorer::{
+    VerificationInterface, VerificationPayload, VerificationStatus, VerificationSubmission,
+    fetch_verification_status, submit_to_verifier,
+};
+
+pub struct VoyagerVerificationInterface {
+    network: Network,
+    api_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct VoyagerJob {
+    job_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct VoyagerJobStatus {
+    /// 0 - submitted, 1 - compiled, 2 - compilation failed, 3 - verification failed, 4 - verified
+    status: u8,
+    status_description: String,
+    message: Option<String>,
+}
+
+impl From<VoyagerJobStatus> for VerificationStatus {
+    fn from(value: VoyagerJobStatus) -> Self {
+        let message = value.message.unwrap_or(value.status_description);
+        match value.status {
+            0 | 1 => VerificationStatus::InProgress,
+            4 => VerificationStatus::Success(message),
+            _ => VerificationStatus::Failure(message),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl VerificationInterface for VoyagerVerificationInterface {
+    fn new(network: Network, api_url: Option<String>) -> Self {
+        VoyagerVerificationInterface { network, api_url }
+    }
+
+    async fn submit(&self, payload: &VerificationPayload) -> Result<VerificationSubmission> {
+        let url = format!("{}/class-verify", self.gen_explorer_url());
+        let job: VoyagerJob = submit_to_verifier(&url, payload).await?;
+        Ok(VerificationSubmission::Pending { job_id: job.job_id })
+    }
+
+    async fn status(&self, job_id: &str) -> Result<VerificationStatus> {
+        let url = format!("{}/class-verify/job/{job_id}", self.gen_explorer_url());
+        let status: VoyagerJobStatus = fetch_verification_status(&url).await?;
+        Ok(status.into())
+    }
+
+    fn gen_explorer_url(&self) -> String {
+        match (&self.api_url, &self.network) {
+            (Some(api_url), _) => api_url.clone(),
+            (None, Network::Mainnet) => "https://api.voyager.online/beta".to_string(),
+            (None, Network::Sepolia) => "https://sepolia-api.voyager.online/beta".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job_status(status: u8, message: Option<&str>) -> VoyagerJobStatus {
+        VoyagerJobStatus {
+            status,
+            status_description: "description".to_string(),
+            message: message.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn test_job_status() {
+        assert_eq!(
+            VerificationStatus::from(job_status(1, None)),
+            VerificationStatus::InProgress
+        );
+        assert_eq!(
+            VerificationStatus::from(job_status(4, None)),
+            VerificationStatus::Success("description".to_string())
+        );
+        assert_eq!(
+            VerificationStatus::from(job_status(2, Some("Compilation failed"))),
+            VerificationStatus::Failure("Compilation failed".to_string())
+        );
+    }
+}
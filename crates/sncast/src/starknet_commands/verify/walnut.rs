@@ -1,74 +1,31 @@
-use anyhow::{Context, Result, anyhow};
-use camino::Utf8PathBuf;
+use anyhow::{Context, Result, anyhow, bail};
 use reqwest::StatusCode;
 use sncast::Network;
-use sncast::response::structs::VerifyResponse;
-use starknet_types_core::felt::Felt;
 use std::env;
-use std::ffi::OsStr;
-use walkdir::WalkDir;
 
-use super::explorer::{VerificationInterface, VerificationPayload};
+use super::explorer::{
+    VerificationInterface, VerificationPayload, VerificationStatus, VerificationSubmission,
+};
 
 pub struct WalnutVerificationInterface {
     network: Network,
-    workspace_dir: Utf8PathBuf,
+    api_url: Option<String>,
 }
 
 #[async_trait::async_trait]
 impl VerificationInterface for WalnutVerificationInterface {
-    fn new(network: Network, workspace_dir: Utf8PathBuf) -> Self {
-        WalnutVerificationInterface {
-            network,
-            workspace_dir,
-        }
+    fn new(network: Network, api_url: Option<String>) -> Self {
+        WalnutVerificationInterface { network, api_url }
     }
 
-    async fn verify(
-        &self,
-        contract_address: Felt,
-        contract_name: String,
-    ) -> Result<VerifyResponse> {
-        // Read all files name along with their contents in a JSON format
-        // in the workspace dir recursively
-        // key is the file name and value is the file content
-        let mut file_data = serde_json::Map::new();
-
-        // Recursively read files and their contents in workspace directory
-        for entry in WalkDir::new(self.workspace_dir.clone()).follow_links(true) {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(extension) = path.extension() {
-                    if extension == OsStr::new("cairo") || extension == OsStr::new("toml") {
-                        let relative_path = path.strip_prefix(self.workspace_dir.clone())?;
-                        let file_content = std::fs::read_to_string(path)?;
-                        file_data.insert(
-                            relative_path.to_string_lossy().into_owned(),
-                            serde_json::Value::String(file_content),
-                        );
-                    }
-                }
-            }
-        }
-
-        // Serialize the JSON object to a JSON string
-        let source_code = serde_json::Value::Object(file_data);
-
-        // Create the JSON payload with "contract name," "address," and "source_code" fields
-        let payload = VerificationPayload {
-            contract_name: contract_name.to_string(),
-            contract_address: contract_address.to_string(),
-            source_code,
-        };
-
+    async fn submit(&self, payload: &VerificationPayload) -> Result<VerificationSubmission> {
         // Serialize the payload to a JSON string for the POST request
-        let json_payload = serde_json::to_string(&payload)?;
+        let json_payload = serde_json::to_string(payload)?;
 
         // Send the POST request to the explorer
         let client = reqwest::Client::new();
         let api_res = client
-            .post(self.gen_explorer_url()?)
+            .post(self.gen_explorer_url())
             .header("Content-Type", "application/json")
             .body(json_payload)
             .send()
@@ -80,20 +37,25 @@ impl VerificationInterface for WalnutVerificationInterface {
                 .text()
                 .await
                 .context("Failed to read verifier API response")?;
-            Ok(VerifyResponse { message })
+            Ok(VerificationSubmission::Finished(message))
         } else {
             let message = api_res.text().await.context("Failed to verify contract")?;
             Err(anyhow!(message))
         }
     }
 
-    fn gen_explorer_url(&self) -> Result<String> {
-        let api_base_url =
-            env::var("WALNUT_API_URL").unwrap_or_else(|_| "https://api.walnut.dev".to_string());
+    async fn status(&self, _job_id: &str) -> Result<VerificationStatus> {
+        bail!("Walnut does not support polling the verification status")
+    }
+
+    fn gen_explorer_url(&self) -> String {
+        let api_base_url = self.api_url.clone().unwrap_or_else(|| {
+            env::var("WALNUT_API_URL").unwrap_or_else(|_| "https://api.walnut.dev".to_string())
+        });
         let path = match self.network {
             Network::Mainnet => "/v1/sn_main/verify",
             Network::Sepolia => "/v1/sn_sepolia/verify",
         };
-        Ok(format!("{api_base_url}{path}"))
+        format!("{api_base_url}{path}")
     }
 }
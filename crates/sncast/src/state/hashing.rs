@@ -1,5 +1,6 @@
 use sha3::Digest;
 use sha3::Sha3_256;
+use starknet::core::types::Call;
 use starknet_types_core::felt::Felt;
 use std::vec;
 
@@ -94,11 +95,28 @@ pub fn generate_invoke_tx_id(
     generate_id("invoke", bytes)
 }
 
+#[must_use]
+pub fn generate_multicall_tx_id(calls: &[Call]) -> String {
+    let bytes = calls
+        .iter()
+        .flat_map(|call| {
+            [
+                call.to.serialise_as_bytes(),
+                call.selector.serialise_as_bytes(),
+                call.calldata.as_slice().serialise_as_bytes(),
+            ]
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    generate_id("multicall", bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::state::hashing::{
         generate_declare_tx_id, generate_deploy_tx_id, generate_id, generate_invoke_tx_id,
+        generate_multicall_tx_id,
     };
     use conversions::IntoConv;
 
@@ -154,4 +172,25 @@ mod tests {
             "9b7d3fa2d93d1360a343bfd1d3d76aedef74aace5a5ad47ddbda136d9ce9b244"
         );
     }
+
+    #[test]
+    fn multicall() {
+        let calls = vec![
+            Call {
+                to: Felt::from(123u32),
+                selector: Felt::from(890u32),
+                calldata: vec![Felt::from(1809u32), Felt::from(14u32)],
+            },
+            Call {
+                to: Felt::from(456u32),
+                selector: Felt::from(891u32),
+                calldata: vec![],
+            },
+        ];
+        let hash = generate_multicall_tx_id(&calls);
+        assert_eq!(
+            hash,
+            "3975b54ccde18e54283abf7fe5727665d35505d9ad86748dcf1726c74030e2a5"
+        );
+    }
 }
@@ -0,0 +1,287 @@
+use crate::decode_chain_id;
+use crate::helpers::constants::MANIFEST_VERSION;
+use crate::response::structs::{DeclareResponse, DeployResponse};
+use anyhow::{Context, Result, anyhow};
+use camino::Utf8PathBuf;
+use conversions::padded_felt::PaddedFelt;
+use serde::{Deserialize, Serialize};
+use starknet_types_core::felt::Felt;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Human-readable record of contracts declared and deployed by a script on a single network
+#[derive(Deserialize, Serialize, Debug, PartialEq)]
+pub struct DeploymentManifest {
+    pub version: u8,
+    pub chain_id: String,
+    /// Declared contracts by their names
+    pub contracts: BTreeMap<String, DeclaredContract>,
+    /// Deployed contracts in the order of deployment
+    pub deployments: Vec<Deployment>,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq)]
+pub struct DeclaredContract {
+    pub class_hash: PaddedFelt,
+    /// Not set if the contract was declared before the script was run
+    pub transaction_hash: Option<PaddedFelt>,
+    pub block_number: Option<u64>,
+}
+
+#[derive(Clone, Deserialize, Serialize, Debug, PartialEq)]
+pub struct Deployment {
+    /// Not set if the contract was not declared by the script
+    pub contract_name: Option<String>,
+    pub class_hash: PaddedFelt,
+    pub contract_address: PaddedFelt,
+    pub constructor_calldata: Vec<Felt>,
+    pub transaction_hash: PaddedFelt,
+    pub block_number: Option<u64>,
+}
+
+impl DeploymentManifest {
+    #[must_use]
+    pub fn new(chain_id: Felt) -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            chain_id: decode_chain_id(chain_id),
+            contracts: BTreeMap::new(),
+            deployments: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn contract_name(&self, class_hash: Felt) -> Option<String> {
+        self.contracts
+            .iter()
+            .find(|(_, contract)| contract.class_hash.0 == class_hash)
+            .map(|(name, _)| name.clone())
+    }
+
+    #[must_use]
+    pub fn contains_declaration(&self, contract_name: &str, class_hash: Felt) -> bool {
+        self.contracts
+            .get(contract_name)
+            .is_some_and(|contract| contract.class_hash.0 == class_hash)
+    }
+
+    #[must_use]
+    pub fn contains_deployment(&self, contract_address: Felt) -> bool {
+        self.deployments
+            .iter()
+            .any(|deployment| deployment.contract_address.0 == contract_address)
+    }
+
+    pub fn insert_declaration(&mut self, contract_name: &str, contract: DeclaredContract) {
+        self.contracts.insert(contract_name.to_string(), contract);
+    }
+
+    pub fn insert_deployment(&mut self, deployment: Deployment) {
+        self.deployments
+            .retain(|existing| existing.contract_address != deployment.contract_address);
+        self.deployments.push(deployment);
+    }
+}
+
+struct InnerManifestManager {
+    manifest_file: Utf8PathBuf,
+    manifest: DeploymentManifest,
+}
+
+/// Records contracts declared and deployed by a script in the manifest file
+#[derive(Default)]
+pub struct ManifestManager {
+    inner: Option<InnerManifestManager>,
+}
+
+impl ManifestManager {
+    pub fn from(manifest_file_path: Option<Utf8PathBuf>, chain_id: Felt) -> Result<Self> {
+        let inner = match manifest_file_path {
+            Some(manifest_file) => Some(InnerManifestManager {
+                manifest: load_or_create_manifest(&manifest_file, chain_id)?,
+                manifest_file,
+            }),
+            None => None,
+        };
+
+        Ok(Self { inner })
+    }
+
+    /// `block_number` is only queried for transactions not recorded yet
+    pub fn record_declaration(
+        &mut self,
+        contract_name: &str,
+        response: &DeclareResponse,
+        block_number: impl FnOnce(Felt) -> Option<u64>,
+    ) -> Result<()> {
+        let Some(state) = &mut self.inner else {
+            return Ok(());
+        };
+
+        let contract = match response {
+            DeclareResponse::Success(response) => DeclaredContract {
+                class_hash: response.class_hash,
+                transaction_hash: Some(response.transaction_hash),
+                block_number: None,
+            },
+            DeclareResponse::AlreadyDeclared(response) => DeclaredContract {
+                class_hash: response.class_hash,
+                transaction_hash: None,
+                block_number: None,
+            },
+        };
+
+        if state
+            .manifest
+            .contains_declaration(contract_name, contract.class_hash.0)
+        {
+            return Ok(());
+        }
+
+        let block_number = contract
+            .transaction_hash
+            .and_then(|transaction_hash| block_number(transaction_hash.0));
+        state.manifest.insert_declaration(
+            contract_name,
+            DeclaredContract {
+                block_number,
+                ..contract
+            },
+        );
+
+        write_manifest(&state.manifest_file, &state.manifest)
+    }
+
+    /// `block_number` is only queried for transactions not recorded yet
+    pub fn record_deployment(
+        &mut self,
+        class_hash: Felt,
+        constructor_calldata: &[Felt],
+        response: &DeployResponse,
+        block_number: impl FnOnce(Felt) -> Option<u64>,
+    ) -> Result<()> {
+        let Some(state) = &mut self.inner else {
+            return Ok(());
+        };
+
+        if state
+            .manifest
+            .contains_deployment(response.contract_address.0)
+        {
+            return Ok(());
+        }
+
+        state.manifest.insert_deployment(Deployment {
+            contract_name: state.manifest.contract_name(class_hash),
+            class_hash: PaddedFelt(class_hash),
+            contract_address: response.contract_address,
+            constructor_calldata: constructor_calldata.to_vec(),
+            transaction_hash: response.transaction_hash,
+            block_number: block_number(response.transaction_hash.0),
+        });
+
+        write_manifest(&state.manifest_file, &state.manifest)
+    }
+}
+
+pub fn load_or_create_manifest(path: &Utf8PathBuf, chain_id: Felt) -> Result<DeploymentManifest> {
+    if !path.exists() {
+        return Ok(DeploymentManifest::new(chain_id));
+    }
+
+    let content = fs::read_to_string(path).context("Failed to load deployment manifest")?;
+    let manifest = serde_json::from_str::<DeploymentManifest>(&content)
+        .map_err(|_| anyhow!("Failed to parse deployment manifest - it may be corrupt"))?;
+
+    match manifest.version {
+        MANIFEST_VERSION => Ok(manifest),
+        version => Err(anyhow!("Unsupported deployment manifest version {version}")),
+    }
+}
+
+pub fn write_manifest(path: &Utf8PathBuf, manifest: &DeploymentManifest) -> Result<()> {
+    fs::write(
+        path,
+        serde_json::to_string_pretty(manifest)
+            .expect("Failed to convert DeploymentManifest to json"),
+    )
+    .with_context(|| format!("Failed to write deployment manifest {path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use conversions::IntoConv;
+    use tempfile::TempDir;
+
+    fn declared_contract(class_hash: u64) -> DeclaredContract {
+        DeclaredContract {
+            class_hash: Felt::from(class_hash).into_(),
+            transaction_hash: Some(Felt::from(0x321).into_()),
+            block_number: Some(10),
+        }
+    }
+
+    fn deployment(contract_address: u64) -> Deployment {
+        Deployment {
+            contract_name: Some("Map".to_string()),
+            class_hash: Felt::from(0x123).into_(),
+            contract_address: Felt::from(contract_address).into_(),
+            constructor_calldata: vec![Felt::ONE, Felt::TWO],
+            transaction_hash: Felt::from(0x456).into_(),
+            block_number: None,
+        }
+    }
+
+    #[test]
+    fn test_write_and_load_manifest() {
+        let tempdir = TempDir::new().unwrap();
+        let path = Utf8PathBuf::from_path_buf(tempdir.path().join("script_manifest.json")).unwrap();
+        let chain_id = Felt::from_hex("0x534e5f5345504f4c4941").unwrap();
+
+        let mut manifest = load_or_create_manifest(&path, chain_id).unwrap();
+        assert_eq!(manifest, DeploymentManifest::new(chain_id));
+        assert_eq!(manifest.chain_id, "SN_SEPOLIA");
+
+        manifest.insert_declaration("Map", declared_contract(0x123));
+        manifest.insert_deployment(deployment(0x789));
+        write_manifest(&path, &manifest).unwrap();
+
+        let loaded = load_or_create_manifest(&path, chain_id).unwrap();
+        assert_eq!(loaded, manifest);
+        assert_eq!(
+            loaded.contract_name(Felt::from(0x123)),
+            Some("Map".to_string())
+        );
+        assert!(loaded.contains_declaration("Map", Felt::from(0x123)));
+        assert!(loaded.contains_deployment(Felt::from(0x789)));
+    }
+
+    #[test]
+    fn test_deployments_are_not_duplicated() {
+        let mut manifest = DeploymentManifest::new(Felt::ONE);
+
+        manifest.insert_deployment(deployment(0x1));
+        manifest.insert_deployment(deployment(0x2));
+        manifest.insert_deployment(deployment(0x1));
+
+        assert_eq!(manifest.deployments, vec![deployment(0x2), deployment(0x1)]);
+    }
+
+    #[test]
+    fn test_version_mismatch() {
+        let tempdir = TempDir::new().unwrap();
+        let path = Utf8PathBuf::from_path_buf(tempdir.path().join("script_manifest.json")).unwrap();
+        fs::write(
+            &path,
+            r#"{"version": 0, "chain_id": "SN_SEPOLIA", "contracts": {}, "deployments": []}"#,
+        )
+        .unwrap();
+
+        let error = load_or_create_manifest(&path, Felt::ONE).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Unsupported deployment manifest version 0"
+        );
+    }
+}
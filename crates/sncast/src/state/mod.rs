@@ -1,2 +1,3 @@
 pub mod hashing;
+pub mod manifest;
 pub mod state_file;
@@ -298,6 +298,31 @@ pub fn write_txs_to_state_file(
     Ok(())
 }
 
+/// Removes entries of transactions which did not succeed, or all entries if `all` is set,
+/// so they are executed again on the next run of the script. Returns the number of removed entries
+pub fn prune_state_file(state_file_path: &Utf8PathBuf, all: bool) -> Result<usize> {
+    let mut state_file = load_state_file(state_file_path)?;
+
+    let removed = match &mut state_file.transactions {
+        Some(entries) => {
+            let count = entries.transactions.len();
+            entries
+                .transactions
+                .retain(|_, entry| !all && entry.status == ScriptTransactionStatus::Success);
+            count - entries.transactions.len()
+        }
+        None => 0,
+    };
+
+    fs::write(
+        state_file_path,
+        serde_json::to_string_pretty(&state_file)
+            .expect("Failed to convert ScriptTransactionsSchema to json"),
+    )
+    .with_context(|| anyhow!("Failed to write state file {state_file_path}"))?;
+    Ok(removed)
+}
+
 fn verify_version(version: u8) -> Result<()> {
     match version {
         STATE_FILE_VERSION => Ok(()),
@@ -626,4 +651,22 @@ mod tests {
         assert_eq!(entries.transactions.len(), 3);
         assert_eq!(transaction_entry.status, ScriptTransactionStatus::Success);
     }
+
+    #[test]
+    fn test_prune_state_file() {
+        let from_state_file = Utf8PathBuf::from("tests/data/files/state_with_txs.json");
+        let tempdir = TempDir::new().unwrap();
+        let temp_state_file =
+            Utf8PathBuf::from_path_buf(tempdir.path().join("state_with_txs.json")).unwrap();
+        fs::copy(from_state_file, &temp_state_file).unwrap();
+
+        assert_eq!(prune_state_file(&temp_state_file, false).unwrap(), 1);
+        let entries = read_txs_from_state_file(&temp_state_file).unwrap().unwrap();
+        assert_eq!(entries.transactions.len(), 2);
+        assert!(entries.get("789def420").is_none());
+
+        assert_eq!(prune_state_file(&temp_state_file, true).unwrap(), 2);
+        let entries = read_txs_from_state_file(&temp_state_file).unwrap().unwrap();
+        assert!(entries.transactions.is_empty());
+    }
 }
@@ -0,0 +1,22 @@
+use sncast_std::{var, FileTrait, FileParser, read_txt};
+
+#[derive(Drop, Serde)]
+struct Config {
+    name: ByteArray,
+    salt: felt252,
+    values: Array<felt252>,
+}
+
+fn main() {
+    let network = var("SCRIPT_NETWORK");
+    println!("network: {:?}", network);
+
+    let config: Config = FileParser::parse_json(@FileTrait::new("data/config.json"))
+        .expect('Failed to parse config');
+    println!("name: {}", config.name);
+    println!("salt: {}", config.salt);
+    println!("values: {:?}", config.values);
+
+    let numbers = read_txt(@FileTrait::new("data/numbers.txt"));
+    println!("numbers: {:?}", numbers);
+}
@@ -0,0 +1,2 @@
+mod with_arguments;
+mod env_and_files;
@@ -0,0 +1,10 @@
+use starknet::ContractAddress;
+
+fn main(owner: ContractAddress, amount: u256, name: ByteArray, values: Array<u8>, verbose: bool) {
+    let owner: felt252 = owner.into();
+    println!("owner: {}", owner);
+    println!("amount: {}", amount);
+    println!("name: {}", name);
+    println!("values: {:?}", values);
+    println!("verbose: {}", verbose);
+}
@@ -0,0 +1,11 @@
+use sncast_std::{get_balance, STRK_ADDRESS};
+
+fn main() {
+    let strk = STRK_ADDRESS.try_into().expect('Invalid contract address value');
+    let account = 0x691a61b12a7105b1372cc377f135213c11e8400a546f6b0e7ea0296046690ce
+        .try_into()
+        .expect('Invalid contract address value');
+
+    let balance = get_balance(strk, account).expect('get_balance failed');
+    println!("has balance: {}", balance > 0);
+}
@@ -0,0 +1,9 @@
+use sncast_std::{get_block, BlockId};
+
+fn main() {
+    let block_err = get_block(BlockId::Number(999999999)).unwrap_err();
+    println!("{:?}", block_err);
+
+    let block = get_block(BlockId::Number(0)).expect('get_block failed');
+    println!("block_number: {:?}", block.block_number);
+}
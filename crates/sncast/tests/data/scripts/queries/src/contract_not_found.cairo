@@ -0,0 +1,11 @@
+use sncast_std::{get_storage_at, get_class_hash_at};
+
+fn main() {
+    let contract_address = 0x123.try_into().expect('Invalid contract address value');
+
+    let storage_err = get_storage_at(contract_address, selector!("storage")).unwrap_err();
+    println!("{:?}", storage_err);
+
+    let class_hash_err = get_class_hash_at(contract_address).unwrap_err();
+    println!("{:?}", class_hash_err);
+}
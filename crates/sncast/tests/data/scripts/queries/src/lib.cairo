@@ -0,0 +1,4 @@
+mod balance;
+mod block_not_found;
+mod contract_not_found;
+mod multicall_and_events;
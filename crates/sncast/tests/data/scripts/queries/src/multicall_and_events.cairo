@@ -0,0 +1,48 @@
+use sncast_std::{
+    multicall, estimate_fee, get_events, get_storage_at, get_class_hash_at, Call, FeeSettings,
+};
+use starknet::ContractAddress;
+
+fn main() {
+    let map_contract_address: ContractAddress =
+        0x07537a17e169c96cf2b0392508b3a66cbc50c9a811a8a7896529004c5e93fdf6
+        .try_into()
+        .expect('Invalid contract address value');
+
+    let calls = array![
+        Call {
+            contract_address: map_contract_address,
+            entry_point_selector: selector!("put"),
+            calldata: array![0x10, 0x1],
+        },
+        Call {
+            contract_address: map_contract_address,
+            entry_point_selector: selector!("put"),
+            calldata: array![0x11, 0x2],
+        },
+    ];
+
+    let fee = estimate_fee(calls.clone()).expect('estimate_fee failed');
+    println!("fee estimated: {}", fee.overall_fee != 0);
+
+    let multicall_result = multicall(
+        calls,
+        FeeSettings {
+            max_fee: Option::None, max_gas: Option::None, max_gas_unit_price: Option::None
+        },
+        Option::None
+    )
+        .expect('multicall failed');
+
+    let events = get_events(multicall_result.transaction_hash).expect('get_events failed');
+    println!("events emitted: {}", events.len() != 0);
+
+    let class_hash = get_class_hash_at(map_contract_address).expect('get_class_hash_at failed');
+    let class_hash_felt: felt252 = class_hash.into();
+    println!("class hash found: {}", class_hash_felt != 0);
+
+    // Storage address of `storage.entry(0x10)`
+    let key = core::pedersen::pedersen(selector!("storage"), 0x10);
+    let value = get_storage_at(map_contract_address, key).expect('get_storage_at failed');
+    println!("value: {}", value);
+}
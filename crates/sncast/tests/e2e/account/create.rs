@@ -117,6 +117,123 @@ pub async fn test_invalid_class_hash() {
     );
 }
 
+#[tokio::test]
+pub async fn test_happy_case_custom_account() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+    let accounts_file = "accounts.json";
+    let class_hash = OZ_CLASS_HASH.into_hex_string();
+
+    let args = vec![
+        "--accounts-file",
+        accounts_file,
+        "account",
+        "create",
+        "--url",
+        URL,
+        "--name",
+        "my_account",
+        "--salt",
+        "0x1",
+        "--type",
+        "custom",
+        "--class-hash",
+        &class_hash,
+        "--constructor-calldata-template",
+        "{public_key}",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        command: account create
+        add_profile: --add-profile flag was not set. No profile added to snfoundry.toml
+        address: 0x0[..]
+        max_fee: [..]
+        message: Account successfully created[..]
+        ...
+    "});
+
+    let contents = fs::read_to_string(temp_dir.path().join(accounts_file))
+        .expect("Unable to read created file");
+
+    let expected = json!(
+        {
+            "alpha-sepolia": {
+                "my_account": {
+                    "address": "0x[..]",
+                    "class_hash": class_hash,
+                    "constructor_calldata": ["{public_key}"],
+                    "deployed": false,
+                    "legacy": false,
+                    "private_key": "0x[..]",
+                    "public_key": "0x[..]",
+                    "salt": "0x1",
+                    "type": "custom"
+                }
+            }
+        }
+    );
+
+    assert_matches(to_string_pretty(&expected).unwrap(), contents);
+}
+
+#[tokio::test]
+pub async fn test_custom_account_requires_class_hash() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "account",
+        "create",
+        "--url",
+        URL,
+        "--name",
+        "my_account",
+        "--type",
+        "custom",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account create
+        error: Argument `--class-hash` must be passed when using `--type custom`
+        "},
+    );
+}
+
+#[tokio::test]
+pub async fn test_signer_config_requires_custom_account() {
+    let temp_dir = tempdir().expect("Unable to create a temporary directory");
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "account",
+        "create",
+        "--url",
+        URL,
+        "--name",
+        "my_account",
+        "--signer-config",
+        "signer.json",
+    ];
+
+    let snapbox = runner(&args).current_dir(temp_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: account create
+        error: Arguments `--constructor-calldata-template` and `--signer-config` can only be used with `--type custom`
+        "},
+    );
+}
+
 #[tokio::test]
 pub async fn test_happy_case_generate_salt() {
     let temp_dir = tempdir().expect("Unable to create a temporary directory");
@@ -815,6 +932,7 @@ fn get_keystore_account_pattern(account_type: AccountType, class_hash: Option<&s
                 }
             )
         }
+        AccountType::Custom => unreachable!("Custom accounts are not supported with keystore"),
     };
 
     to_string_pretty(&account_json).unwrap()
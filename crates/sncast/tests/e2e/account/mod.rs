@@ -4,3 +4,4 @@ mod deploy;
 mod helpers;
 mod import;
 mod list;
+mod nonce;
@@ -0,0 +1,123 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, URL};
+use crate::helpers::fixtures::create_and_deploy_oz_account;
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::{AsOutput, assert_stdout_contains};
+use std::fs;
+use std::thread;
+
+#[tokio::test]
+async fn test_happy_case() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "account",
+        "nonce",
+        "--url",
+        URL,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account nonce
+        next_nonce: 1
+        nonce: 1
+        pending_transactions: []
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_reset_without_tracked_nonces() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "account",
+        "nonce",
+        "--url",
+        URL,
+        "--reset",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account nonce
+        message: No nonces are tracked by the nonce manager
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_concurrent_invokes_with_nonce_manager() {
+    let tempdir = create_and_deploy_oz_account().await;
+    fs::write(
+        tempdir.path().join("snfoundry.toml"),
+        formatdoc! {r#"
+            [sncast.default]
+            url = "{URL}"
+            accounts-file = "accounts.json"
+            account = "my_account"
+            nonce-manager = true
+        "#},
+    )
+    .unwrap();
+
+    let handles: Vec<_> = (0..3)
+        .map(|value| {
+            let tempdir_path = tempdir.path().to_path_buf();
+            thread::spawn(move || {
+                let value = value.to_string();
+                let args = vec![
+                    "invoke",
+                    "--contract-address",
+                    MAP_CONTRACT_ADDRESS_SEPOLIA,
+                    "--function",
+                    "put",
+                    "--calldata",
+                    "0x1",
+                    &value,
+                ];
+
+                runner(&args)
+                    .current_dir(tempdir_path)
+                    .assert()
+                    .success()
+                    .as_stderr()
+                    .to_string()
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        let stderr = handle.join().unwrap();
+        assert!(stderr.is_empty(), "Invoke failed, stderr: \n{stderr}");
+    }
+
+    let args = vec!["account", "nonce", "--reset"];
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: account nonce
+        message: Nonces tracked by the nonce manager have been reset
+        "},
+    );
+}
@@ -1,10 +1,11 @@
 use crate::helpers::constants::{
-    ACCOUNT, ACCOUNT_FILE_PATH, CONSTRUCTOR_WITH_PARAMS_CONTRACT_CLASS_HASH_SEPOLIA,
+    ACCOUNT, ACCOUNT_FILE_PATH, CONSTRUCTOR_WITH_PARAMS_CONTRACT_CLASS_HASH_SEPOLIA, CONTRACTS_DIR,
     DEVNET_OZ_CLASS_HASH_CAIRO_0, MAP_CONTRACT_CLASS_HASH_SEPOLIA, URL,
 };
 use crate::helpers::fixtures::{
-    create_and_deploy_account, create_and_deploy_oz_account, get_transaction_hash,
-    get_transaction_receipt,
+    create_and_deploy_account, create_and_deploy_oz_account,
+    duplicate_contract_directory_with_salt, get_transaction_hash, get_transaction_receipt,
+    join_tempdirs,
 };
 use crate::helpers::runner::runner;
 use indoc::indoc;
@@ -200,6 +201,122 @@ async fn test_happy_case_with_constructor_cairo_expression_calldata() {
     assert!(matches!(receipt, Deploy(_)));
 }
 
+#[tokio::test]
+async fn test_happy_case_contract_name() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/constructor_with_params",
+        "value1",
+        "contract_name",
+    );
+    let tempdir = create_and_deploy_oz_account().await;
+    join_tempdirs(&contract_path, &tempdir);
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "deploy",
+        "--url",
+        URL,
+        "--contract-name",
+        "ConstructorWithParams",
+        "--arguments",
+        "0x420, 0x2137_u256",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {
+            "
+            command: declare
+            class_hash: 0x0[..]
+            transaction_hash: 0x0[..]
+            command: deploy
+            contract_address: 0x0[..]
+            transaction_hash: 0x0[..]
+            "
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_contract_name_already_declared() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/constructor_with_params",
+        "value1",
+        "already_declared",
+    );
+    let tempdir = create_and_deploy_oz_account().await;
+    join_tempdirs(&contract_path, &tempdir);
+
+    let declare_args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "declare",
+        "--url",
+        URL,
+        "--contract-name",
+        "ConstructorWithParams",
+    ];
+    runner(&declare_args)
+        .current_dir(tempdir.path())
+        .assert()
+        .success();
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "deploy",
+        "--url",
+        URL,
+        "--contract-name",
+        "ConstructorWithParams",
+        "--arguments",
+        "0x420, 0x2137_u256",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success().get_output().stdout.clone();
+
+    let stdout = std::str::from_utf8(&output).unwrap();
+    assert!(!stdout.contains(r#""command":"declare""#));
+    assert!(stdout.contains(r#""command":"deploy""#));
+}
+
+#[test]
+fn test_contract_name_and_class_hash_conflict() {
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        ACCOUNT,
+        "deploy",
+        "--url",
+        URL,
+        "--class-hash",
+        MAP_CONTRACT_CLASS_HASH_SEPOLIA,
+        "--contract-name",
+        "Map",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: the argument '--class-hash <CLASS_HASH>' cannot be used with '--contract-name <CONTRACT_NAME>'",
+    );
+}
+
 #[test]
 fn test_wrong_calldata() {
     let args = vec![
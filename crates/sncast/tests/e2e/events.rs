@@ -0,0 +1,104 @@
+use crate::helpers::constants::{MAP_CONTRACT_ADDRESS_SEPOLIA, STRK_CONTRACT_ADDRESS, URL};
+use crate::helpers::fixtures::{create_and_deploy_oz_account, get_transaction_hash};
+use crate::helpers::runner::runner;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use sncast::get_provider;
+use starknet::providers::Provider;
+
+#[tokio::test]
+async fn test_happy_case() {
+    let tempdir = create_and_deploy_oz_account().await;
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "invoke",
+        "--url",
+        URL,
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--function",
+        "put",
+        "--calldata",
+        "0x1 0x2",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+    let transaction_hash = get_transaction_hash(&output.get_output().stdout);
+
+    // Devnet creates a block for each transaction, paying the fee emits `Transfer` event of STRK
+    let provider = get_provider(URL).expect("Failed to get the provider");
+    let block_number = provider.block_number().await.unwrap().to_string();
+
+    let args = vec![
+        "events",
+        "watch",
+        "--url",
+        URL,
+        "--address",
+        STRK_CONTRACT_ADDRESS,
+        "--event",
+        "Transfer",
+        "--from-block",
+        &block_number,
+        "--to-block",
+        &block_number,
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        format!(
+            "command: events watch\nstatus: Accepted\nblock_number: {block_number}\ntransaction_hash: {transaction_hash:#066x}\nfrom_address: {STRK_CONTRACT_ADDRESS}\nname: [..]Transfer"
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_contract_does_not_exist() {
+    let args = vec![
+        "events",
+        "watch",
+        "--url",
+        URL,
+        "--address",
+        "0x1",
+        "--to-block",
+        "0",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        "command: events watch\nerror: An error occurred in the called contract[..]Requested contract address 0x1 is not deployed[..]",
+    );
+}
+
+#[test]
+fn test_zero_chunk_size() {
+    let args = vec![
+        "events",
+        "watch",
+        "--url",
+        URL,
+        "--address",
+        STRK_CONTRACT_ADDRESS,
+        "--chunk-size",
+        "0",
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: invalid value '0' for '--chunk-size <CHUNK_SIZE>': 0 is not in [..]",
+    );
+}
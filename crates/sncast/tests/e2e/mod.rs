@@ -2,6 +2,7 @@ mod account;
 mod call;
 mod declare;
 mod deploy;
+mod events;
 mod invoke;
 mod main_tests;
 mod multicall;
@@ -1,8 +1,10 @@
-use crate::helpers::constants::{ACCOUNT_FILE_PATH, MULTICALL_CONFIGS_DIR, URL};
-use crate::helpers::fixtures::create_and_deploy_oz_account;
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, CONTRACTS_DIR, MULTICALL_CONFIGS_DIR, URL};
+use crate::helpers::fixtures::{
+    create_and_deploy_oz_account, duplicate_contract_directory_with_salt, join_tempdirs,
+};
 use crate::helpers::runner::runner;
 use indoc::{formatdoc, indoc};
-use shared::test_utils::output_assert::{AsOutput, assert_stderr_contains};
+use shared::test_utils::output_assert::{AsOutput, assert_stderr_contains, assert_stdout_contains};
 use std::path::Path;
 use test_case::test_case;
 
@@ -309,3 +311,134 @@ async fn test_numeric_overflow() {
         "},
     );
 }
+
+#[tokio::test]
+async fn test_arguments_and_references() {
+    let tempdir = create_and_deploy_oz_account().await;
+
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("deploy_invoke_arguments.toml");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert();
+
+    let stderr_str = output.as_stderr();
+    assert!(
+        stderr_str.is_empty(),
+        "Multicall error, stderr: \n{stderr_str}",
+    );
+
+    output.stdout_matches(indoc! {r"
+        command: multicall run
+        transaction_hash: 0x0[..]
+
+        To see invocation details, visit:
+        transaction: [..]
+    "});
+}
+
+#[tokio::test]
+async fn test_declare_deploy_invoke() {
+    let contract_path = duplicate_contract_directory_with_salt(
+        CONTRACTS_DIR.to_string() + "/map",
+        "put",
+        "multicall_declare",
+    );
+    let tempdir = create_and_deploy_oz_account().await;
+    join_tempdirs(&contract_path, &tempdir);
+
+    std::fs::write(
+        tempdir.path().join("multicall.toml"),
+        indoc! {r#"
+            [[call]]
+            call_type = "declare"
+            contract_name = "Map"
+            id = "map_class"
+
+            [[call]]
+            call_type = "deploy"
+            class_hash = "@map_class.class_hash"
+            id = "map_contract"
+
+            [[call]]
+            call_type = "invoke"
+            contract_address = "@map_contract"
+            function = "putmulticall_declare"
+            arguments = "0x123, 234"
+        "#},
+    )
+    .unwrap();
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        "multicall.toml",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: multicall run
+        transaction_hash: 0x0[..]
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_reference() {
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    let path = Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join("invoke_unknown_reference.toml");
+    let path = path.to_str().expect("failed converting path to str");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "run",
+        "--url",
+        URL,
+        "--path",
+        path,
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: multicall run
+        error: Reference `@map_contract` does not match any of the previous calls
+        "},
+    );
+}
@@ -0,0 +1,102 @@
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, MULTICALL_CONFIGS_DIR, URL};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use std::path::Path;
+
+fn multicall_config_path(file_name: &str) -> String {
+    let path = project_root::get_project_root().expect("failed to get project root path");
+    Path::new(&path)
+        .join(MULTICALL_CONFIGS_DIR)
+        .join(file_name)
+        .to_str()
+        .expect("failed converting path to str")
+        .to_string()
+}
+
+#[tokio::test]
+async fn test_happy_case() {
+    let path = multicall_config_path("deploy_invoke_arguments.toml");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "validate",
+        "--url",
+        URL,
+        "--path",
+        &path,
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: multicall validate
+        message: Multicall file [..]deploy_invoke_arguments.toml is valid, 4 calls are ready to be executed
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_function() {
+    let path = multicall_config_path("invoke_unknown_function.toml");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "validate",
+        "--url",
+        URL,
+        "--path",
+        &path,
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r#"
+        command: multicall validate
+        error: Function "non_existent_function" not found in ABI of the contract
+        "#},
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_reference() {
+    let path = multicall_config_path("invoke_unknown_reference.toml");
+
+    let args = vec![
+        "--accounts-file",
+        ACCOUNT_FILE_PATH,
+        "--account",
+        "oz",
+        "multicall",
+        "validate",
+        "--url",
+        URL,
+        "--path",
+        &path,
+    ];
+
+    let snapbox = runner(&args);
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: multicall validate
+        error: Reference `@map_contract` does not match any of the previous calls
+        "},
+    );
+}
@@ -0,0 +1,111 @@
+use crate::helpers::constants::{SCRIPTS_DIR, URL};
+use crate::helpers::fixtures::copy_script_directory_to_tempdir;
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+
+#[tokio::test]
+async fn test_happy_case() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/arguments",
+        Vec::<String>::new(),
+    );
+
+    let args = vec![
+        "script",
+        "run",
+        "with_arguments",
+        "--url",
+        URL,
+        "--",
+        "--arguments",
+        r#"0x123, 1000, "token", array![1, 2, 3], true"#,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        owner: 291
+        amount: 1000
+        name: token
+        values: [1, 2, 3]
+        verbose: true
+        command: script run
+        status: success
+    "});
+}
+
+#[tokio::test]
+async fn test_missing_arguments() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/arguments",
+        Vec::<String>::new(),
+    );
+
+    let args = vec!["script", "run", "with_arguments", "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: script run
+        error: Failed to parse script arguments: Script main function expects 5 argument(s), pass them with `-- --arguments`
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_arguments() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/arguments",
+        Vec::<String>::new(),
+    );
+
+    let args = vec![
+        "script",
+        "run",
+        "with_arguments",
+        "--url",
+        URL,
+        "--",
+        "--arguments",
+        "0x123, 1000",
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: script run
+        error: Failed to parse script arguments: Error while processing Cairo-like calldata: Invalid number of arguments: passed 2, expected 5
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_env_and_files() {
+    let tempdir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/arguments",
+        Vec::<String>::new(),
+    );
+
+    let args = vec!["script", "run", "env_and_files", "--url", URL];
+
+    let snapbox = runner(&args)
+        .env("SCRIPT_NETWORK", "'sepolia'")
+        .current_dir(tempdir.path());
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        network: [32481155901843809]
+        name: token
+        salt: 123
+        values: [1, 2, 3]
+        numbers: [1, 2, 499968533861]
+        command: script run
+        status: success
+    "});
+}
@@ -1,7 +1,11 @@
+mod arguments;
 mod call;
 mod declare;
 mod deploy;
 mod general;
 mod init;
 mod invoke;
+mod queries;
+mod simulate;
+mod state;
 mod tx_status;
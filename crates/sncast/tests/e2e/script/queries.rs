@@ -0,0 +1,105 @@
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, SCRIPTS_DIR, URL};
+use crate::helpers::fixtures::{copy_script_directory_to_tempdir, get_accounts_path};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stdout_contains;
+
+#[tokio::test]
+async fn test_contract_not_found() {
+    let tempdir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/queries", Vec::<String>::new());
+
+    let script_name = "contract_not_found";
+    let args = vec!["script", "run", &script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        ScriptCommandError::ProviderError(ProviderError::StarknetError(StarknetError::ContractNotFound(())))
+        ScriptCommandError::ProviderError(ProviderError::StarknetError(StarknetError::ContractNotFound(())))
+        command: script run
+        status: success
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_get_block() {
+    let tempdir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/queries", Vec::<String>::new());
+
+    let script_name = "block_not_found";
+    let args = vec!["script", "run", &script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        ScriptCommandError::ProviderError(ProviderError::StarknetError(StarknetError::BlockNotFound(())))
+        block_number: Option::Some(0)
+        command: script run
+        status: success
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_get_balance() {
+    let tempdir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/queries", Vec::<String>::new());
+
+    let script_name = "balance";
+    let args = vec!["script", "run", &script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        has balance: true
+        command: script run
+        status: success
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_multicall_and_events() {
+    let tempdir =
+        copy_script_directory_to_tempdir(SCRIPTS_DIR.to_owned() + "/queries", Vec::<String>::new());
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "multicall_and_events";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user4",
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+    ];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        fee estimated: true
+        events emitted: true
+        class hash found: true
+        value: 1
+        command: script run
+        status: success
+        "},
+    );
+}
@@ -0,0 +1,137 @@
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, SCRIPTS_DIR, URL};
+use crate::helpers::fixtures::{
+    copy_script_directory_to_tempdir, duplicate_contract_directory_with_salt, get_accounts_path,
+};
+use crate::helpers::runner::runner;
+use indoc::indoc;
+use shared::test_utils::output_assert::assert_stderr_contains;
+use sncast::get_default_state_file_name;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_happy_case() {
+    let contract_dir = duplicate_contract_directory_with_salt(
+        SCRIPTS_DIR.to_owned() + "/map_script/contracts/",
+        "dummy",
+        "simulate",
+    );
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/map_script/scripts/",
+        vec![contract_dir.as_ref()],
+    );
+
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "map_script";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user13",
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+        "--simulate",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        class_hash: 0x[..]
+        estimated_fee: [..]
+        index: 1
+        status: Succeeded
+        transaction: declare
+        command: script run
+        class_hash: 0x[..]
+        contract_address: 0x[..]
+        estimated_fee: [..]
+        index: 2
+        status: Succeeded
+        transaction: deploy
+        command: script run
+        contract_address: 0x[..]
+        estimated_fee: [..]
+        index: 3
+        status: Succeeded
+        transaction: invoke
+        [WARNING] Simulation does not run `__validate__` and `__execute__` of the account [..]
+        command: script run
+        status: success
+    "});
+
+    assert!(
+        !script_dir
+            .path()
+            .join(get_default_state_file_name(script_name, "alpha-sepolia"))
+            .exists()
+    );
+}
+
+#[tokio::test]
+async fn test_broadcast() {
+    let contract_dir = duplicate_contract_directory_with_salt(
+        SCRIPTS_DIR.to_owned() + "/map_script/contracts/",
+        "dummy",
+        "simulate_broadcast",
+    );
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/map_script/scripts/",
+        vec![contract_dir.as_ref()],
+    );
+
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "map_script";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user12",
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+        "--simulate",
+        "--broadcast",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        status: success
+        command: declare
+        class_hash: 0x[..]
+        transaction_hash: 0x[..]
+        ...
+        command: deploy
+        contract_address: 0x[..]
+        transaction_hash: 0x[..]
+        ...
+        command: invoke
+        transaction_hash: 0x[..]
+        ...
+    "});
+}
+
+#[tokio::test]
+async fn test_broadcast_requires_simulate() {
+    let tempdir = tempdir().expect("Unable to create temporary directory");
+
+    let args = vec!["script", "run", "map_script", "--url", URL, "--broadcast"];
+
+    let snapbox = runner(&args).current_dir(tempdir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "error: the following required arguments were not provided:",
+    );
+}
@@ -0,0 +1,194 @@
+use crate::helpers::constants::{ACCOUNT_FILE_PATH, SCRIPTS_DIR, URL};
+use crate::helpers::fixtures::{
+    copy_script_directory_to_tempdir, duplicate_contract_directory_with_salt, get_accounts_path,
+};
+use crate::helpers::runner::runner;
+use camino::Utf8PathBuf;
+use indoc::indoc;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use sncast::state::manifest::load_or_create_manifest;
+use sncast::state::state_file::read_txs_from_state_file;
+use sncast::{get_default_manifest_file_name, get_default_state_file_name};
+use starknet_types_core::felt::Felt;
+
+#[tokio::test]
+async fn test_show() {
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/state_file/",
+        Vec::<String>::new(),
+    );
+
+    let args = vec!["script", "state", "show", "rerun_failed_tx", "--url", URL];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: script state show
+        error: An error occurred in the called contract [..]
+        id: 31829eae07da513c7e6f457b9ac48af0004512db23efeae38734af97834bb273
+        name: invoke
+        status: Error
+        timestamp: 1712758668
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_prune() {
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/state_file/",
+        Vec::<String>::new(),
+    );
+    let script_name = "rerun_failed_tx";
+
+    let args = vec!["script", "state", "prune", script_name, "--url", URL];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+    let output = snapbox.assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: script state prune
+        message: Removed 1 transaction(s) from state file [..]rerun_failed_tx_alpha-sepolia_state.json
+        "},
+    );
+
+    let state_file_path = Utf8PathBuf::from_path_buf(
+        script_dir
+            .path()
+            .join(get_default_state_file_name(script_name, "alpha-sepolia")),
+    )
+    .unwrap();
+    let tx_entries = read_txs_from_state_file(&state_file_path).unwrap().unwrap();
+    assert!(tx_entries.transactions.is_empty());
+}
+
+#[tokio::test]
+async fn test_show_no_state_file() {
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/state_file/",
+        Vec::<String>::new(),
+    );
+
+    let args = vec!["script", "state", "show", "all_tx_fail", "--url", URL];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+    let output = snapbox.assert().failure();
+
+    assert_stderr_contains(
+        output,
+        "Error: State file [..]all_tx_fail_alpha-sepolia_state.json does not exist",
+    );
+}
+
+#[tokio::test]
+async fn test_manifest() {
+    let contract_dir = duplicate_contract_directory_with_salt(
+        SCRIPTS_DIR.to_owned() + "/state_script/contracts/",
+        "dummy",
+        "34548",
+    );
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/state_script/scripts/",
+        vec![contract_dir.as_ref()],
+    );
+
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "state_script";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user9",
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        status: success
+    "});
+
+    let manifest_path = Utf8PathBuf::from_path_buf(
+        script_dir
+            .path()
+            .join(get_default_manifest_file_name(script_name, "alpha-sepolia")),
+    )
+    .unwrap();
+    let manifest_after_first_run = load_or_create_manifest(&manifest_path, Felt::ZERO).unwrap();
+
+    assert_eq!(manifest_after_first_run.chain_id, "SN_SEPOLIA");
+    let declared_contract = manifest_after_first_run.contracts.get("State").unwrap();
+    assert!(declared_contract.block_number.is_some());
+    assert_eq!(manifest_after_first_run.deployments.len(), 1);
+    let deployment = &manifest_after_first_run.deployments[0];
+    assert_eq!(deployment.contract_name.as_deref(), Some("State"));
+    assert_eq!(deployment.class_hash, declared_contract.class_hash);
+    assert!(deployment.constructor_calldata.is_empty());
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        status: success
+    "});
+
+    let manifest_after_second_run = load_or_create_manifest(&manifest_path, Felt::ZERO).unwrap();
+    assert_eq!(manifest_after_first_run, manifest_after_second_run);
+}
+
+#[tokio::test]
+async fn test_manifest_not_written_with_state_file_disabled() {
+    let contract_dir = duplicate_contract_directory_with_salt(
+        SCRIPTS_DIR.to_owned() + "/state_script/contracts/",
+        "dummy",
+        "34549",
+    );
+    let script_dir = copy_script_directory_to_tempdir(
+        SCRIPTS_DIR.to_owned() + "/state_script/scripts/",
+        vec![contract_dir.as_ref()],
+    );
+
+    let accounts_json_path = get_accounts_path(ACCOUNT_FILE_PATH);
+
+    let script_name = "state_script";
+    let args = vec![
+        "--accounts-file",
+        accounts_json_path.as_str(),
+        "--account",
+        "user5",
+        "script",
+        "run",
+        &script_name,
+        "--url",
+        URL,
+        "--no-state-file",
+    ];
+
+    let snapbox = runner(&args).current_dir(script_dir.path());
+
+    snapbox.assert().success().stdout_matches(indoc! {r"
+        ...
+        command: script run
+        status: success
+    "});
+
+    assert!(
+        !script_dir
+            .path()
+            .join(get_default_manifest_file_name(script_name, "alpha-sepolia"))
+            .exists()
+    );
+}
@@ -0,0 +1,112 @@
+use crate::helpers::constants::{CONTRACTS_DIR, DEVNET_OZ_CLASS_HASH_CAIRO_1, URL};
+use crate::helpers::fixtures::{
+    copy_directory_to_tempdir, create_and_deploy_oz_account,
+    duplicate_contract_directory_with_salt, join_tempdirs,
+};
+use crate::helpers::runner::runner;
+use conversions::string::IntoHexStr;
+use indoc::indoc;
+use serde_json::Value;
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+
+#[tokio::test]
+async fn test_happy_case() {
+    let contract_path =
+        duplicate_contract_directory_with_salt(CONTRACTS_DIR.to_string() + "/map", "put", "8");
+    let tempdir = create_and_deploy_oz_account().await;
+    join_tempdirs(&contract_path, &tempdir);
+
+    let args = vec![
+        "--accounts-file",
+        "accounts.json",
+        "--account",
+        "my_account",
+        "--json",
+        "declare",
+        "--url",
+        URL,
+        "--contract-name",
+        "Map",
+    ];
+    let output = runner(&args).current_dir(tempdir.path()).assert().success();
+    let stdout = output.get_output().stdout.clone();
+    let declare_output: Value = serde_json::from_slice(
+        stdout
+            .split(|byte| *byte == b'\n')
+            .find(|line| line.starts_with(b"{"))
+            .unwrap(),
+    )
+    .unwrap();
+    let class_hash = declare_output["class_hash"].as_str().unwrap();
+
+    let args = vec![
+        "verify",
+        "--local",
+        "--class-hash",
+        class_hash,
+        "--contract-name",
+        "Map",
+        "--url",
+        URL,
+    ];
+    let output = runner(&args).current_dir(tempdir.path()).assert().success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: verify
+        class_hash: 0x[..]
+        compiled_class_hash: 0x[..]
+        message: Class hash 0x[..] on chain matches the local build of Map, compiled class hash is computed locally and not compared
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_class_hash_mismatch() {
+    let contract_path = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+    let class_hash = DEVNET_OZ_CLASS_HASH_CAIRO_1.into_hex_string();
+
+    let args = vec![
+        "verify",
+        "--local",
+        "--class-hash",
+        &class_hash,
+        "--contract-name",
+        "Map",
+        "--url",
+        URL,
+    ];
+    let output = runner(&args)
+        .current_dir(contract_path.path())
+        .assert()
+        .failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: verify
+        error: Class hash 0x[..] of the local build of Map does not match class hash 0x[..] on chain
+        ABI: function IMap::get only in the local build
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_requires_address_or_class_hash() {
+    let contract_path = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+
+    let args = vec!["verify", "--local", "--contract-name", "Map", "--url", URL];
+    let output = runner(&args)
+        .current_dir(contract_path.path())
+        .assert()
+        .failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        error: the following required arguments were not provided:
+          --contract-address <CONTRACT_ADDRESS>
+        "},
+    );
+}
@@ -1 +1,4 @@
+mod local;
+mod starkscan;
+mod voyager;
 mod walnut;
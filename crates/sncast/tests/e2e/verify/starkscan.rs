@@ -0,0 +1,93 @@
+use crate::helpers::constants::{CONTRACTS_DIR, MAP_CONTRACT_ADDRESS_SEPOLIA};
+use crate::helpers::fixtures::copy_directory_to_tempdir;
+use crate::helpers::runner::runner;
+use indoc::{formatdoc, indoc};
+use shared::test_utils::output_assert::{assert_stderr_contains, assert_stdout_contains};
+use std::fs;
+use tempfile::TempDir;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn setup(job_status: serde_json::Value) -> (TempDir, MockServer) {
+    let contract_path = copy_directory_to_tempdir(CONTRACTS_DIR.to_string() + "/map");
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/verify_contract"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "job_id": "abc"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/verify_contract/abc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(job_status))
+        .mount(&mock_server)
+        .await;
+
+    fs::write(
+        contract_path.path().join("snfoundry.toml"),
+        formatdoc! {r#"
+            [sncast.default.verification]
+            starkscan-api-url = "{}"
+        "#, mock_server.uri()},
+    )
+    .unwrap();
+
+    (contract_path, mock_server)
+}
+
+fn args() -> Vec<&'static str> {
+    vec![
+        "verify",
+        "--contract-address",
+        MAP_CONTRACT_ADDRESS_SEPOLIA,
+        "--contract-name",
+        "Map",
+        "--verifier",
+        "starkscan",
+        "--network",
+        "sepolia",
+        "--confirm-verification",
+    ]
+}
+
+#[tokio::test]
+async fn test_happy_case() {
+    let (contract_path, _mock_server) = setup(serde_json::json!({ "status": "success" })).await;
+
+    let output = runner(&args())
+        .current_dir(contract_path.path())
+        .assert()
+        .success();
+
+    assert_stdout_contains(
+        output,
+        indoc! {r"
+        command: verify
+        message: Contract successfully verified
+        "},
+    );
+}
+
+#[tokio::test]
+async fn test_failed_verification() {
+    let (contract_path, _mock_server) = setup(serde_json::json!({
+        "status": "failed",
+        "message": "Compilation failed"
+    }))
+    .await;
+
+    let output = runner(&args())
+        .current_dir(contract_path.path())
+        .assert()
+        .failure();
+
+    assert_stderr_contains(
+        output,
+        indoc! {r"
+        command: verify
+        error: Verification job abc failed: Compilation failed
+        "},
+    );
+}
@@ -499,6 +499,7 @@ pub fn get_address_from_keystore(
                 .unwrap(),
         )
         .unwrap(),
+        AccountType::Custom => unreachable!("Custom accounts are not supported with keystore"),
     };
 
     let calldata = match account_type {
@@ -506,6 +507,7 @@ pub fn get_address_from_keystore(
             vec![private_key.verifying_key().scalar()]
         }
         AccountType::Argent => vec![private_key.verifying_key().scalar(), Felt::ZERO],
+        AccountType::Custom => unreachable!("Custom accounts are not supported with keystore"),
     };
 
     get_contract_address(salt, class_hash, &calldata, Felt::ZERO)
@@ -574,6 +576,7 @@ pub async fn create_and_deploy_account(class_hash: Felt, account_type: AccountTy
         AccountType::OpenZeppelin => "oz",
         AccountType::Argent => "argent",
         AccountType::Braavos => "braavos",
+        AccountType::Custom => "custom",
     };
     let tempdir = tempdir().unwrap();
     let accounts_file = "accounts.json";
@@ -1,11 +1,11 @@
 use self::block_id::{BlockId, BlockIdVariants};
 use crate::{
-    args::Arguments,
+    args::{named::NamedArgs, Arguments},
     attributes::{AttributeCollector, AttributeInfo, AttributeTypeData},
     branch,
     cairo_expression::CairoExpression,
     config_statement::extend_with_config_cheatcodes,
-    types::ParseFromExpr,
+    types::{Number, ParseFromExpr},
 };
 use cairo_lang_macro::{Diagnostic, Diagnostics, ProcMacroResult, Severity, TokenStream};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -52,16 +52,19 @@ fn inline_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Diagnos
 
     let block_id = BlockId::parse_from_expr::<ForkCollector>(db, &block_id, block_id.0.as_ref())?;
     let url = Url::parse_from_expr::<ForkCollector>(db, url, "url")?;
+    let transaction_index = transaction_index(db, &named_args)?;
 
     let block_id = block_id.as_cairo_expression();
     let url = url.as_cairo_expression();
+    let transaction_index = transaction_index.as_cairo_expression();
 
     Ok(formatdoc!(
         "
             snforge_std::_config_types::ForkConfig::Inline(
                 snforge_std::_config_types::InlineForkConfig {{
                     url: {url},
-                    block: {block_id}
+                    block: {block_id},
+                    transaction_index: {transaction_index}
                 }}
             )
         "
@@ -93,22 +96,31 @@ fn overridden_args(db: &dyn SyntaxGroup, args: &Arguments) -> Result<String, Dia
 
     let block_id = BlockId::parse_from_expr::<ForkCollector>(db, &block_id, block_id.0.as_ref())?;
     let name = String::parse_from_expr::<ForkCollector>(db, arg.1, arg.0.to_string().as_str())?;
+    let transaction_index = transaction_index(db, &args.named)?;
 
     let block_id = block_id.as_cairo_expression();
     let name = name.as_cairo_expression();
+    let transaction_index = transaction_index.as_cairo_expression();
 
     Ok(formatdoc!(
         "
             snforge_std::_config_types::ForkConfig::Overridden(
                 snforge_std::_config_types::OverriddenForkConfig {{
                     block: {block_id},
-                    name: {name}
+                    name: {name},
+                    transaction_index: {transaction_index}
                 }}
             )
         "
     ))
 }
 
+fn transaction_index(db: &dyn SyntaxGroup, args: &NamedArgs) -> Result<Option<Number>, Diagnostic> {
+    args.as_once_optional("transaction_index")?
+        .map(|arg| Number::parse_from_expr::<ForkCollector>(db, arg, "transaction_index"))
+        .transpose()
+}
+
 #[must_use]
 pub fn fork(args: TokenStream, item: TokenStream) -> ProcMacroResult {
     extend_with_config_cheatcodes::<ForkCollector>(args, item)
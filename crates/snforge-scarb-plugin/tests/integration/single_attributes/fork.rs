@@ -139,7 +139,45 @@ fn accepts_inline_config() {
                     snforge_std::_config_types::ForkConfig::Inline(
                         snforge_std::_config_types::InlineForkConfig {
                             url: "http://example.com/",
-                            block: snforge_std::_config_types::BlockId::BlockNumber(0x17)
+                            block: snforge_std::_config_types::BlockId::BlockNumber(0x17),
+                            transaction_index: Option::None
+                        }
+                    )
+                    .serialize(ref data);
+
+                    starknet::testing::cheatcode::<'set_config_fork'>(data.span());
+
+                    return;
+                }
+            }
+        "#,
+    );
+}
+
+#[test]
+fn accepts_inline_config_with_transaction_index() {
+    let item = TokenStream::new(EMPTY_FN.into());
+    let args = TokenStream::new(
+        r#"(url: "http://example.com", block_number: 23, transaction_index: 3)"#.into(),
+    );
+
+    let result = fork(args, item);
+
+    assert_diagnostics(&result, &[]);
+
+    assert_output(
+        &result,
+        r#"
+            fn empty_fn() {
+                if snforge_std::_internals::_is_config_run() {
+
+                    let mut data = array![];
+
+                    snforge_std::_config_types::ForkConfig::Inline(
+                        snforge_std::_config_types::InlineForkConfig {
+                            url: "http://example.com/",
+                            block: snforge_std::_config_types::BlockId::BlockNumber(0x17),
+                            transaction_index: Option::Some(0x3)
                         }
                     )
                     .serialize(ref data);
@@ -173,7 +211,8 @@ fn overriding_config_name_first() {
                     snforge_std::_config_types::ForkConfig::Overridden(
                         snforge_std::_config_types::OverriddenForkConfig {
                             block: snforge_std::_config_types::BlockId::BlockNumber(0x17),
-                            name: "MAINNET"
+                            name: "MAINNET",
+                            transaction_index: Option::None
                         }
                      )
                     .serialize(ref data);
@@ -207,7 +246,8 @@ fn overriding_config_name_second() {
                     snforge_std::_config_types::ForkConfig::Overridden(
                         snforge_std::_config_types::OverriddenForkConfig {
                             block: snforge_std::_config_types::BlockId::BlockNumber(0x17),
-                            name: "MAINNET"
+                            name: "MAINNET",
+                            transaction_index: Option::None
                         }
                     )
                     .serialize(ref data);
@@ -0,0 +1,34 @@
+use sncast_std::{multicall, estimate_fee, get_events, Call, FeeSettings};
+use starknet::ContractAddress;
+
+fn main() {
+    let contract_address: ContractAddress =
+        0x07537a17e169c96cf2b0392508b3a66cbc50c9a811a8a7896529004c5e93fdf6
+        .try_into()
+        .expect('Invalid contract address value');
+
+    let calls = array![
+        Call {
+            contract_address,
+            entry_point_selector: selector!("put"),
+            calldata: array![0x1, 0x2],
+        },
+        Call {
+            contract_address,
+            entry_point_selector: selector!("put"),
+            calldata: array![0x3, 0x4],
+        },
+    ];
+
+    let fee = estimate_fee(calls.clone()).expect('Failed to estimate fee');
+    println!("estimated fee: {}", fee.overall_fee);
+
+    let fee_settings = FeeSettings {
+        max_fee: Option::None, max_gas: Option::None, max_gas_unit_price: Option::None
+    };
+    let result = multicall(calls, fee_settings, Option::None).expect('Multicall failed');
+    println!("transaction hash: {}", result.transaction_hash);
+
+    let events = get_events(result.transaction_hash).expect('Failed to get events');
+    println!("events: {:?}", events);
+}
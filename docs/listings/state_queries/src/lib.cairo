@@ -0,0 +1,25 @@
+use sncast_std::{
+    get_storage_at, get_class_hash_at, get_block, get_balance, BlockId, BlockTag, STRK_ADDRESS,
+};
+use starknet::ContractAddress;
+
+fn main() {
+    let contract_address: ContractAddress =
+        0x07537a17e169c96cf2b0392508b3a66cbc50c9a811a8a7896529004c5e93fdf6
+        .try_into()
+        .expect('Invalid contract address value');
+
+    let class_hash = get_class_hash_at(contract_address).expect('Failed to get class hash');
+    println!("class hash: {}", class_hash);
+
+    let value = get_storage_at(contract_address, selector!("owner"))
+        .expect('Failed to read storage');
+    println!("owner: {}", value);
+
+    let block = get_block(BlockId::Tag(BlockTag::Latest)).expect('Failed to get block');
+    println!("block number: {:?}, timestamp: {}", block.block_number, block.timestamp);
+
+    let strk = STRK_ADDRESS.try_into().expect('Invalid contract address value');
+    let balance = get_balance(strk, contract_address).expect('Failed to get balance');
+    println!("balance: {}", balance);
+}
@@ -0,0 +1,35 @@
+use snforge_std::{
+    declare, ContractClassTrait, DeclareResultTrait, spy_events, EventMatcherTrait,
+    EventSpyMatchingTrait, // Add for assertions with event matchers
+};
+
+use testing_events::contract::{ISpyEventsCheckerDispatcher, ISpyEventsCheckerDispatcherTrait};
+
+#[test]
+fn test_matching_assertions() {
+    let contract = declare("SpyEventsChecker").unwrap().contract_class();
+    let (contract_address, _) = contract.deploy(@array![]).unwrap();
+    let dispatcher = ISpyEventsCheckerDispatcher { contract_address };
+
+    let mut spy = spy_events();
+
+    dispatcher.emit_one_event(123);
+    dispatcher.emit_one_event(456);
+    dispatcher.emit_one_event(123);
+
+    let first_event = EventMatcherTrait::new("FirstEvent") // Ad. 1
+        .emitted_by(contract_address)
+        .with_field("some_data", 123);
+    let second_event = EventMatcherTrait::new("FirstEvent").with_field("some_data", 456);
+
+    spy.assert_emitted_matching(@first_event); // Ad. 2
+    spy.assert_emitted_matching_times(@first_event, 2);
+    spy
+        .assert_not_emitted_matching(
+            @EventMatcherTrait::new("FirstEvent").with_field("some_data", 789)
+        );
+
+    assert(spy.count_matching(@EventMatcherTrait::new("FirstEvent")) == 3, 'Wrong events count');
+
+    spy.assert_emitted_in_order(@array![first_event, second_event]); // Ad. 3
+}
@@ -389,6 +389,254 @@ pub fn tx_status(transaction_hash: felt252) -> Result<TxStatusResult, ScriptComm
     result_data
 }
 
+/// Reads the value of a storage variable of a contract
+/// `contract_address` - address of the contract
+/// `key` - address of the storage variable, e.g. `selector!("balance")`
+pub fn get_storage_at(
+    contract_address: ContractAddress, key: felt252
+) -> Result<felt252, ScriptCommandError> {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt, key];
+
+    let mut buf = handle_cheatcode(cheatcode::<'get_storage_at'>(inputs.span()));
+
+    let mut result_data: Result<felt252, ScriptCommandError> =
+        match Serde::<Result<felt252>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("get_storage_at deserialize failed")
+    };
+
+    result_data
+}
+
+/// Returns the class hash of a contract deployed at `contract_address`
+pub fn get_class_hash_at(
+    contract_address: ContractAddress
+) -> Result<ClassHash, ScriptCommandError> {
+    let contract_address_felt: felt252 = contract_address.into();
+    let mut inputs = array![contract_address_felt];
+
+    let mut buf = handle_cheatcode(cheatcode::<'get_class_hash_at'>(inputs.span()));
+
+    let mut result_data: Result<ClassHash, ScriptCommandError> =
+        match Serde::<Result<ClassHash>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("get_class_hash_at deserialize failed")
+    };
+
+    result_data
+}
+
+#[derive(Drop, Copy, Debug, Serde, PartialEq)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+}
+
+#[derive(Drop, Copy, Debug, Serde, PartialEq)]
+pub enum BlockId {
+    Tag: BlockTag,
+    Hash: felt252,
+    Number: u64,
+}
+
+#[derive(Drop, Clone, Debug, Serde, PartialEq)]
+pub struct BlockResult {
+    /// `None` for the pending block
+    pub block_hash: Option<felt252>,
+    pub parent_hash: felt252,
+    /// `None` for the pending block
+    pub block_number: Option<u64>,
+    pub timestamp: u64,
+    pub sequencer_address: ContractAddress,
+    pub transaction_hashes: Array<felt252>,
+}
+
+/// Returns the block with hashes of its transactions
+/// The block is always fetched from the network, also when running with `--simulate`
+pub fn get_block(block_id: BlockId) -> Result<BlockResult, ScriptCommandError> {
+    let mut inputs = array![];
+    block_id.serialize(ref inputs);
+
+    let mut buf = handle_cheatcode(cheatcode::<'get_block'>(inputs.span()));
+
+    let mut result_data: Result<BlockResult, ScriptCommandError> =
+        match Serde::<Result<BlockResult>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("get_block deserialize failed")
+    };
+
+    result_data
+}
+
+#[derive(Drop, Clone, Debug, Serde, PartialEq)]
+pub struct Event {
+    pub from_address: ContractAddress,
+    pub keys: Array<felt252>,
+    pub data: Array<felt252>,
+}
+
+/// Returns events emitted by the transaction with `transaction_hash`
+pub fn get_events(transaction_hash: felt252) -> Result<Array<Event>, ScriptCommandError> {
+    let mut inputs = array![transaction_hash];
+
+    let mut buf = handle_cheatcode(cheatcode::<'get_events'>(inputs.span()));
+
+    let mut result_data: Result<Array<Event>, ScriptCommandError> =
+        match Serde::<Result<Array<Event>>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("get_events deserialize failed")
+    };
+
+    result_data
+}
+
+#[derive(Drop, Clone, Debug, Serde, PartialEq)]
+pub struct Call {
+    pub contract_address: ContractAddress,
+    pub entry_point_selector: felt252,
+    pub calldata: Array<felt252>,
+}
+
+#[derive(Drop, Copy, Debug, Serde, PartialEq)]
+pub struct EstimateFeeResult {
+    pub overall_fee: felt252,
+}
+
+/// Estimates the fee of an invoke transaction executing `calls` from the script account
+pub fn estimate_fee(calls: Array<Call>) -> Result<EstimateFeeResult, ScriptCommandError> {
+    let mut inputs = array![];
+    calls.serialize(ref inputs);
+
+    let mut buf = handle_cheatcode(cheatcode::<'estimate_fee'>(inputs.span()));
+
+    let mut result_data: Result<EstimateFeeResult, ScriptCommandError> =
+        match Serde::<Result<EstimateFeeResult>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("estimate_fee deserialize failed")
+    };
+
+    result_data
+}
+
+/// Executes `calls` in a single invoke transaction
+pub fn multicall(
+    calls: Array<Call>, fee_settings: FeeSettings, nonce: Option<felt252>
+) -> Result<InvokeResult, ScriptCommandError> {
+    let mut inputs = array![];
+    calls.serialize(ref inputs);
+
+    let mut fee_settings_serialized = array![];
+    fee_settings.serialize(ref fee_settings_serialized);
+
+    let mut nonce_serialized = array![];
+    nonce.serialize(ref nonce_serialized);
+
+    inputs.append_span(fee_settings_serialized.span());
+    inputs.append_span(nonce_serialized.span());
+
+    let mut buf = handle_cheatcode(cheatcode::<'multicall'>(inputs.span()));
+
+    let mut result_data: Result<InvokeResult, ScriptCommandError> =
+        match Serde::<Result<InvokeResult>>::deserialize(ref buf) {
+        Option::Some(result_data) => result_data,
+        Option::None => panic!("multicall deserialize failed")
+    };
+
+    result_data
+}
+
+/// Address of the STRK token contract, the same on Mainnet and Sepolia
+pub const STRK_ADDRESS: felt252 =
+    0x04718f5a0fc34cc1af16a1cdee98ffb20c31f5cd61d6ab07201858f4287c938d;
+/// Address of the ETH token contract, the same on Mainnet and Sepolia
+pub const ETH_ADDRESS: felt252 = 0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7;
+
+// `selector!("balance_of")`
+const BALANCE_OF_SELECTOR: felt252 =
+    0x35a73cd311a05d46deda634c5ee045db92f811b4e74bca4437fcb5302b7af33;
+
+/// Returns the balance of `account_address` in the ERC20 `token` by calling its `balance_of`
+pub fn get_balance(
+    token: ContractAddress, account_address: ContractAddress
+) -> Result<u256, ScriptCommandError> {
+    let account_address_felt: felt252 = account_address.into();
+    let call_result = call(token, BALANCE_OF_SELECTOR, array![account_address_felt])?;
+
+    let mut data = call_result.data.span();
+    match Serde::<u256>::deserialize(ref data) {
+        Option::Some(balance) => Result::Ok(balance),
+        Option::None => panic!("get_balance deserialize failed")
+    }
+}
+
+/// Reads an environment variable and parses it the same way as `--arguments` literals:
+/// hex or decimal numbers, 'short strings' and "strings" (serialized as `ByteArray`)
+/// `name` - name of an environment variable
+/// Returns the read array of felts, panics if the variable is not set
+pub fn var(name: ByteArray) -> Array<felt252> {
+    let mut inputs = array![];
+    name.serialize(ref inputs);
+    handle_cheatcode(cheatcode::<'var'>(inputs.span())).into()
+}
+
+#[derive(Drop, Clone)]
+pub struct File {
+    path: ByteArray
+}
+
+pub trait FileTrait {
+    /// Creates a file struct used for reading json / text
+    /// `path` - a path to file in ByteArray form, relative to the script package root
+    fn new(path: ByteArray) -> File;
+}
+
+impl FileTraitImpl of FileTrait {
+    fn new(path: ByteArray) -> File {
+        File { path }
+    }
+}
+
+/// `file` - a `File` struct to read text data from
+/// Returns an array of felts read from the file, panics if read was not possible
+pub fn read_txt(file: @File) -> Array<felt252> {
+    let mut inputs = array![];
+    file.path.serialize(ref inputs);
+    handle_cheatcode(cheatcode::<'read_txt'>(inputs.span())).into()
+}
+
+/// `file` - a `File` struct to read json data from
+/// Returns an array of felts read from the file, panics if read was not possible, or json was
+/// incorrect
+pub fn read_json(file: @File) -> Array<felt252> {
+    let mut inputs = array![];
+    file.path.serialize(ref inputs);
+    handle_cheatcode(cheatcode::<'read_json'>(inputs.span())).into()
+}
+
+pub trait FileParser<T, impl TSerde: Serde<T>> {
+    /// Reads from the text file and tries to deserialize the result into given type with `Serde`
+    /// `file` - File instance
+    /// Returns an instance of `T` if deserialization was possible
+    fn parse_txt(file: @File) -> Option<T>;
+    /// Reads from the json file and tries to deserialize the result into given type with `Serde`
+    /// `file` - File instance
+    /// Returns an instance of `T` if deserialization was possible
+    fn parse_json(file: @File) -> Option<T>;
+}
+
+impl FileParserImpl<T, impl TSerde: Serde<T>> of FileParser<T> {
+    fn parse_txt(file: @File) -> Option<T> {
+        let mut content = read_txt(file).span();
+        Serde::<T>::deserialize(ref content)
+    }
+
+    fn parse_json(file: @File) -> Option<T> {
+        let mut content = read_json(file).span();
+        Serde::<T>::deserialize(ref content)
+    }
+}
+
 fn handle_cheatcode(input: Span<felt252>) -> Span<felt252> {
     let first = *input.at(0);
     let input = input.slice(1, input.len() - 1);
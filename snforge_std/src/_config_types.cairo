@@ -13,13 +13,15 @@ pub enum BlockId {
 #[derive(Drop, Serde)]
 pub struct InlineForkConfig {
     pub url: ByteArray,
-    pub block: BlockId
+    pub block: BlockId,
+    pub transaction_index: Option<felt252>
 }
 
 #[derive(Drop, Serde)]
 pub struct OverriddenForkConfig {
     pub name: ByteArray,
-    pub block: BlockId
+    pub block: BlockId,
+    pub transaction_index: Option<felt252>
 }
 
 #[derive(Drop, Serde)]
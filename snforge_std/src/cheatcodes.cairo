@@ -1,11 +1,20 @@
 use starknet::{ContractAddress, ClassHash, contract_address_const};
 use super::_cheatcode::execute_cheatcode_and_deserialize;
 
+pub mod block_progression;
 pub mod events;
+pub mod expect;
 pub mod l1_handler;
+pub mod l1_messaging;
+pub mod mock_contract;
 pub mod contract_class;
+pub mod contract_state;
 pub mod storage;
+pub mod storage_accesses;
+pub mod state_diff;
+pub mod transaction;
 pub mod execution_info;
+pub mod fork;
 pub mod message_to_l1;
 pub mod generate_random_felt;
 pub mod generate_arg;
@@ -19,6 +28,25 @@ pub enum CheatSpan {
     /// Applies the cheatcode for a specified number of calls to the target,
     /// after which the cheat is canceled (or until the cheat is canceled manually).
     TargetCalls: usize,
+    /// Applies the cheatcode only to calls to the target made by the given caller, for a specified
+    /// number of such calls. Calls to the target made by other contracts are not cheated.
+    TargetCallsFrom: (ContractAddress, usize),
+    /// Applies the cheatcode to calls to the target until and including the first call of the
+    /// given selector, after which the cheat is canceled.
+    UntilSelector: felt252,
+}
+
+/// Panics if the span targets 0 calls, as such a cheat could never be applied
+pub(crate) fn validate_cheat_span(span: CheatSpan) {
+    let n_calls = match span {
+        CheatSpan::TargetCalls(n) => n,
+        CheatSpan::TargetCallsFrom((_, n)) => n,
+        _ => { return; },
+    };
+
+    assert!(
+        n_calls > 0, "cannot cheat 0 calls, number of calls in CheatSpan must be greater than 0"
+    );
 }
 
 pub fn test_selector() -> felt252 {
@@ -0,0 +1,37 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Sets the timestamp of the current block for all subsequent calls
+/// Cheats of the block timestamp made with `cheat_block_timestamp` take precedence
+/// - `block_timestamp` - the new block timestamp
+pub fn warp(block_timestamp: u64) {
+    execute_cheatcode_and_deserialize::<'warp', ()>(array![block_timestamp.into()].span());
+}
+
+/// Sets the number of the current block for all subsequent calls
+/// Hashes of the preceding blocks follow the default block hash history
+/// Cheats of the block number made with `cheat_block_number` take precedence
+/// - `block_number` - the new block number
+pub fn roll(block_number: u64) {
+    execute_cheatcode_and_deserialize::<'roll', ()>(array![block_number.into()].span());
+}
+
+/// Moves the chain forward by the given number of blocks, increasing the block number by `count`
+/// and the block timestamp by `count` times the block time set with `set_block_time`
+/// - `count` - number of blocks to advance
+pub fn advance_blocks(count: u64) {
+    execute_cheatcode_and_deserialize::<'advance_blocks', ()>(array![count.into()].span());
+}
+
+/// Sets the number of seconds between blocks produced by `advance_blocks`, 6 by default
+/// - `block_time` - seconds between blocks
+pub fn set_block_time(block_time: u64) {
+    execute_cheatcode_and_deserialize::<'set_block_time', ()>(array![block_time.into()].span());
+}
+
+/// Sets the sequencer address of the current block for all subsequent calls
+/// Cheats of the sequencer address made with `cheat_sequencer_address` take precedence
+/// - `sequencer_address` - the new sequencer address
+pub fn set_sequencer(sequencer_address: ContractAddress) {
+    execute_cheatcode_and_deserialize::<'set_sequencer', ()>(array![sequencer_address.into()].span());
+}
@@ -0,0 +1,29 @@
+use starknet::{ContractAddress, ClassHash};
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Sets the nonce of the contract at the given address, it may be lower than the current one.
+/// - `target` - address of the contract, which nonce you want to set
+/// - `nonce` - the new nonce of the contract
+pub fn set_nonce(target: ContractAddress, nonce: felt252) {
+    execute_cheatcode_and_deserialize::<'set_nonce', ()>(array![target.into(), nonce].span());
+}
+
+/// Gets the nonce of the contract at the given address.
+/// - `target` - address of the contract, which nonce you want to get
+/// Returns the current nonce of the contract
+pub fn get_nonce(target: ContractAddress) -> felt252 {
+    execute_cheatcode_and_deserialize::<'get_nonce'>(array![target.into()].span())
+}
+
+/// Sets the class of the contract at the given address in the state, e.g. to simulate an upgrade.
+/// Unlike `replace_bytecode`, the change is visible in the state like a `replace_class_syscall`,
+/// and an address with no contract can be targeted as well. Cancels `replace_bytecode` made for
+/// the contract.
+/// The test fails if the class is not declared.
+/// - `target` - address of the contract, which class you want to set
+/// - `class_hash` - hash of the declared class
+pub fn set_class_hash(target: ContractAddress, class_hash: ClassHash) {
+    execute_cheatcode_and_deserialize::<
+        'set_class_hash', ()
+    >(array![target.into(), class_hash.into()].span());
+}
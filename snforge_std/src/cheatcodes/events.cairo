@@ -136,3 +136,157 @@ impl EventTraitImpl of starknet::Event<Event> {
         Option::None
     }
 }
+
+/// Field of an event to match, with its value serialized with `Serde`
+#[derive(Drop, Clone, Serde, Debug)]
+pub struct EventFieldMatcher {
+    pub name: ByteArray,
+    pub value: Array<felt252>
+}
+
+/// Describes events to match by their name and a subset of their fields.
+/// Events are decoded using the ABI of the contract which emitted them,
+/// so only events emitted by contracts from the project can be matched.
+#[derive(Drop, Clone, Serde, Debug)]
+pub struct EventMatcher {
+    /// Address of the contract which emitted the event, any contract if `None`
+    pub from: Option<ContractAddress>,
+    /// Name of the event, either the type name (e.g. `Transfer`) or its full path
+    pub name: ByteArray,
+    pub fields: Array<EventFieldMatcher>
+}
+
+pub trait EventMatcherTrait {
+    /// Creates [`EventMatcher`] matching all events named `name`
+    fn new(name: ByteArray) -> EventMatcher;
+    /// Matches only events emitted by the given [`ContractAddress`]
+    fn emitted_by(self: EventMatcher, contract_address: ContractAddress) -> EventMatcher;
+    /// Matches only events with the field named `name` equal to `value`
+    fn with_field<T, +Serde<T>, +Drop<T>>(
+        self: EventMatcher, name: ByteArray, value: T
+    ) -> EventMatcher;
+}
+
+impl EventMatcherTraitImpl of EventMatcherTrait {
+    fn new(name: ByteArray) -> EventMatcher {
+        EventMatcher { from: Option::None, name, fields: array![] }
+    }
+
+    fn emitted_by(self: EventMatcher, contract_address: ContractAddress) -> EventMatcher {
+        EventMatcher { from: Option::Some(contract_address), name: self.name, fields: self.fields }
+    }
+
+    fn with_field<T, +Serde<T>, +Drop<T>>(
+        self: EventMatcher, name: ByteArray, value: T
+    ) -> EventMatcher {
+        let mut serialized_value = array![];
+        value.serialize(ref serialized_value);
+
+        let mut fields = self.fields;
+        fields.append(EventFieldMatcher { name, value: serialized_value });
+
+        EventMatcher { from: self.from, name: self.name, fields }
+    }
+}
+
+/// Allows to filter and assert events emitted in the scope of [`EventSpy`] structure
+/// using [`EventMatcher`]s. Failed assertions print the expected event
+/// and the closest of the emitted ones.
+pub trait EventSpyMatchingTrait {
+    /// Gets events matched by `matcher`, in the order they were emitted
+    fn get_matching_events(ref self: EventSpy, matcher: @EventMatcher) -> Events;
+    /// Counts events matched by `matcher`
+    fn count_matching(ref self: EventSpy, matcher: @EventMatcher) -> usize;
+    fn assert_emitted_matching(ref self: EventSpy, matcher: @EventMatcher);
+    /// Asserts that exactly `times` events are matched by `matcher`
+    fn assert_emitted_matching_times(ref self: EventSpy, matcher: @EventMatcher, times: usize);
+    fn assert_not_emitted_matching(ref self: EventSpy, matcher: @EventMatcher);
+    /// Asserts that each of `matchers` matches an event emitted after the event matched by
+    /// the previous one
+    fn assert_emitted_in_order(ref self: EventSpy, matchers: @Array<EventMatcher>);
+}
+
+impl EventSpyMatchingTraitImpl of EventSpyMatchingTrait {
+    fn get_matching_events(ref self: EventSpy, matcher: @EventMatcher) -> Events {
+        let indices = get_matching_indices(self.event_offset, matcher);
+        let events = self.get_events();
+
+        let mut i = 0;
+        let mut matching_events = array![];
+
+        while i < indices.len() {
+            let (from, event) = events.events.at(*indices.at(i));
+            matching_events.append((*from, event.clone()));
+            i += 1;
+        };
+        Events { events: matching_events }
+    }
+
+    fn count_matching(ref self: EventSpy, matcher: @EventMatcher) -> usize {
+        get_matching_indices(self.event_offset, matcher).len()
+    }
+
+    fn assert_emitted_matching(ref self: EventSpy, matcher: @EventMatcher) {
+        if get_matching_indices(self.event_offset, matcher).is_empty() {
+            let description = describe_event_mismatch(self.event_offset, matcher);
+            panic!("Event matching the expected one was not emitted\n{}", description);
+        }
+    }
+
+    fn assert_emitted_matching_times(ref self: EventSpy, matcher: @EventMatcher, times: usize) {
+        let count = get_matching_indices(self.event_offset, matcher).len();
+        if count != times {
+            let description = describe_event_mismatch(self.event_offset, matcher);
+            panic!(
+                "Event matching the expected one was emitted {} times instead of {}\n{}",
+                count,
+                times,
+                description
+            );
+        }
+    }
+
+    fn assert_not_emitted_matching(ref self: EventSpy, matcher: @EventMatcher) {
+        if !get_matching_indices(self.event_offset, matcher).is_empty() {
+            let description = describe_event_mismatch(self.event_offset, matcher);
+            panic!("Event matching the expected one was emitted\n{}", description);
+        }
+    }
+
+    fn assert_emitted_in_order(ref self: EventSpy, matchers: @Array<EventMatcher>) {
+        let mut event_offset = self.event_offset;
+        let mut i = 0;
+
+        while i < matchers.len() {
+            let matcher = matchers.at(i);
+            match get_matching_indices(event_offset, matcher).get(0) {
+                Option::Some(index) => { event_offset += *index.unbox() + 1; },
+                Option::None => {
+                    let description = describe_event_mismatch(event_offset, matcher);
+                    panic!(
+                        "Event matching the expected one at position {} was not emitted after the previous ones\n{}",
+                        i,
+                        description
+                    );
+                }
+            }
+
+            i += 1;
+        };
+    }
+}
+
+/// Returns indices of events emitted since `event_offset` which are matched by `matcher`
+fn get_matching_indices(event_offset: usize, matcher: @EventMatcher) -> Array<usize> {
+    let mut inputs = array![event_offset.into()];
+    matcher.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'get_matching_events'>(inputs.span())
+}
+
+fn describe_event_mismatch(event_offset: usize, matcher: @EventMatcher) -> ByteArray {
+    let mut inputs = array![event_offset.into()];
+    matcher.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'describe_event_mismatch'>(inputs.span())
+}
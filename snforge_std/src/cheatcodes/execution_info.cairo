@@ -1,10 +1,11 @@
 use starknet::ContractAddress;
 use starknet::ResourcesBounds;
-use snforge_std::cheatcodes::CheatSpan;
+use snforge_std::cheatcodes::{CheatSpan, validate_cheat_span};
 use super::super::_cheatcode::execute_cheatcode_and_deserialize;
 
 pub mod caller_address;
 pub mod block_number;
+pub mod block_hash;
 pub mod block_timestamp;
 pub mod sequencer_address;
 pub mod version;
@@ -38,6 +39,14 @@ enum Operation<T> {
     Retain,
 }
 
+/// Panics if the operation starts a cheat with a span targeting 0 calls
+fn validate_operation<T>(operation: @Operation<T>) {
+    match operation {
+        Operation::Start(arguments) => validate_cheat_span(*arguments.span),
+        _ => {},
+    }
+}
+
 /// A structure used for setting individual fields in `TxInfo`
 /// All fields are wrapped into `Operation`, meaning that the field will be:
 /// - `Retain` - unchanged
@@ -141,6 +150,24 @@ impl ExecutionInfoMockImpl of Default<ExecutionInfoMock> {
 /// - `execution_info_mock` - a struct with same structure as `ExecutionInfo` (returned by
 /// `get_execution_info()`)
 fn cheat_execution_info(execution_info_mock: ExecutionInfoMock) {
+    validate_operation(@execution_info_mock.block_info.block_number);
+    validate_operation(@execution_info_mock.block_info.block_timestamp);
+    validate_operation(@execution_info_mock.block_info.sequencer_address);
+    validate_operation(@execution_info_mock.tx_info.version);
+    validate_operation(@execution_info_mock.tx_info.account_contract_address);
+    validate_operation(@execution_info_mock.tx_info.max_fee);
+    validate_operation(@execution_info_mock.tx_info.signature);
+    validate_operation(@execution_info_mock.tx_info.transaction_hash);
+    validate_operation(@execution_info_mock.tx_info.chain_id);
+    validate_operation(@execution_info_mock.tx_info.nonce);
+    validate_operation(@execution_info_mock.tx_info.resource_bounds);
+    validate_operation(@execution_info_mock.tx_info.tip);
+    validate_operation(@execution_info_mock.tx_info.paymaster_data);
+    validate_operation(@execution_info_mock.tx_info.nonce_data_availability_mode);
+    validate_operation(@execution_info_mock.tx_info.fee_data_availability_mode);
+    validate_operation(@execution_info_mock.tx_info.account_deployment_data);
+    validate_operation(@execution_info_mock.caller_address);
+
     let mut inputs = array![];
 
     execution_info_mock.serialize(ref inputs);
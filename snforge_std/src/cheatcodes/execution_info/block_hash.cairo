@@ -0,0 +1,63 @@
+use super::{
+    Operation, CheatArguments, CheatSpan, ContractAddress, execute_cheatcode_and_deserialize,
+    validate_operation
+};
+
+/// Changes the hash of the given block returned by `get_block_hash_syscall` for the given contract
+/// address and span.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `block_number` - number of the block whose hash is changed
+/// - `block_hash` - block hash to be set
+/// - `span` - instance of `CheatSpan` specifying the number of contract calls with the cheat
+/// applied
+pub fn cheat_block_hash(
+    contract_address: ContractAddress, block_number: u64, block_hash: felt252, span: CheatSpan
+) {
+    cheat_block_hash_operation(
+        block_number,
+        Operation::Start(CheatArguments { value: block_hash, span, target: contract_address, })
+    );
+}
+
+/// Changes the hash of the given block returned by `get_block_hash_syscall`.
+/// - `block_number` - number of the block whose hash is changed
+/// - `block_hash` - block hash to be set
+pub fn start_cheat_block_hash_global(block_number: u64, block_hash: felt252) {
+    cheat_block_hash_operation(block_number, Operation::StartGlobal(block_hash));
+}
+
+/// Cancels the `start_cheat_block_hash_global` for the given block.
+/// - `block_number` - number of the block whose hash is not cheated anymore
+pub fn stop_cheat_block_hash_global(block_number: u64) {
+    cheat_block_hash_operation(block_number, Operation::StopGlobal);
+}
+
+/// Changes the hash of the given block returned by `get_block_hash_syscall` for the given
+/// contract_address.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to cheat
+/// - `block_number` - number of the block whose hash is changed
+/// - `block_hash` - block hash to be set
+pub fn start_cheat_block_hash(
+    contract_address: ContractAddress, block_number: u64, block_hash: felt252
+) {
+    cheat_block_hash(contract_address, block_number, block_hash, CheatSpan::Indefinite);
+}
+
+/// Cancels the `cheat_block_hash` / `start_cheat_block_hash` for the given contract_address and
+/// block.
+/// - `contract_address` - instance of `ContractAddress` specifying which contract to stop cheating
+/// - `block_number` - number of the block whose hash is not cheated anymore
+pub fn stop_cheat_block_hash(contract_address: ContractAddress, block_number: u64) {
+    cheat_block_hash_operation(block_number, Operation::Stop(contract_address));
+}
+
+fn cheat_block_hash_operation(block_number: u64, operation: Operation<felt252>) {
+    validate_operation(@operation);
+
+    let mut inputs = array![];
+
+    block_number.serialize(ref inputs);
+    operation.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'cheat_block_hash', ()>(inputs.span());
+}
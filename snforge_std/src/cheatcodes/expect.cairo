@@ -0,0 +1,36 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+use super::events::Event;
+
+/// Expects the next call made from the test contract to revert with the given panic data.
+/// If it does, the call returns default values of the return type of the called function
+/// instead of panicking, otherwise the test fails.
+/// - `panic_data` - data the call is expected to panic with
+pub fn expect_revert(panic_data: Array<felt252>) {
+    let mut inputs = array![];
+    panic_data.serialize(ref inputs);
+    execute_cheatcode_and_deserialize::<'expect_revert', ()>(inputs.span());
+}
+
+/// Expects the next call made from the test contract to emit the given events, in the given order.
+/// The test fails if any of them is not emitted during the call.
+/// - `events` - array of `(emitter_address, event)` tuples
+pub fn expect_emit<T, impl TEvent: starknet::Event<T>, impl TDrop: Drop<T>>(
+    events: @Array<(ContractAddress, T)>
+) {
+    let mut raw_events: Array<(ContractAddress, Event)> = array![];
+    let mut i = 0;
+
+    while i < events.len() {
+        let (from, event) = events.at(i);
+        let mut keys = array![];
+        let mut data = array![];
+        event.append_keys_and_data(ref keys, ref data);
+        raw_events.append((*from, Event { keys, data }));
+        i += 1;
+    };
+
+    let mut inputs = array![];
+    raw_events.serialize(ref inputs);
+    execute_cheatcode_and_deserialize::<'expect_emit', ()>(inputs.span());
+}
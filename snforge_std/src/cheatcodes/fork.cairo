@@ -0,0 +1,60 @@
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Identifier of a fork, the fork configured with `#[fork]` attribute has id 0
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub struct ForkId {
+    pub id: u32,
+}
+
+/// Block at which a fork is created
+#[derive(Copy, Drop, Serde, PartialEq, Debug)]
+pub enum ForkBlockId {
+    Latest: (),
+    Hash: felt252,
+    Number: u64,
+}
+
+/// Creates a fork of the network without selecting it
+/// - `url` - RPC url of the forked network
+/// - `block` - block the fork is pinned to
+/// Returns the id of the created fork
+pub fn create_fork(url: ByteArray, block: ForkBlockId) -> ForkId {
+    let mut inputs = array![];
+    url.serialize(ref inputs);
+    block.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'create_fork'>(inputs.span())
+}
+
+/// Creates a fork of the network and selects it
+/// - `url` - RPC url of the forked network
+/// - `block` - block the fork is pinned to
+/// Returns the id of the created fork
+pub fn create_select_fork(url: ByteArray, block: ForkBlockId) -> ForkId {
+    let fork_id = create_fork(url, block);
+    select_fork(fork_id);
+    fork_id
+}
+
+/// Selects the fork the test interacts with
+/// Classes declared and contracts deployed in the test remain available after switching,
+/// changes made to contracts of the previously selected fork are discarded
+/// - `fork_id` - id of the fork returned by `create_fork`
+pub fn select_fork(fork_id: ForkId) {
+    let mut inputs = array![];
+    fork_id.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'select_fork', ()>(inputs.span());
+}
+
+/// Moves the selected fork to another block
+/// Classes declared and contracts deployed in the test remain available
+/// - `block_number` - number of the block to move the fork to
+pub fn roll_fork(block_number: u64) {
+    execute_cheatcode_and_deserialize::<'roll_fork', ()>(array![block_number.into()].span());
+}
+
+/// Returns the id of the selected fork, or `Option::None` if the test does not use forking
+pub fn active_fork() -> Option<ForkId> {
+    execute_cheatcode_and_deserialize::<'active_fork'>(array![].span())
+}
@@ -0,0 +1,65 @@
+use starknet::{ContractAddress, EthAddress, SyscallResult};
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Result of sending a message from L1 with `send_message_to_l2`
+#[derive(Drop, Clone, Serde)]
+pub struct MessageToL2Receipt {
+    /// Nonce assigned to the message by the Starknet core contract, starting from 0
+    pub nonce: felt252,
+    /// Hash of the message, as computed by `l1ToL2MessageHash` of the Starknet core contract
+    pub message_hash: u256,
+    /// Result of the L1 handler execution, panic data if it failed
+    pub result: SyscallResult<()>,
+}
+
+/// Message sent to L1 by a contract, which was not consumed yet
+#[derive(Drop, Clone, Serde, PartialEq)]
+pub struct PendingMessageToL1 {
+    /// Address of the L2 contract which sent the message
+    pub from_address: ContractAddress,
+    /// Ethereum address of the L1 contract the message is destined to
+    pub to_address: EthAddress,
+    pub payload: Array<felt252>,
+    /// Hash of the message, as computed by `l2ToL1MessageHash` of the Starknet core contract
+    pub message_hash: u256,
+}
+
+/// Sends a message from L1 through the simulated Starknet core contract and delivers it to the
+/// given L1 handler function. Each message gets the next nonce, the same way as on L1.
+/// - `from_address` - Ethereum address of the L1 contract sending the message
+/// - `to_address` - address of the L2 contract handling the message
+/// - `selector` - selector of a `#[l1_handler]` function of the contract
+/// - `payload` - the handler's function arguments serialized with `Serde`
+/// Returns `MessageToL2Receipt` with the nonce and hash of the message and the handler result
+pub fn send_message_to_l2(
+    from_address: EthAddress,
+    to_address: ContractAddress,
+    selector: felt252,
+    payload: Span<felt252>,
+) -> MessageToL2Receipt {
+    let mut inputs = array![from_address.into(), to_address.into(), selector];
+    payload.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'send_message_to_l2'>(inputs.span())
+}
+
+/// Consumes a message sent to L1, as `consumeMessageFromL2` of the Starknet core contract does
+/// when called by the L1 contract at `to_address`. Each sent message can be consumed once.
+/// - `from_address` - address of the L2 contract which sent the message
+/// - `to_address` - Ethereum address of the L1 contract consuming the message
+/// - `payload` - payload of the message
+/// Returns the hash of the consumed message, or an error if no matching message is pending
+pub fn consume_message_from_l2(
+    from_address: ContractAddress, to_address: EthAddress, payload: Span<felt252>,
+) -> Result<u256, ByteArray> {
+    let mut inputs = array![from_address.into(), to_address.into()];
+    payload.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'consume_message_from_l2'>(inputs.span())
+}
+
+/// Returns all messages sent to L1 during the test which were not consumed yet, in the order they
+/// were sent
+pub fn pending_messages_to_l1() -> Array<PendingMessageToL1> {
+    execute_cheatcode_and_deserialize::<'get_pending_messages_to_l1'>(array![].span())
+}
@@ -0,0 +1,74 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+use super::super::byte_array::byte_array_as_felt_array;
+
+/// Contract mocked with `mock_contract`
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct ContractMock {
+    pub contract_address: ContractAddress,
+}
+
+/// Mocks every function of the contract at the given address.
+/// All calls to the contract return the default value of the function return type (zeros for
+/// numeric types, empty arrays and strings, `Option::None` and first variants of other enums),
+/// until overridden with `ContractMockTrait::mock_function`.
+/// Calls to functions not present in the ABI panic. An address with no contract can be mocked as
+/// well.
+/// - `contract_address` - target contract address
+/// - `contract` - name of the contract from the project, or a path to a JSON file with its ABI
+/// (either a plain ABI or a whole Sierra class)
+/// Returns a `ContractMock` for overriding functions and inspecting the calls
+pub fn mock_contract(contract_address: ContractAddress, contract: ByteArray) -> ContractMock {
+    let mut inputs = array![contract_address.into()];
+    inputs.append_span(byte_array_as_felt_array(@contract).span());
+
+    execute_cheatcode_and_deserialize::<'mock_contract', ()>(inputs.span());
+
+    ContractMock { contract_address }
+}
+
+pub trait ContractMockTrait {
+    /// Overrides data returned by the function of the mocked contract.
+    /// The test fails if the function is not present in the ABI of the contract.
+    /// - `function_name` - name of the function
+    /// - `ret_data` - data to return by the function
+    fn mock_function<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+        self: @ContractMock, function_name: ByteArray, ret_data: T
+    );
+
+    /// Returns the number of calls made to the function of the mocked contract.
+    /// The test fails if the function is not present in the ABI of the contract.
+    /// - `function_name` - name of the function
+    fn calls_count(self: @ContractMock, function_name: ByteArray) -> u32;
+
+    /// Cancels the mock, calls are executed by the contract deployed at the address again
+    fn stop(self: @ContractMock);
+}
+
+impl ContractMockImpl of ContractMockTrait {
+    fn mock_function<T, impl TSerde: core::serde::Serde<T>, impl TDestruct: Destruct<T>>(
+        self: @ContractMock, function_name: ByteArray, ret_data: T
+    ) {
+        let mut inputs = array![(*self.contract_address).into()];
+        inputs.append_span(byte_array_as_felt_array(@function_name).span());
+
+        let mut ret_data_arr = array![];
+        ret_data.serialize(ref ret_data_arr);
+        ret_data_arr.serialize(ref inputs);
+
+        execute_cheatcode_and_deserialize::<'mock_contract_function', ()>(inputs.span());
+    }
+
+    fn calls_count(self: @ContractMock, function_name: ByteArray) -> u32 {
+        let mut inputs = array![(*self.contract_address).into()];
+        inputs.append_span(byte_array_as_felt_array(@function_name).span());
+
+        execute_cheatcode_and_deserialize::<'get_mocked_function_calls_count'>(inputs.span())
+    }
+
+    fn stop(self: @ContractMock) {
+        execute_cheatcode_and_deserialize::<
+            'stop_mock_contract', ()
+        >(array![(*self.contract_address).into()].span());
+    }
+}
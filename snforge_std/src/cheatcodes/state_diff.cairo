@@ -0,0 +1,70 @@
+use starknet::{ClassHash, ContractAddress};
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Change of a storage value.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct StorageDiff {
+    pub contract_address: ContractAddress,
+    pub key: felt252,
+    pub old_value: felt252,
+    pub new_value: felt252
+}
+
+/// Change of a contract nonce.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct NonceDiff {
+    pub contract_address: ContractAddress,
+    pub old_nonce: felt252,
+    pub new_nonce: felt252
+}
+
+/// Change of a contract class hash, `old_class_hash` is zero for deployed contracts.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct ClassHashDiff {
+    pub contract_address: ContractAddress,
+    pub old_class_hash: ClassHash,
+    pub new_class_hash: ClassHash
+}
+
+/// Changes made to the state, sorted by contract addresses and storage keys.
+/// Values changed back to the original ones are not included.
+#[derive(Drop, Clone, Serde, PartialEq, Debug)]
+pub struct StateDiff {
+    pub storage: Array<StorageDiff>,
+    pub nonces: Array<NonceDiff>,
+    pub class_hashes: Array<ClassHashDiff>,
+    pub declared_classes: Array<ClassHash>
+}
+
+/// Gets changes made to the state since the first [`mark_state`] call.
+/// Fails if [`mark_state`] was not called before.
+pub fn get_state_diff() -> StateDiff {
+    execute_cheatcode_and_deserialize::<'get_state_diff'>(array![0].span())
+}
+
+/// Creates `StateMarker` instance marking the current state, changes made after it can be
+/// retrieved with [`StateMarkerTrait::get_state_diff`].
+/// Changes are recorded only after the first call, as recording them requires reading previous
+/// values, which may be fetched from the fork node.
+pub fn mark_state() -> StateMarker {
+    execute_cheatcode_and_deserialize::<'mark_state'>(array![].span())
+}
+
+/// A marker structure allowing to get changes made to the state only after its creation.
+#[derive(Drop, Copy, Serde)]
+pub struct StateMarker {
+    changes_offset: usize
+}
+
+pub trait StateMarkerTrait {
+    /// Gets changes made to the state since the creation of given [`StateMarker`].
+    fn get_state_diff(self: @StateMarker) -> StateDiff;
+}
+
+impl StateMarkerTraitImpl of StateMarkerTrait {
+    fn get_state_diff(self: @StateMarker) -> StateDiff {
+        execute_cheatcode_and_deserialize::<
+            'get_state_diff'
+        >(array![(*self.changes_offset).into()].span())
+    }
+}
@@ -0,0 +1,98 @@
+use starknet::ContractAddress;
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+
+/// Creates `StorageAccessRecorder` instance that records all storage reads and writes done by
+/// contracts after its creation.
+pub fn record_storage_accesses() -> StorageAccessRecorder {
+    execute_cheatcode_and_deserialize::<'record_storage_accesses'>(array![].span())
+}
+
+/// Storage read done with `storage_read_syscall`.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct StorageRead {
+    pub key: felt252,
+    pub value: felt252
+}
+
+/// Storage write done with `storage_write_syscall`.
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct StorageWrite {
+    pub key: felt252,
+    pub old_value: felt252,
+    pub new_value: felt252
+}
+
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub enum StorageAccess {
+    Read: StorageRead,
+    Write: StorageWrite
+}
+
+/// A recorder structure allowing to get storage accesses done only after its creation.
+#[derive(Drop, Serde)]
+pub struct StorageAccessRecorder {
+    access_offset: usize
+}
+
+/// A wrapper structure on an array of storage accesses to handle filtering smoothly.
+#[derive(Drop, Serde, Clone, Debug)]
+pub struct StorageAccesses {
+    pub accesses: Array<(ContractAddress, StorageAccess)>
+}
+
+pub trait StorageAccessRecorderTrait {
+    /// Gets all storage accesses given [`StorageAccessRecorder`] records, in the order they were
+    /// done.
+    fn get_storage_accesses(ref self: StorageAccessRecorder) -> StorageAccesses;
+}
+
+impl StorageAccessRecorderTraitImpl of StorageAccessRecorderTrait {
+    fn get_storage_accesses(ref self: StorageAccessRecorder) -> StorageAccesses {
+        execute_cheatcode_and_deserialize::<
+            'get_storage_accesses'
+        >(array![self.access_offset.into()].span())
+    }
+}
+
+pub trait StorageAccessesFilterTrait {
+    /// Gets reads done in the storage of a given [`ContractAddress`].
+    fn reads(self: @StorageAccesses, contract_address: ContractAddress) -> Array<StorageRead>;
+    /// Gets writes done in the storage of a given [`ContractAddress`].
+    fn writes(self: @StorageAccesses, contract_address: ContractAddress) -> Array<StorageWrite>;
+}
+
+impl StorageAccessesFilterTraitImpl of StorageAccessesFilterTrait {
+    fn reads(self: @StorageAccesses, contract_address: ContractAddress) -> Array<StorageRead> {
+        let mut counter = 0;
+        let mut reads = array![];
+
+        while counter < self.accesses.len() {
+            let (from, access) = self.accesses.at(counter);
+            if *from == contract_address {
+                match access {
+                    StorageAccess::Read(read) => reads.append(*read),
+                    StorageAccess::Write(_) => {},
+                }
+            };
+            counter += 1;
+        };
+        reads
+    }
+
+    fn writes(self: @StorageAccesses, contract_address: ContractAddress) -> Array<StorageWrite> {
+        let mut counter = 0;
+        let mut writes = array![];
+
+        while counter < self.accesses.len() {
+            let (from, access) = self.accesses.at(counter);
+            if *from == contract_address {
+                match access {
+                    StorageAccess::Read(_) => {},
+                    StorageAccess::Write(write) => writes.append(*write),
+                }
+            };
+            counter += 1;
+        };
+        writes
+    }
+}
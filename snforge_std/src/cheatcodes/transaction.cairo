@@ -0,0 +1,119 @@
+use starknet::{ContractAddress, ClassHash};
+use super::super::_cheatcode::execute_cheatcode_and_deserialize;
+use super::super::byte_array::byte_array_as_felt_array;
+use super::events::Events;
+
+/// Maximal amount of a resource the transaction can consume and the maximal price paid per unit
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct ResourceBound {
+    pub max_amount: u64,
+    pub max_price_per_unit: u128,
+}
+
+/// Specifies how the transaction is signed
+#[derive(Drop, Clone, Serde, PartialEq, Debug)]
+pub enum TransactionSigner {
+    /// Signature passed to the account as is
+    Signature: Array<felt252>,
+    /// Stark curve private key used to sign the transaction hash, producing `[r, s]` signature
+    StarkKey: felt252,
+}
+
+/// Parameters of a submitted transaction.
+/// The fee is charged only if any of `max_price_per_unit` is non-zero.
+#[derive(Drop, Clone, Serde, PartialEq, Debug)]
+pub struct TransactionParams {
+    /// Nonce of the transaction, the current nonce of the sender is used if `Option::None`
+    pub nonce: Option<felt252>,
+    pub signer: TransactionSigner,
+    pub l1_gas: ResourceBound,
+    pub l2_gas: ResourceBound,
+    pub l1_data_gas: ResourceBound,
+    pub tip: u64,
+}
+
+impl TransactionParamsDefault of Default<TransactionParams> {
+    fn default() -> TransactionParams {
+        TransactionParams {
+            nonce: Option::None,
+            signer: TransactionSigner::Signature(array![]),
+            l1_gas: ResourceBound { max_amount: 0, max_price_per_unit: 0 },
+            l2_gas: ResourceBound { max_amount: 10_000_000_000, max_price_per_unit: 0 },
+            l1_data_gas: ResourceBound { max_amount: 0, max_price_per_unit: 0 },
+            tip: 0,
+        }
+    }
+}
+
+/// Gas the transaction was charged for
+#[derive(Drop, Copy, Serde, PartialEq, Debug)]
+pub struct GasConsumed {
+    pub l1_gas: u64,
+    pub l1_data_gas: u64,
+    pub l2_gas: u64,
+}
+
+/// Receipt of a transaction included in the state
+#[derive(Drop, Clone, Serde, Debug)]
+pub struct TransactionReceipt {
+    pub transaction_hash: felt252,
+    pub actual_fee: u128,
+    pub gas_consumed: GasConsumed,
+    /// Events emitted during validation, execution and fee transfer
+    pub events: Events,
+    /// `Option::Some` if the execution reverted; the nonce is bumped and the fee is charged anyway
+    pub revert_reason: Option<ByteArray>,
+}
+
+/// Submits an invoke transaction executing `__execute__` of the account through the full
+/// transaction flow: nonce check, `__validate__`, execution and fee charging.
+/// - `sender_address` - address of the account sending the transaction
+/// - `calldata` - calldata passed to `__execute__`
+/// - `params` - nonce, signature and resource bounds of the transaction
+/// Returns the receipt, or the reason the transaction was rejected (e.g. failing `__validate__`)
+pub fn invoke_transaction(
+    sender_address: ContractAddress, calldata: Span<felt252>, params: TransactionParams
+) -> Result<TransactionReceipt, ByteArray> {
+    let mut inputs = array![sender_address.into()];
+    calldata.serialize(ref inputs);
+    params.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'invoke_transaction'>(inputs.span())
+}
+
+/// Submits a deploy account transaction, validated with `__validate_deploy__` of the deployed account.
+/// - `class_hash` - class hash of the account
+/// - `contract_address_salt` - salt used to calculate the account address
+/// - `constructor_calldata` - calldata passed to the constructor of the account
+/// - `params` - nonce, signature and resource bounds of the transaction
+/// Returns the address of the deployed account with the receipt, or the reason the transaction was
+/// rejected
+pub fn deploy_account_transaction(
+    class_hash: ClassHash,
+    contract_address_salt: felt252,
+    constructor_calldata: Span<felt252>,
+    params: TransactionParams
+) -> Result<(ContractAddress, TransactionReceipt), ByteArray> {
+    let mut inputs = array![class_hash.into(), contract_address_salt];
+    constructor_calldata.serialize(ref inputs);
+    params.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'deploy_account_transaction'>(inputs.span())
+}
+
+/// Submits a declare transaction of a contract from the project, validated with
+/// `__validate_declare__` of the account.
+/// - `sender_address` - address of the account sending the transaction
+/// - `contract` - name of the contract to declare
+/// - `params` - nonce, signature and resource bounds of the transaction
+/// Returns the receipt, or the reason the transaction was rejected (e.g. the class is already
+/// declared)
+pub fn declare_transaction(
+    sender_address: ContractAddress, contract: ByteArray, params: TransactionParams
+) -> Result<TransactionReceipt, ByteArray> {
+    let mut inputs = array![sender_address.into()];
+    inputs.append_span(byte_array_as_felt_array(@contract).span());
+    params.serialize(ref inputs);
+
+    execute_cheatcode_and_deserialize::<'declare_transaction'>(inputs.span())
+}
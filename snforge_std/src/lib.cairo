@@ -9,23 +9,57 @@ pub use cheatcodes::contract_class::DeclareResultTrait;
 
 pub use cheatcodes::l1_handler::L1Handler;
 pub use cheatcodes::l1_handler::L1HandlerTrait;
+pub use cheatcodes::l1_messaging::{
+    send_message_to_l2, consume_message_from_l2, pending_messages_to_l1, MessageToL2Receipt,
+    PendingMessageToL1,
+};
 
 pub use cheatcodes::events::Event;
 pub use cheatcodes::events::EventSpy;
 pub use cheatcodes::events::EventSpyTrait;
 pub use cheatcodes::events::EventSpyAssertionsTrait;
 pub use cheatcodes::events::EventsFilterTrait;
+pub use cheatcodes::events::EventMatcher;
+pub use cheatcodes::events::EventMatcherTrait;
+pub use cheatcodes::events::EventSpyMatchingTrait;
 pub use cheatcodes::events::spy_events;
 
+pub use cheatcodes::expect::{expect_revert, expect_emit};
+
 pub use cheatcodes::message_to_l1::{
     spy_messages_to_l1, MessageToL1, MessageToL1Spy, MessageToL1SpyTrait, MessageToL1FilterTrait,
     MessageToL1SpyAssertionsTrait,
 };
 
+pub use cheatcodes::block_progression::{
+    warp, roll, advance_blocks, set_block_time, set_sequencer,
+};
+
+pub use cheatcodes::fork::{
+    create_fork, create_select_fork, select_fork, roll_fork, active_fork, ForkId, ForkBlockId,
+};
+
 pub use cheatcodes::storage::store;
 pub use cheatcodes::storage::load;
 pub use cheatcodes::storage::map_entry_address;
 
+pub use cheatcodes::contract_state::{set_nonce, get_nonce, set_class_hash};
+
+pub use cheatcodes::storage_accesses::{
+    record_storage_accesses, StorageAccess, StorageAccesses, StorageAccessRecorder,
+    StorageAccessRecorderTrait, StorageAccessesFilterTrait, StorageRead, StorageWrite,
+};
+
+pub use cheatcodes::state_diff::{
+    get_state_diff, mark_state, StateDiff, StateMarker, StateMarkerTrait, StorageDiff, NonceDiff,
+    ClassHashDiff,
+};
+
+pub use cheatcodes::transaction::{
+    invoke_transaction, deploy_account_transaction, declare_transaction, TransactionParams,
+    TransactionSigner, TransactionReceipt, ResourceBound, GasConsumed,
+};
+
 pub use cheatcodes::CheatSpan;
 pub use cheatcodes::ReplaceBytecodeError;
 pub use cheatcodes::test_address;
@@ -33,6 +67,7 @@ pub use cheatcodes::test_selector;
 pub use cheatcodes::mock_call;
 pub use cheatcodes::start_mock_call;
 pub use cheatcodes::stop_mock_call;
+pub use cheatcodes::mock_contract::{mock_contract, ContractMock, ContractMockTrait};
 pub use cheatcodes::replace_bytecode;
 
 pub use cheatcodes::execution_info::caller_address::cheat_caller_address;
@@ -45,6 +80,11 @@ pub use cheatcodes::execution_info::block_number::start_cheat_block_number_globa
 pub use cheatcodes::execution_info::block_number::stop_cheat_block_number;
 pub use cheatcodes::execution_info::block_number::stop_cheat_block_number_global;
 pub use cheatcodes::execution_info::block_number::start_cheat_block_number;
+pub use cheatcodes::execution_info::block_hash::cheat_block_hash;
+pub use cheatcodes::execution_info::block_hash::start_cheat_block_hash_global;
+pub use cheatcodes::execution_info::block_hash::stop_cheat_block_hash;
+pub use cheatcodes::execution_info::block_hash::stop_cheat_block_hash_global;
+pub use cheatcodes::execution_info::block_hash::start_cheat_block_hash;
 pub use cheatcodes::execution_info::block_timestamp::cheat_block_timestamp;
 pub use cheatcodes::execution_info::block_timestamp::start_cheat_block_timestamp_global;
 pub use cheatcodes::execution_info::block_timestamp::stop_cheat_block_timestamp;